@@ -17,6 +17,46 @@ pub fn format_duration(secs: i64) -> String {
     }
 }
 
+/// Byte count for progress text: `512 B`, `120 KB`, `3.4 MB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const KB: usize = 1024;
+    const MB: usize = 1024 * KB;
+    if bytes < KB {
+        format!("{bytes} B")
+    } else if bytes < MB {
+        format!("{} KB", bytes / KB)
+    } else {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    }
+}
+
+/// Coarse "time ago" for `dt`: `just now`, `5m ago`, `2h ago`, `3d ago`.
+pub fn format_relative(dt: DateTime<Utc>) -> String {
+    format_relative_to(dt, Utc::now())
+}
+
+/// [`format_relative`] against a fixed `now`. Future times (clock skew) read as
+/// `just now`.
+pub fn format_relative_to(dt: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let secs = now.signed_duration_since(dt).num_seconds();
+    if secs < 60 {
+        "just now".to_string()
+    } else if secs < 3600 {
+        format!("{}m ago", secs / 60)
+    } else if secs < 86_400 {
+        format!("{}h ago", secs / 3600)
+    } else {
+        format!("{}d ago", secs / 86_400)
+    }
+}
+
+/// The most recently created run still in progress; what `--auto-expand` opens.
+pub fn newest_in_progress(runs: &[WorkflowRun]) -> Option<&WorkflowRun> {
+    runs.iter()
+        .filter(|r| r.status == RunStatus::InProgress)
+        .max_by_key(|r| r.created_at)
+}
+
 /// Compute a human-readable duration from optional start/end timestamps.
 /// Returns an empty string if no start time is available.
 pub fn compute_duration(
@@ -57,12 +97,251 @@ pub fn truncate(s: &str, max_width: usize) -> String {
     }
 }
 
+/// Case-insensitive subsequence match; `None` if `query` doesn't match at all.
+/// Consecutive characters and matches at word starts score higher, so `ci`
+/// ranks `CI` above `Code Inspection`.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<u32> {
+    let mut score = 0;
+    // Byte offset just past the previous match, for the consecutive bonus
+    let mut prev_end: Option<usize> = None;
+    let mut prev_char: Option<char> = None;
+    let mut chars = candidate.char_indices();
+    for q in query.chars().flat_map(char::to_lowercase) {
+        loop {
+            let (i, c) = chars.next()?;
+            let at_word_start = prev_char.is_none_or(|p| !p.is_alphanumeric());
+            prev_char = Some(c);
+            if c.to_lowercase().eq(std::iter::once(q)) {
+                score += 1;
+                if prev_end == Some(i) {
+                    score += 5;
+                }
+                if at_word_start {
+                    score += 3;
+                }
+                prev_end = Some(i + c.len_utf8());
+                break;
+            }
+        }
+    }
+    Some(score)
+}
+
+/// `candidates` that fuzzy-match `query`, best first. Ties keep the shorter
+/// (then alphabetically first) name on top.
+pub fn fuzzy_rank<'a>(query: &str, candidates: &'a [String]) -> Vec<&'a str> {
+    let mut scored: Vec<(u32, &str)> = candidates
+        .iter()
+        .filter_map(|c| fuzzy_score(query, c).map(|s| (s, c.as_str())))
+        .collect();
+    scored.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then(a.1.len().cmp(&b.1.len()))
+            .then(a.1.cmp(b.1))
+    });
+    scored.into_iter().map(|(_, c)| c).collect()
+}
+
+/// `test (ubuntu, 1.70)` → `test`. Only GitHub's matrix naming is recognized:
+/// a base name followed by a parenthesized value list.
+pub fn matrix_base(name: &str) -> Option<&str> {
+    let (base, rest) = name.split_once(" (")?;
+    (rest.ends_with(')') && !base.is_empty()).then_some(base)
+}
+
+/// Cancelled run id → number of the run that most likely cancelled it through
+/// a concurrency group: the oldest newer run of the same workflow on the same
+/// branch. Neither API tells these apart from manual cancels, so it's inferred
+/// — a cancel followed by a fresh push reads the same.
+pub fn superseded_runs(runs: &[WorkflowRun]) -> HashMap<u64, u64> {
+    let mut groups: HashMap<(&str, &str), Vec<&WorkflowRun>> = HashMap::new();
+    for run in runs {
+        groups
+            .entry((run.name.as_str(), run.head_branch.as_str()))
+            .or_default()
+            .push(run);
+    }
+    let mut superseded = HashMap::new();
+    for group in groups.values_mut() {
+        group.sort_by_key(|r| r.created_at);
+        for run in group.iter() {
+            if run.conclusion != Some(Conclusion::Cancelled) {
+                continue;
+            }
+            let next = group.partition_point(|r| r.created_at <= run.created_at);
+            if let Some(newer) = group.get(next) {
+                superseded.insert(run.database_id, newer.number);
+            }
+        }
+    }
+    superseded
+}
+
+/// Conclusion for the details panel; names the superseding run when inferred.
+pub fn conclusion_label(run: &WorkflowRun, superseded_by: Option<u64>) -> String {
+    match (run.conclusion, superseded_by) {
+        (Some(c), Some(number)) => format!("{c:?} (superseded by #{number})"),
+        (Some(c), None) => format!("{c:?}"),
+        (None, _) => "-".into(),
+    }
+}
+
+/// Whether `old`'s fetched jobs still describe `new`. An unchanged `updated_at`
+/// says so directly; a run that was and still is completed with the same
+/// conclusion and attempt can't have new job results either — its `updated_at`
+/// still moves for unrelated reasons (e.g. artifacts expiring).
+pub fn jobs_still_valid(old: &WorkflowRun, new: &WorkflowRun) -> bool {
+    old.updated_at == new.updated_at
+        || (old.status == RunStatus::Completed
+            && new.status == RunStatus::Completed
+            && old.conclusion == new.conclusion
+            && old.attempt == new.attempt)
+}
+
+/// Carries job data from the previous poll into `new_runs` (polls return runs
+/// only). Stale jobs are dropped; returns the runs among those for which
+/// `wants_jobs` holds, to be refetched.
+pub fn carry_over_jobs(
+    old_runs: &[WorkflowRun],
+    new_runs: &mut [WorkflowRun],
+    wants_jobs: impl Fn(&WorkflowRun) -> bool,
+) -> Vec<u64> {
+    let mut refetch = Vec::new();
+    for run in new_runs {
+        let Some(old) = old_runs
+            .iter()
+            .find(|r| r.database_id == run.database_id && r.jobs.is_some())
+        else {
+            continue;
+        };
+        if jobs_still_valid(old, run) {
+            run.jobs.clone_from(&old.jobs);
+        } else if wants_jobs(run) {
+            refetch.push(run.database_id);
+        }
+    }
+    refetch
+}
+
+/// `(active, failed)` runs under a branch header, for its row and details.
+pub fn branch_counts(runs: &[&WorkflowRun]) -> (usize, usize) {
+    let active = runs
+        .iter()
+        .filter(|r| r.status != RunStatus::Completed)
+        .count();
+    let failed = runs
+        .iter()
+        .filter(|r| r.conclusion.is_some_and(Conclusion::is_failure))
+        .count();
+    (active, failed)
+}
+
+/// Rolled-up status for a group row: any failure wins, then anything still
+/// running, then cancellation; a fully green group is a success.
+pub fn aggregate_status(jobs: &[&Job]) -> (RunStatus, Option<Conclusion>) {
+    if jobs
+        .iter()
+        .any(|j| j.conclusion.is_some_and(Conclusion::is_failure))
+    {
+        return (RunStatus::Completed, Some(Conclusion::Failure));
+    }
+    if jobs.iter().any(|j| j.status == RunStatus::InProgress) {
+        return (RunStatus::InProgress, None);
+    }
+    if jobs.iter().any(|j| j.status != RunStatus::Completed) {
+        return (RunStatus::Queued, None);
+    }
+    if jobs
+        .iter()
+        .any(|j| j.conclusion == Some(Conclusion::Cancelled))
+    {
+        return (RunStatus::Completed, Some(Conclusion::Cancelled));
+    }
+    if jobs
+        .iter()
+        .all(|j| j.conclusion == Some(Conclusion::Skipped))
+    {
+        return (RunStatus::Completed, Some(Conclusion::Skipped));
+    }
+    (RunStatus::Completed, Some(Conclusion::Success))
+}
+
+/// Splits log content into lines, keeping only the last `max_lines`.
+/// Returns the lines and how many were dropped from the front.
+fn log_lines(content: &str, max_lines: usize) -> (Vec<String>, usize) {
+    let lines: Vec<String> = content
+        .lines()
+        .map(std::string::ToString::to_string)
+        .collect();
+    if lines.len() > max_lines {
+        let dropped = lines.len() - max_lines;
+        (lines[dropped..].to_vec(), dropped)
+    } else {
+        (lines, 0)
+    }
+}
+
+/// `gh run view --log-failed` prefixes every line with `job\tstep\t`; keeps only
+/// `step_name`'s lines. `None` when the log has no step column (REST job logs,
+/// GitLab traces) or the step printed nothing, so callers show the whole job.
+pub fn step_log_section(log: &str, step_name: &str) -> Option<String> {
+    let lines: Vec<&str> = log
+        .lines()
+        .filter(|line| line.split('\t').nth(1) == Some(step_name))
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Regroups a run-level `job\t…` log under `=== Job: name ===` headers, the
+/// layout GitLab's aggregated traces use, so a pasted log reads per job.
+/// Lines without a job column are kept as they are.
+pub fn group_log_by_job(log: &str) -> String {
+    let mut out = String::new();
+    let mut current = None;
+    for line in log.lines() {
+        let text = match line.split_once('\t') {
+            Some((job, rest)) => {
+                if current != Some(job) {
+                    if !out.is_empty() {
+                        out.push('\n');
+                    }
+                    out.push_str("=== Job: ");
+                    out.push_str(job);
+                    out.push_str(" ===\n");
+                    current = Some(job);
+                }
+                rest
+            }
+            None => line,
+        };
+        out.push_str(text);
+        out.push('\n');
+    }
+    out
+}
+
+/// Where a reopened log starts: the last position if it still fits the content.
+fn saved_log_scroll(
+    saved: &HashMap<(u64, Option<u64>), usize>,
+    key: (u64, Option<u64>),
+    line_count: usize,
+) -> usize {
+    saved
+        .get(&key)
+        .copied()
+        .filter(|&scroll| scroll < line_count)
+        .unwrap_or(0)
+}
+
 /// A snapshot of a run's state at a given poll, used for change detection.
 #[derive(Debug, Clone, Copy)]
 pub struct SnapshotEntry {
     pub status: RunStatus,
     pub conclusion: Option<Conclusion>,
     pub last_seen_poll: u64,
+    /// Already counted as started in a poll summary; reset once it completes so
+    /// a rerun counts again.
+    pub counted_start: bool,
 }
 
 /// GitHub updates status ~every 2s; 3s catches most transitions in one cycle.
@@ -74,21 +353,135 @@ pub const POLL_INTERVAL_IDLE: u64 = 30;
 /// "Recent" = completed within 60s. Covers typical "fail → push fix" cycle.
 pub const POLL_RECENT_THRESHOLD_SECS: u64 = 60;
 
+/// Adaptive poll intervals in seconds, overridable via `--{active,recent,idle}-interval`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Intervals {
+    pub active: u64,
+    pub recent: u64,
+    pub idle: u64,
+}
+
+impl Default for Intervals {
+    fn default() -> Self {
+        Self {
+            active: POLL_INTERVAL_ACTIVE,
+            recent: POLL_INTERVAL_RECENT,
+            idle: POLL_INTERVAL_IDLE,
+        }
+    }
+}
+
+impl Intervals {
+    /// Rejects zero and out-of-order intervals: polling slower while runs are
+    /// active than while idle is never what the user meant.
+    pub fn new(active: u64, recent: u64, idle: u64) -> Result<Self, String> {
+        if active == 0 || recent == 0 || idle == 0 {
+            return Err("Poll intervals must be at least 1 second".to_string());
+        }
+        if active > recent || recent > idle {
+            return Err(format!(
+                "Poll intervals must satisfy active <= recent <= idle (got {active}, {recent}, {idle})"
+            ));
+        }
+        Ok(Self {
+            active,
+            recent,
+            idle,
+        })
+    }
+}
+
+/// Adaptive polling: active runs win over a recent completion, idle otherwise.
+pub fn select_poll_interval(has_active: bool, recently_completed: bool, cfg: &Intervals) -> u64 {
+    if has_active {
+        cfg.active
+    } else if recently_completed {
+        cfg.recent
+    } else {
+        cfg.idle
+    }
+}
+
+/// A manual refresh is dropped while another started less than this long ago.
+/// Past it the earlier one is presumed lost (its `gh` call times out at 30s
+/// anyway) so `r` can't stay blocked forever.
+pub const REFRESH_DEBOUNCE_SECS: u64 = 10;
+
+/// Whether a manual refresh should be skipped, given how long ago the in-flight
+/// one (if any) started.
+pub fn is_refresh_debounced(in_flight_elapsed: Option<std::time::Duration>) -> bool {
+    in_flight_elapsed.is_some_and(|e| e.as_secs() < REFRESH_DEBOUNCE_SECS)
+}
+
+/// A run deserves a one-shot "still running" warning: in progress for at least
+/// `threshold_mins` since it was created, and not warned about before.
+pub fn should_warn_long_run<S: std::hash::BuildHasher>(
+    run: &WorkflowRun,
+    now: DateTime<Utc>,
+    threshold_mins: u64,
+    warned: &std::collections::HashSet<u64, S>,
+) -> bool {
+    let threshold = i64::try_from(threshold_mins.saturating_mul(60)).unwrap_or(i64::MAX);
+    run.status == RunStatus::InProgress
+        && (now - run.created_at).num_seconds() >= threshold
+        && !warned.contains(&run.database_id)
+}
+
+/// Strictly below `POLL_RECENT_THRESHOLD_SECS`: at exactly 60s we're idle.
+pub fn is_recent(elapsed_secs: u64) -> bool {
+    elapsed_secs < POLL_RECENT_THRESHOLD_SECS
+}
+
+/// Lines one page scroll moves in a log overlay `visible_height` lines tall:
+/// the full height (`PageDown`, `Ctrl-f`) or half of it (`Ctrl-d`), at least one.
+pub fn log_page_step(visible_height: usize, half: bool) -> usize {
+    let step = if half {
+        visible_height / 2
+    } else {
+        visible_height
+    };
+    step.max(1)
+}
+
 pub const NOTIFICATION_TTL_SECS: u64 = 5;
+/// Notifications kept for the `H` history overlay after they leave the footer.
+pub const NOTIFICATION_HISTORY_MAX: usize = 200;
+/// Poll intervals without a result before the header flags the data as stale.
+pub const STALE_POLL_FACTOR: u64 = 2;
 /// Must match the length of `BRAILLE_FRAMES` in `tui::spinner`.
 pub const SPINNER_FRAME_COUNT: usize = 10;
 pub const QUICK_SELECT_MAX: usize = 9;
+/// Runs past the digits are labelled with these and reached with `g` + letter.
+/// No `g`: `gg` goes to the first row.
+pub const JUMP_LABELS: &str = "abcdefhijklmnopqrstuvwxyz";
+
+/// Row label of the `visual_idx`-th run (0-based): `1`–`9`, then the [`JUMP_LABELS`] letters.
+pub fn quick_select_label(visual_idx: usize) -> Option<char> {
+    if visual_idx < QUICK_SELECT_MAX {
+        char::from_digit(u32::try_from(visual_idx + 1).ok()?, 10)
+    } else {
+        JUMP_LABELS.chars().nth(visual_idx - QUICK_SELECT_MAX)
+    }
+}
+/// Long enough to survive one 100ms tick; a flash, not a blink.
+pub const FLASH_DURATION_MS: u64 = 150;
+/// A burst of failures (e.g. a matrix) alerts once, not once per poll.
+pub const FAILURE_ALERT_COOLDOWN_SECS: u64 = 5;
 /// Below 60 cols, branch names and key hints don't fit — triggers compact layout.
 pub const NARROW_WIDTH_THRESHOLD: u16 = 60;
 /// Long enough to read; short enough to not permanently obscure the tree.
 pub const ERROR_TTL_SECS: u64 = 10;
 
 /// Keeps tail (most relevant for debugging). Prevents OOM on huge logs.
+/// Default for `--log-lines`.
 pub const LOG_MAX_LINES: usize = 500;
 /// Re-open without re-fetch, but get fresh data after rerun.
 pub const LOG_CACHE_TTL_SECS: u64 = 120;
+/// API quota is re-checked on every Nth poll — it moves slowly, and each check
+/// costs a subprocess.
+pub const RATE_LIMIT_CHECK_POLLS: u64 = 10;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RunStatus {
     Completed,
@@ -101,7 +494,32 @@ pub enum RunStatus {
     Unknown,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+impl RunStatus {
+    /// The API's snake_case name, for machine-facing output: `--once` tables,
+    /// webhooks and `--notify-command`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RunStatus::Completed => "completed",
+            RunStatus::InProgress => "in_progress",
+            RunStatus::Queued => "queued",
+            RunStatus::Requested => "requested",
+            RunStatus::Waiting => "waiting",
+            RunStatus::Pending => "pending",
+            RunStatus::Unknown => "unknown",
+        }
+    }
+}
+
+/// Status for the details panel; spells out that `Unknown` is a status this
+/// version doesn't recognise rather than a missing one.
+pub fn status_label(status: RunStatus) -> String {
+    match status {
+        RunStatus::Unknown => "Unknown (unrecognized status from the API)".to_string(),
+        other => format!("{other:?}"),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Conclusion {
     Success,
@@ -117,7 +535,42 @@ pub enum Conclusion {
     Unknown,
 }
 
-#[derive(Debug, Clone, serde::Deserialize)]
+impl Conclusion {
+    /// The API's snake_case name; see [`RunStatus::as_str`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Conclusion::Success => "success",
+            Conclusion::Failure => "failure",
+            Conclusion::Cancelled => "cancelled",
+            Conclusion::Skipped => "skipped",
+            Conclusion::TimedOut => "timed_out",
+            Conclusion::ActionRequired => "action_required",
+            Conclusion::StartupFailure => "startup_failure",
+            Conclusion::Stale => "stale",
+            Conclusion::Neutral => "neutral",
+            Conclusion::Unknown => "unknown",
+        }
+    }
+
+    /// Conclusions that mean the run broke, as opposed to being stopped or skipped.
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            Conclusion::Failure | Conclusion::TimedOut | Conclusion::StartupFailure
+        )
+    }
+
+    /// Ran nothing worth a look: dimmed in the tree, dropped under `hide_skipped`.
+    pub fn is_skipped(self) -> bool {
+        matches!(self, Conclusion::Skipped | Conclusion::Neutral)
+    }
+}
+
+fn is_skipped(conclusion: Option<Conclusion>) -> bool {
+    conclusion.is_some_and(Conclusion::is_skipped)
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkflowRun {
     pub database_id: u64,
@@ -133,12 +586,169 @@ pub struct WorkflowRun {
     pub event: String,
     pub number: u64,
     pub url: String,
-    /// `None` = not yet fetched, `Some(vec)` = fetched (possibly empty).
-    #[serde(skip)]
+    #[serde(default)]
+    pub head_sha: String,
+    /// `gh run list` doesn't expose it; stays `None` unless the backend provides it.
+    #[serde(default)]
+    pub head_commit_message: Option<String>,
+    /// Workflow file, e.g. `.github/workflows/ci.yml`. Only the REST shape
+    /// (`path`) has it; `gh run list` exposes just the workflow name.
+    #[serde(default, alias = "path", skip_serializing_if = "Option::is_none")]
+    pub workflow_path: Option<String>,
+    /// First associated PR. Only the REST shape (`pull_requests: [{number}]`)
+    /// carries it; `gh run list` has no such field, so with the default gh
+    /// backend (and on GitLab) it stays `None` and `P` has nothing to open.
+    #[serde(
+        default,
+        rename = "pullRequests",
+        alias = "pull_requests",
+        deserialize_with = "first_pr_number",
+        serialize_with = "pr_number_as_list"
+    )]
+    pub pr_number: Option<u64>,
+    /// Bumped by each rerun; `gh` calls it `attempt`, the REST API `run_attempt`.
+    #[serde(default = "first_attempt", alias = "runAttempt", alias = "run_attempt")]
+    pub attempt: u64,
+    /// Whether the workflow is a required check under branch protection. No
+    /// run payload (`gh` or REST) carries it yet, so it stays `None` and
+    /// nothing renders until a backend fills it in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    /// Login of whoever triggered the run: REST `actor: {login}`, GitLab
+    /// `user.username`. `gh run list --json` has no actor field.
+    #[serde(
+        default,
+        deserialize_with = "actor_login",
+        serialize_with = "login_as_object",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub actor: Option<String>,
+    /// Set when the backend's timestamps didn't parse; `created_at`/`updated_at`
+    /// then only hold a placeholder for ordering, and durations read "unknown".
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub timestamps_unknown: bool,
+    /// `None` = not yet fetched, `Some(vec)` = fetched (possibly empty). Never in
+    /// CLI output; serialized only for the `--cache` file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub jobs: Option<Vec<Job>>,
 }
 
-#[derive(Debug, Clone, serde::Deserialize)]
+fn first_attempt() -> u64 {
+    1
+}
+
+fn first_pr_number<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    struct PrRef {
+        number: u64,
+    }
+    let prs: Option<Vec<PrRef>> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(prs.and_then(|p| p.first().map(|pr| pr.number)))
+}
+
+fn actor_login<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    struct Actor {
+        login: String,
+    }
+    let actor: Option<Actor> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(actor.map(|a| a.login))
+}
+
+/// Inverse of [`actor_login`], for the same reason as [`pr_number_as_list`].
+#[allow(clippy::ref_option)] // signature dictated by `serialize_with`
+fn login_as_object<S>(actor: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serde::Serialize::serialize(
+        &actor
+            .as_ref()
+            .map(|login| serde_json::json!({ "login": login })),
+        serializer,
+    )
+}
+
+/// Inverse of [`first_pr_number`] so cached runs deserialize the same way.
+#[allow(clippy::ref_option)] // signature dictated by `serialize_with`
+fn pr_number_as_list<S>(pr_number: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeSeq;
+    let mut seq = serializer.serialize_seq(Some(usize::from(pr_number.is_some())))?;
+    if let Some(number) = pr_number {
+        seq.serialize_element(&serde_json::json!({ "number": number }))?;
+    }
+    seq.end()
+}
+
+impl WorkflowRun {
+    /// First 7 chars of the head commit, like `git log --oneline`.
+    pub fn short_sha(&self) -> &str {
+        self.head_sha.get(..7).unwrap_or(&self.head_sha)
+    }
+
+    /// PR URL derived from the run URL, so GHES hosts work without extra config.
+    pub fn pr_url(&self) -> Option<String> {
+        let number = self.pr_number?;
+        let (base, _) = self.url.split_once("/actions/runs/")?;
+        Some(format!("{base}/pull/{number}"))
+    }
+
+    /// Not started yet: time since `created_at` is queue wait, not run time.
+    pub fn is_queued(&self) -> bool {
+        matches!(
+            self.status,
+            RunStatus::Queued | RunStatus::Waiting | RunStatus::Pending | RunStatus::Requested
+        )
+    }
+
+    /// Seconds from creation to completion, or to now while still going.
+    pub fn elapsed_secs(&self) -> i64 {
+        self.elapsed_secs_at(Utc::now())
+    }
+
+    /// [`Self::elapsed_secs`] against a fixed `now`, so a sort compares every
+    /// run at the same instant.
+    pub fn elapsed_secs_at(&self, now: DateTime<Utc>) -> i64 {
+        if self.timestamps_unknown {
+            return 0;
+        }
+        let end = if self.status == RunStatus::Completed {
+            self.updated_at
+        } else {
+            now
+        };
+        end.signed_duration_since(self.created_at).num_seconds()
+    }
+
+    /// Run time, or `queued 2m 5s` while the run hasn't started.
+    pub fn duration_label(&self) -> String {
+        if self.timestamps_unknown {
+            return "unknown".to_string();
+        }
+        if self.is_queued() {
+            return format!("queued {}", compute_duration(Some(self.created_at), None));
+        }
+        let end = (self.status == RunStatus::Completed).then_some(self.updated_at);
+        compute_duration(Some(self.created_at), end)
+    }
+
+    /// Blocked on a human: deployment review (`waiting`) or a first-time
+    /// contributor approval (`action_required`).
+    pub fn needs_approval(&self) -> bool {
+        self.status == RunStatus::Waiting || self.conclusion == Some(Conclusion::ActionRequired)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Job {
     /// `None` during GitHub job provisioning — code must handle this when fetching
@@ -154,10 +764,22 @@ pub struct Job {
     pub completed_at: Option<DateTime<Utc>>,
     pub url: String,
     pub steps: Vec<Step>,
+    /// GitLab stage (`build`, `test`, ...); GitHub jobs have none.
+    #[serde(default)]
+    pub stage: Option<String>,
+    /// Runner that picked the job up. Only the REST jobs payload
+    /// (`--backend http`) has it: `gh run view --json jobs` doesn't emit it, and
+    /// GitLab jobs never do. `None` while queued either way.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runner_name: Option<String>,
+    /// `runs-on` labels, e.g. `ubuntu-latest` or `self-hosted`. REST backend
+    /// only, like `runner_name`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
 }
 
 /// GitLab jobs always have `steps: vec![]` — the GitLab API has no step-level data.
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Step {
     pub name: String,
@@ -172,7 +794,12 @@ pub struct Step {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TreeLevel {
+    /// Header over a branch's runs when grouping by branch (`B`).
+    Branch,
     Run,
+    /// Synthetic parent for jobs sharing a GitLab stage or a matrix base name;
+    /// `job_idx` is the first member.
+    Group,
     Job,
     Step,
     Loading,
@@ -187,19 +814,74 @@ pub struct TreeItem {
     pub job_idx: Option<usize>,
     pub step_idx: Option<usize>,
     pub expanded: bool,
+    /// Job/Step rows nested under a `Group`; rendered one level deeper.
+    pub grouped: bool,
+    /// Name on `Branch` rows, `None` elsewhere.
+    pub branch: Option<String>,
+}
+
+/// `run_idx` of `Branch` rows: past the end of `runs`, so run actions under the
+/// cursor find nothing instead of acting on some run of the branch.
+const BRANCH_ROW: usize = usize::MAX;
+
+/// `filtered` runs bucketed by `head_branch`, most recently active branch first;
+/// runs keep their order within a branch.
+fn branch_groups<'a>(filtered: &[(usize, &'a WorkflowRun)]) -> Vec<(&'a str, Vec<usize>)> {
+    let mut groups: Vec<(&str, Vec<usize>, DateTime<Utc>)> = Vec::new();
+    for &(run_idx, run) in filtered {
+        match groups.iter_mut().find(|(b, ..)| *b == run.head_branch) {
+            Some((_, idxs, latest)) => {
+                idxs.push(run_idx);
+                *latest = (*latest).max(run.updated_at);
+            }
+            None => groups.push((&run.head_branch, vec![run_idx], run.updated_at)),
+        }
+    }
+    groups.sort_by_key(|(.., latest)| std::cmp::Reverse(*latest));
+    groups.into_iter().map(|(b, idxs, _)| (b, idxs)).collect()
 }
 
 pub enum ResolvedItem<'a> {
+    Branch {
+        name: &'a str,
+        runs: Vec<&'a WorkflowRun>,
+    },
     Run(&'a WorkflowRun),
+    Group {
+        name: &'a str,
+        jobs: Vec<&'a Job>,
+    },
     Job(&'a Job),
     Step(&'a Step),
 }
 
 impl AppState {
     pub fn resolve_item(&self, item: &TreeItem) -> Option<ResolvedItem<'_>> {
+        if item.level == TreeLevel::Branch {
+            let branch = item.branch.as_deref()?;
+            let runs: Vec<&WorkflowRun> = self
+                .runs
+                .iter()
+                .filter(|r| r.head_branch == branch && self.filter_predicate(r))
+                .collect();
+            let name = runs.first()?.head_branch.as_str();
+            return Some(ResolvedItem::Branch { name, runs });
+        }
         let run = self.runs.get(item.run_idx)?;
         match item.level {
             TreeLevel::Run => Some(ResolvedItem::Run(run)),
+            TreeLevel::Group => {
+                let jobs = run.jobs.as_ref()?;
+                let name = self.job_group(jobs.get(item.job_idx?)?)?;
+                let members = jobs
+                    .iter()
+                    .filter(|j| self.job_group(j) == Some(name))
+                    .collect();
+                Some(ResolvedItem::Group {
+                    name,
+                    jobs: members,
+                })
+            }
             TreeLevel::Job => {
                 let job = run.jobs.as_ref()?.get(item.job_idx?)?;
                 Some(ResolvedItem::Job(job))
@@ -209,16 +891,50 @@ impl AppState {
                 let step = job.steps.get(item.step_idx?)?;
                 Some(ResolvedItem::Step(step))
             }
-            TreeLevel::Loading => None,
+            TreeLevel::Branch | TreeLevel::Loading => None,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Run order in the tree; `Recent` keeps the API's newest-first order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortMode {
+    #[default]
+    Recent,
+    /// Longest first; running runs count their elapsed time so far.
+    Duration,
+    /// In progress, then queued, then failed, then the rest.
+    Status,
+}
+
+impl SortMode {
+    pub fn label(self) -> &'static str {
+        match self {
+            SortMode::Recent => "recent",
+            SortMode::Duration => "duration",
+            SortMode::Status => "status",
+        }
+    }
+}
+
+/// Sort key for `SortMode::Status`; ties keep recency order (the sort is stable).
+fn status_rank(run: &WorkflowRun) -> u8 {
+    match run.status {
+        RunStatus::InProgress => 0,
+        _ if run.is_queued() => 1,
+        _ if run.conclusion.is_some_and(Conclusion::is_failure) => 2,
+        _ => 3,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum FilterMode {
     All,
     ActiveOnly,
     CurrentBranch,
+    FailuresOnly,
 }
 
 #[derive(Debug, Clone)]
@@ -228,6 +944,14 @@ pub struct Notification {
     pub timestamp: std::time::Instant,
 }
 
+/// A notification as remembered by the history overlay; wall-clock time so it
+/// can be shown, unlike the footer's `Instant`.
+pub struct NotificationRecord {
+    pub run_id: u64,
+    pub message: String,
+    pub at: DateTime<Utc>,
+}
+
 pub struct FailedLog {
     pub content: String,
     pub fetched_at: std::time::Instant,
@@ -236,9 +960,21 @@ pub struct FailedLog {
 pub struct LogOverlay {
     pub title: String,
     pub lines: Vec<String>,
+    /// Lines cut by `--log-lines`; the gutter adds this so numbers match the full log.
+    pub dropped_lines: usize,
     pub scroll: usize,
     pub run_id: u64,
     pub job_id: Option<u64>,
+    /// Re-fetch on every poll; stays pinned to the bottom if scrolled there.
+    pub following: bool,
+    /// Bytes received so far while the first fetch is in flight; `None` once
+    /// content has arrived.
+    pub loading: Option<usize>,
+    /// Step name the content is narrowed to on every refresh; the cache keeps
+    /// the whole job log.
+    pub step: Option<String>,
+    /// Every line kept instead of the `--log-lines` tail; toggled with `a`.
+    pub full: bool,
 }
 
 pub struct DetailOverlay {
@@ -252,10 +988,110 @@ pub struct ConfirmOverlay {
     pub action: ConfirmAction,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfirmAction {
     CancelRun(u64),
     DeleteRun(u64),
+    ApproveRun(u64),
+    RerunRun(u64),
+    DispatchWorkflow {
+        workflow: String,
+        branch: String,
+    },
+    /// Eligible ids only; ineligible selections were filtered out when opening.
+    Batch {
+        op: BatchOp,
+        run_ids: Vec<u64>,
+        /// Built from the `v` marks, which confirming clears. `K` ignores the
+        /// marks, so it leaves them alone.
+        from_marks: bool,
+    },
+}
+
+/// Bulk operation over [`AppState::selected_runs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchOp {
+    Cancel,
+    Delete,
+}
+
+impl BatchOp {
+    /// Same guards as the single-run `c`/`x` keys.
+    pub fn allows(self, status: RunStatus) -> bool {
+        match self {
+            Self::Cancel => status == RunStatus::InProgress,
+            Self::Delete => status != RunStatus::InProgress,
+        }
+    }
+
+    pub fn verb(self) -> &'static str {
+        match self {
+            Self::Cancel => "Cancel",
+            Self::Delete => "Delete",
+        }
+    }
+
+    pub fn past_tense(self) -> &'static str {
+        match self {
+            Self::Cancel => "Cancelled",
+            Self::Delete => "Deleted",
+        }
+    }
+
+    /// "Deleted 3 runs", or with failures "Deleted 2 of 3 runs; 1 failed (run 42: …)".
+    pub fn summary(self, succeeded: usize, failed: &[(u64, String)]) -> String {
+        let total = succeeded + failed.len();
+        let noun = if total == 1 { "run" } else { "runs" };
+        match failed.first() {
+            None => format!("{} {succeeded} {noun}", self.past_tense()),
+            Some((id, err)) => format!(
+                "{} {succeeded} of {total} {noun}; {} failed (run {id}: {err})",
+                self.past_tense(),
+                failed.len()
+            ),
+        }
+    }
+}
+
+pub struct InputOverlay {
+    pub title: String,
+    pub prompt: String,
+    pub value: String,
+    pub purpose: InputPurpose,
+}
+
+/// What the submitted text of an [`InputOverlay`] is used for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputPurpose {
+    DispatchBranch {
+        workflow: String,
+    },
+    /// Applied live on every keystroke; submit just closes the box.
+    TextFilter,
+}
+
+pub struct WorkflowPicker {
+    pub query: String,
+    /// Distinct workflow names from the loaded runs, sorted.
+    pub workflows: Vec<String>,
+    /// Index into `entries()`.
+    pub selected: usize,
+}
+
+impl WorkflowPicker {
+    /// Fuzzy matches for `query`, best first. `None` clears the filter and is
+    /// only offered while the query is empty.
+    pub fn entries(&self) -> Vec<Option<&str>> {
+        if self.query.is_empty() {
+            return std::iter::once(None)
+                .chain(self.workflows.iter().map(|w| Some(w.as_str())))
+                .collect();
+        }
+        fuzzy_rank(&self.query, &self.workflows)
+            .into_iter()
+            .map(Some)
+            .collect()
+    }
 }
 
 /// At most one overlay active at a time (not a stack). New overlay replaces previous.
@@ -264,55 +1100,362 @@ pub enum ActiveOverlay {
     Log(LogOverlay),
     Detail(DetailOverlay),
     Confirm(ConfirmOverlay),
+    Input(InputOverlay),
+    WorkflowPicker(WorkflowPicker),
 }
 
-/// Immutable configuration set at startup.
-pub struct AppConfig {
-    pub repo: String,
-    pub branch: Option<String>,
-    pub limit: usize,
-    pub workflow_filter: Option<String>,
-    pub version_string: String,
+/// Which completions trigger a desktop notification (`--notify-on`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotifyOn {
+    #[default]
+    All,
+    Failures,
+    None,
 }
 
-pub struct AppState {
-    pub config: AppConfig,
+impl NotifyOn {
+    pub fn allows(self, failed: bool) -> bool {
+        match self {
+            NotifyOn::All => true,
+            NotifyOn::Failures => failed,
+            NotifyOn::None => false,
+        }
+    }
+}
 
-    // Run data
-    pub runs: Vec<WorkflowRun>,
-    /// Entries persist after disappearing from API to prevent false notifications
-    /// when runs scroll out of `--limit`. Evicted after `SNAPSHOT_EVICTION_POLLS`.
-    pub previous_snapshot: HashMap<u64, SnapshotEntry>,
-    pub poll_count: u64,
+impl std::str::FromStr for NotifyOn {
+    type Err = String;
 
-    // Tree navigation
-    pub tree_items: Vec<TreeItem>,
-    pub cursor: usize,
-    pub expanded_runs: std::collections::HashSet<u64>,
-    pub expanded_jobs: std::collections::HashSet<(u64, u64)>,
-    pub filter: FilterMode,
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(NotifyOn::All),
+            "failures" => Ok(NotifyOn::Failures),
+            "none" => Ok(NotifyOn::None),
+            _ => Err(format!("expected all, failures or none, got '{s}'")),
+        }
+    }
+}
 
-    // Polling
-    pub last_poll: Option<std::time::Instant>,
-    pub next_poll_in: u64,
-    pub poll_interval: u64,
+/// Whose runs trigger a desktop notification (`--notify-scope`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotifyScope {
+    #[default]
+    All,
+    CurrentBranch,
+}
 
-    // Transient UI
-    pub notifications: Vec<Notification>,
-    pub error: Option<(String, std::time::Instant)>,
-    pub spinner_frame: usize,
-    pub loading_count: u16,
-    pub should_quit: bool,
+impl std::str::FromStr for NotifyScope {
+    type Err = String;
 
-    // Log overlay cache
-    pub log_cache: HashMap<(u64, Option<u64>), FailedLog>,
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(NotifyScope::All),
+            "current-branch" => Ok(NotifyScope::CurrentBranch),
+            _ => Err(format!("expected all or current-branch, got '{s}'")),
+        }
+    }
+}
 
-    // Active overlay (mutually exclusive)
-    pub overlay: ActiveOverlay,
+/// API quota from [`crate::traits::CiExecutor::rate_limit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset: DateTime<Utc>,
+}
+
+impl RateLimit {
+    /// Under 10% left — worth drawing attention to.
+    pub fn is_low(&self) -> bool {
+        self.remaining.saturating_mul(10) < self.limit
+    }
+}
+
+/// Zone absolute timestamps are shown in (`--timezone`). Data stays UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayTz {
+    #[default]
+    Utc,
+    Local,
+    #[cfg(feature = "named-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl DisplayTz {
+    /// `YYYY-MM-DD HH:MM:SS <zone>`; the zone suffix is an abbreviation for
+    /// named zones and a numeric offset for `local`.
+    pub fn format(self, dt: DateTime<Utc>) -> String {
+        match self {
+            DisplayTz::Utc => dt.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            DisplayTz::Local => dt
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S %:z")
+                .to_string(),
+            #[cfg(feature = "named-tz")]
+            DisplayTz::Named(tz) => dt
+                .with_timezone(&tz)
+                .format("%Y-%m-%d %H:%M:%S %Z")
+                .to_string(),
+        }
+    }
+
+    /// `HH:MM:SS` in the zone, for lists where the date is usually today.
+    pub fn format_time(self, dt: DateTime<Utc>) -> String {
+        match self {
+            DisplayTz::Utc => dt.format("%H:%M:%S").to_string(),
+            DisplayTz::Local => dt
+                .with_timezone(&chrono::Local)
+                .format("%H:%M:%S")
+                .to_string(),
+            #[cfg(feature = "named-tz")]
+            DisplayTz::Named(tz) => dt.with_timezone(&tz).format("%H:%M:%S").to_string(),
+        }
+    }
+}
+
+impl std::str::FromStr for DisplayTz {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "utc" | "UTC" => Ok(DisplayTz::Utc),
+            "local" => Ok(DisplayTz::Local),
+            #[cfg(feature = "named-tz")]
+            _ => s
+                .parse::<chrono_tz::Tz>()
+                .map(DisplayTz::Named)
+                .map_err(|_| {
+                    format!("expected utc, local, or an IANA zone like Europe/Madrid, got '{s}'")
+                }),
+            #[cfg(not(feature = "named-tz"))]
+            _ => Err(format!(
+                "expected utc or local (zone names need the named-tz feature), got '{s}'"
+            )),
+        }
+    }
+}
+
+/// Follows https://no-color.org: any non-empty `NO_COLOR` disables color.
+pub fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// `--since` cutoff. Relative windows slide with the clock, so a long-running
+/// watcher keeps showing "the last 24h" rather than everything since launch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Since {
+    Within(chrono::Duration),
+    After(DateTime<Utc>),
+}
+
+impl Since {
+    pub fn cutoff(self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Since::Within(window) => now - window,
+            Since::After(at) => at,
+        }
+    }
+}
+
+impl std::str::FromStr for Since {
+    type Err = String;
+
+    /// `30m`, `24h`, `7d`, `2w`, a `YYYY-MM-DD` date (midnight UTC), or RFC 3339.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid =
+            || format!("expected a duration like 24h/7d or a date like 2026-01-31, got '{s}'");
+        if let Ok(at) = DateTime::parse_from_rfc3339(s) {
+            return Ok(Since::After(at.with_timezone(&Utc)));
+        }
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+            return Ok(Since::After(
+                date.and_time(chrono::NaiveTime::MIN).and_utc(),
+            ));
+        }
+        let unit_at = s.len().checked_sub(1).ok_or_else(invalid)?;
+        let (count, unit) = s.split_at(unit_at);
+        let unit_secs: i64 = match unit {
+            "m" => 60,
+            "h" => 3600,
+            "d" => 86_400,
+            "w" => 604_800,
+            _ => return Err(invalid()),
+        };
+        count
+            .parse::<i64>()
+            .ok()
+            .filter(|&n| n > 0)
+            .and_then(|n| n.checked_mul(unit_secs))
+            .and_then(chrono::Duration::try_seconds)
+            .map(Since::Within)
+            .ok_or_else(invalid)
+    }
+}
+
+/// Immutable configuration set at startup.
+pub struct AppConfig {
+    pub repo: String,
+    /// Non-default server (GitHub Enterprise); shown before the repo.
+    pub host: Option<String>,
+    pub branch: Option<String>,
+    pub limit: usize,
+    pub workflow_filter: Option<String>,
+    pub version_string: String,
+    /// `--cache` target, rewritten after every successful poll.
+    pub cache_path: Option<std::path::PathBuf>,
+    /// `--session` target, written on quit.
+    pub session_path: Option<std::path::PathBuf>,
+    pub notify_on: NotifyOn,
+    pub notify_scope: NotifyScope,
+    /// Ring the terminal bell when a desktop notification can't be shown.
+    pub bell_fallback: bool,
+    pub timezone: DisplayTz,
+    /// Fetch jobs for newly failed runs without waiting for an expand.
+    pub prefetch_failed_jobs: bool,
+    /// `--auto-expand`: open the newest in-progress run after the first poll.
+    /// Cleared once applied so later polls leave the tree alone.
+    pub auto_expand: bool,
+    /// `--long-run` minutes; `None` disables the warning.
+    pub long_run_threshold: Option<u64>,
+    /// `--unknown-active`: the active filter also keeps unrecognized statuses,
+    /// which are usually a new flavour of pending.
+    pub unknown_is_active: bool,
+    /// `--flash`: invert the screen briefly when a run newly fails.
+    pub flash_on_failure: bool,
+    /// `--bell-on-failure`: ring the terminal bell when a run newly fails.
+    pub bell_on_failure: bool,
+    /// `--since`: hide runs created before the cutoff.
+    pub since: Option<Since>,
+    /// Off with `--no-color`/`NO_COLOR`: the TUI renders unstyled, bar the
+    /// cursor highlight.
+    pub color: bool,
+    /// `--tint-rows`: red/green row backgrounds for failed/successful runs.
+    pub tint_rows: bool,
+    /// `--watch-run`: the one run polled and shown.
+    pub watch_run: Option<u64>,
+    /// `--webhook`: POST each finished run here (filtered by `notify_on`).
+    pub webhook_url: Option<String>,
+    /// `--notify-command`: run this instead of the built-in desktop notifier.
+    pub notify_command: Option<crate::notify::NotifyCommand>,
+    /// `--log-lines`: tail of each log kept for the overlay.
+    pub log_max_lines: usize,
+}
+
+pub struct AppState {
+    pub config: AppConfig,
+
+    // Run data
+    pub runs: Vec<WorkflowRun>,
+    /// Entries persist after disappearing from API to prevent false notifications
+    /// when runs scroll out of `--limit`. Evicted after `SNAPSHOT_EVICTION_POLLS`.
+    pub previous_snapshot: HashMap<u64, SnapshotEntry>,
+    pub poll_count: u64,
+
+    // Tree navigation
+    pub tree_items: Vec<TreeItem>,
+    pub cursor: usize,
+    /// Run under the cursor, captured by [`Self::anchor_cursor`] before `runs`
+    /// is replaced (the old `tree_items` indices go stale then).
+    pub selected_run_id: Option<u64>,
+    /// Cursor's row offset below `selected_run_id`'s row, so a job row stays put.
+    pub selected_row_offset: usize,
+    pub expanded_runs: std::collections::HashSet<u64>,
+    /// Runs marked with `v` for a batch cancel/delete.
+    pub selected_runs: std::collections::HashSet<u64>,
+    pub expanded_jobs: std::collections::HashSet<(u64, u64)>,
+    /// `(run_id, group name)` flipped from the default: matrix groups start
+    /// collapsed, GitLab stages expanded.
+    pub toggled_groups: std::collections::HashSet<(u64, String)>,
+    /// Cluster matrix jobs (`test (ubuntu, 1.70)`) under their base name.
+    pub group_matrix: bool,
+    /// Minimal run rows (no number, duration, branch or sha) at any width.
+    pub compact: bool,
+    pub filter: FilterMode,
+    pub sort_mode: SortMode,
+    /// `O`: flips whatever order `sort_mode` gives, so `Recent` reads oldest first.
+    pub reverse: bool,
+    /// `s`: leave skipped/neutral jobs and steps out of the tree.
+    pub hide_skipped: bool,
+    /// `B`: nest runs under a header per branch, most recently active first.
+    /// Orthogonal to `FilterMode::CurrentBranch`, which hides other branches.
+    pub group_by_branch: bool,
+    /// Branch headers folded shut while grouping by branch.
+    pub collapsed_branches: std::collections::HashSet<String>,
+    /// Every workflow name polled so far, for the `w` picker: once a filter is
+    /// on, `runs` only holds the filtered workflow.
+    pub seen_workflows: std::collections::HashSet<String>,
+    /// [`superseded_runs`] of `runs`, refreshed by `rebuild_tree` so rows don't
+    /// each rescan the list.
+    pub superseded: HashMap<u64, u64>,
+    /// Applied on top of `filter`; `None` = all events.
+    pub event_filter: Option<String>,
+    /// Only runs whose `actor` is this login, from `--actor` or `M`. Runs
+    /// with no known actor are hidden while it's set.
+    pub actor_filter: Option<String>,
+    /// Authenticated login, looked up once at startup; what `M` filters to.
+    pub current_user: Option<String>,
+    /// Case-insensitive substring of `display_title` or `name`, from `/`.
+    pub text_filter: Option<String>,
+    /// First key of an in-progress sequence like `zc`.
+    pub pending_key: Option<char>,
+    /// Count typed so far for `<n>G`; cleared by any other key.
+    pub pending_count: Option<usize>,
+
+    // Polling
+    pub last_poll: Option<std::time::Instant>,
+    pub next_poll_in: u64,
+    pub poll_interval: u64,
+    pub intervals: Intervals,
+    /// Poller idles and adaptive interval changes are suspended; manual refresh still works.
+    pub paused: bool,
+    /// Set from `AppEvent::RateLimited`, cleared by the next successful poll.
+    pub rate_limited_until: Option<std::time::Instant>,
+    /// Runs came from the `--cache` file; cleared by the first live poll.
+    pub from_cache: bool,
+    /// Last quota reading; `None` until the first check or on platforms without one.
+    pub api_quota: Option<RateLimit>,
+
+    // Transient UI
+    pub notifications: Vec<Notification>,
+    /// Last poll's transition counts (`2 started, 1 failed`), shown beside the
+    /// per-run notification and gone after `NOTIFICATION_TTL_SECS`. Not a run
+    /// notification, so it stays out of the history.
+    pub poll_summary: Option<(String, std::time::Instant)>,
+    /// Every notification, oldest first, capped at `NOTIFICATION_HISTORY_MAX`.
+    pub notification_history: std::collections::VecDeque<NotificationRecord>,
+    pub error: Option<(String, std::time::Instant)>,
+    pub spinner_frame: usize,
+    pub loading_count: u16,
+    /// Start of the in-flight manual refresh, cleared by its `PollResult` or an
+    /// `Error`. Background polls don't touch it.
+    pub refresh_in_flight: Option<std::time::Instant>,
+    pub should_quit: bool,
+
+    /// Line-number gutter in the log overlay; narrow terminals can hide it.
+    pub log_line_numbers: bool,
+
+    // Log overlay cache
+    pub log_cache: HashMap<(u64, Option<u64>), FailedLog>,
+    /// Scroll of each closed whole-job/run log, keyed like `log_cache`.
+    pub log_scroll: HashMap<(u64, Option<u64>), usize>,
+
+    // Active overlay (mutually exclusive)
+    pub overlay: ActiveOverlay,
 
     // Per-run errors (e.g. job-fetch failures)
     pub run_errors: HashMap<u64, String>,
 
+    /// Runs already flagged as exceeding `long_run_threshold`.
+    pub long_run_warned: std::collections::HashSet<u64>,
+
+    // Failure alert (`--flash` / `--bell-on-failure`)
+    /// Render inverts the whole screen until this deadline.
+    pub flash_until: Option<std::time::Instant>,
+    pub last_failure_alert: Option<std::time::Instant>,
+    /// Set by `alert_failure`; the event loop rings and clears it.
+    pub bell_pending: bool,
+
     // Desktop notifications
     pub desktop_notify: bool,
 }
@@ -327,107 +1470,377 @@ impl AppState {
         Self {
             config: AppConfig {
                 repo,
+                host: None,
                 branch,
                 limit,
                 workflow_filter,
                 version_string: String::new(),
+                cache_path: None,
+                session_path: None,
+                notify_on: NotifyOn::All,
+                notify_scope: NotifyScope::All,
+                bell_fallback: true,
+                timezone: DisplayTz::Utc,
+                prefetch_failed_jobs: false,
+                auto_expand: false,
+                long_run_threshold: None,
+                unknown_is_active: false,
+                flash_on_failure: false,
+                bell_on_failure: false,
+                since: None,
+                color: true,
+                tint_rows: false,
+                watch_run: None,
+                webhook_url: None,
+                notify_command: None,
+                log_max_lines: LOG_MAX_LINES,
             },
             runs: vec![],
             previous_snapshot: HashMap::new(),
             poll_count: 0,
             tree_items: Vec::new(),
             cursor: 0,
+            selected_run_id: None,
+            selected_row_offset: 0,
             expanded_runs: std::collections::HashSet::new(),
+            selected_runs: std::collections::HashSet::new(),
             expanded_jobs: std::collections::HashSet::new(),
+            toggled_groups: std::collections::HashSet::new(),
+            group_matrix: false,
+            compact: false,
             filter: FilterMode::All,
+            sort_mode: SortMode::Recent,
+            reverse: false,
+            hide_skipped: false,
+            group_by_branch: false,
+            collapsed_branches: std::collections::HashSet::new(),
+            seen_workflows: std::collections::HashSet::new(),
+            superseded: HashMap::new(),
+            event_filter: None,
+            actor_filter: None,
+            current_user: None,
+            text_filter: None,
+            pending_key: None,
+            pending_count: None,
             last_poll: None,
             next_poll_in: 0,
             poll_interval: 10,
+            intervals: Intervals::default(),
+            paused: false,
+            rate_limited_until: None,
+            from_cache: false,
+            api_quota: None,
             notifications: Vec::new(),
+            poll_summary: None,
+            notification_history: std::collections::VecDeque::new(),
             error: None,
             spinner_frame: 0,
             loading_count: 0,
+            refresh_in_flight: None,
             should_quit: false,
+            log_line_numbers: true,
             log_cache: HashMap::new(),
+            log_scroll: HashMap::new(),
             overlay: ActiveOverlay::None,
             run_errors: HashMap::new(),
+            long_run_warned: std::collections::HashSet::new(),
+            flash_until: None,
+            last_failure_alert: None,
+            bell_pending: false,
             desktop_notify: true,
         }
     }
 
     pub fn rebuild_tree(&mut self) {
+        self.seen_workflows
+            .extend(self.runs.iter().map(|r| r.name.clone()));
+        self.superseded = superseded_runs(&self.runs);
+        // Follow the run, not the index: polls and sorting reorder rows
+        let anchor = match self.selected_run_id.take() {
+            Some(run_id) => Some((run_id, self.selected_row_offset)),
+            None => self.cursor_position_in_run(),
+        };
         let mut items = Vec::new();
         let filtered = self.filtered_runs_indices();
-        for (run_idx, _run_ref) in &filtered {
-            let run = &self.runs[*run_idx];
-            let run_id = run.database_id;
-            let run_expanded = self.expanded_runs.contains(&run_id);
-            items.push(TreeItem {
-                level: TreeLevel::Run,
-                run_idx: *run_idx,
-                job_idx: None,
-                step_idx: None,
-
-                expanded: run_expanded,
-            });
-            if run_expanded {
-                if let Some(jobs) = &run.jobs {
-                    let items_before = items.len();
-                    for (job_idx, job) in jobs.iter().enumerate() {
-                        let Some(job_db_id) = job.database_id else {
+        if self.group_by_branch {
+            for (branch, run_idxs) in branch_groups(&filtered) {
+                let expanded = !self.collapsed_branches.contains(branch);
+                items.push(TreeItem {
+                    level: TreeLevel::Branch,
+                    run_idx: BRANCH_ROW,
+                    job_idx: None,
+                    step_idx: None,
+                    expanded,
+                    grouped: false,
+                    branch: Some(branch.to_string()),
+                });
+                if expanded {
+                    for run_idx in run_idxs {
+                        self.push_run_items(&mut items, run_idx);
+                    }
+                }
+            }
+        } else {
+            for (run_idx, _) in &filtered {
+                self.push_run_items(&mut items, *run_idx);
+            }
+        }
+        self.tree_items = items;
+        if let Some(row) = anchor.and_then(|(run_id, offset)| self.row_in_run(run_id, offset)) {
+            self.cursor = row;
+        } else if self.cursor >= self.tree_items.len() && !self.tree_items.is_empty() {
+            self.cursor = self.tree_items.len() - 1;
+        } else if self.tree_items.is_empty() {
+            self.cursor = 0;
+        }
+    }
+
+    /// A run's row plus, when expanded, its jobs (grouped as configured) and steps.
+    fn push_run_items(&self, items: &mut Vec<TreeItem>, run_idx: usize) {
+        let run = &self.runs[run_idx];
+        let run_id = run.database_id;
+        let run_expanded = self.expanded_runs.contains(&run_id);
+        items.push(TreeItem {
+            level: TreeLevel::Run,
+            run_idx,
+            job_idx: None,
+            step_idx: None,
+            expanded: run_expanded,
+            grouped: false,
+            branch: None,
+        });
+        if run_expanded {
+            if let Some(jobs) = &run.jobs {
+                let items_before = items.len();
+                let visible: Vec<usize> = jobs
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, j)| j.database_id.is_some())
+                    .filter(|(_, j)| !(self.hide_skipped && is_skipped(j.conclusion)))
+                    .map(|(i, _)| i)
+                    .collect();
+                let groups = self.job_groups(jobs, &visible);
+                let mut emitted: std::collections::HashSet<&str> = std::collections::HashSet::new();
+                for &job_idx in &visible {
+                    let group = self
+                        .job_group(&jobs[job_idx])
+                        .filter(|g| groups.contains(g));
+                    if let Some(name) = group {
+                        // Members render together under the group's first position
+                        if !emitted.insert(name) {
                             continue;
-                        };
-                        let job_expanded = self.expanded_jobs.contains(&(run_id, job_db_id));
+                        }
+                        let toggled = self.toggled_groups.contains(&(run_id, name.to_string()));
+                        let group_expanded = toggled != jobs[job_idx].stage.is_some();
                         items.push(TreeItem {
-                            level: TreeLevel::Job,
-                            run_idx: *run_idx,
+                            level: TreeLevel::Group,
+                            run_idx,
                             job_idx: Some(job_idx),
                             step_idx: None,
-                            expanded: job_expanded,
+                            expanded: group_expanded,
+                            grouped: false,
+                            branch: None,
                         });
-                        if job_expanded {
-                            for (step_idx, _step) in jobs[job_idx].steps.iter().enumerate() {
-                                items.push(TreeItem {
-                                    level: TreeLevel::Step,
-                                    run_idx: *run_idx,
-                                    job_idx: Some(job_idx),
-                                    step_idx: Some(step_idx),
-                                    expanded: false,
-                                });
+                        if group_expanded {
+                            for &member in &visible {
+                                if self.job_group(&jobs[member]) == Some(name) {
+                                    self.push_job_items(items, run_idx, jobs, member, true);
+                                }
                             }
                         }
+                    } else {
+                        self.push_job_items(items, run_idx, jobs, job_idx, false);
                     }
-                    // If all jobs were skipped (e.g. all have database_id: None),
-                    // show a Loading placeholder so the expanded run isn't empty
-                    if items.len() == items_before && !jobs.is_empty() {
-                        items.push(TreeItem {
-                            level: TreeLevel::Loading,
-                            run_idx: *run_idx,
-                            job_idx: None,
-                            step_idx: None,
-                            expanded: false,
-                        });
-                    }
-                } else {
+                }
+                // If all jobs were skipped (e.g. all have database_id: None),
+                // show a Loading placeholder so the expanded run isn't empty.
+                // Jobs hidden by `hide_skipped` have loaded; they stay out.
+                if items.len() == items_before
+                    && !jobs.is_empty()
+                    && jobs.iter().all(|j| j.database_id.is_none())
+                {
                     items.push(TreeItem {
                         level: TreeLevel::Loading,
-                        run_idx: *run_idx,
+                        run_idx,
                         job_idx: None,
                         step_idx: None,
                         expanded: false,
+                        grouped: false,
+                        branch: None,
                     });
                 }
+            } else {
+                items.push(TreeItem {
+                    level: TreeLevel::Loading,
+                    run_idx,
+                    job_idx: None,
+                    step_idx: None,
+                    expanded: false,
+                    grouped: false,
+                    branch: None,
+                });
             }
         }
-        self.tree_items = items;
-        if self.cursor >= self.tree_items.len() && !self.tree_items.is_empty() {
-            self.cursor = self.tree_items.len() - 1;
-        } else if self.tree_items.is_empty() {
-            self.cursor = 0;
+    }
+
+    /// Run id under the cursor and how many rows below that run's row it sits.
+    fn cursor_position_in_run(&self) -> Option<(u64, usize)> {
+        let item = self.tree_items.get(self.cursor)?;
+        let run_id = self.runs.get(item.run_idx)?.database_id;
+        let run_row = self.tree_items[..=self.cursor]
+            .iter()
+            .rposition(|i| i.level == TreeLevel::Run && i.run_idx == item.run_idx)?;
+        Some((run_id, self.cursor - run_row))
+    }
+
+    /// Row `offset` below `run_id`'s row, clamped to that run's own rows.
+    fn row_in_run(&self, run_id: u64, offset: usize) -> Option<usize> {
+        let run_row = self.tree_items.iter().position(|i| {
+            i.level == TreeLevel::Run
+                && self
+                    .runs
+                    .get(i.run_idx)
+                    .is_some_and(|r| r.database_id == run_id)
+        })?;
+        let run_idx = self.tree_items[run_row].run_idx;
+        let span = self.tree_items[run_row + 1..]
+            .iter()
+            .take_while(|i| i.level != TreeLevel::Run && i.run_idx == run_idx)
+            .count();
+        Some(run_row + offset.min(span))
+    }
+
+    /// Remembers the cursor's run before `runs` is swapped out from under the tree.
+    pub fn anchor_cursor(&mut self) {
+        if let Some((run_id, offset)) = self.cursor_position_in_run() {
+            self.selected_run_id = Some(run_id);
+            self.selected_row_offset = offset;
+        }
+    }
+
+    fn push_job_items(
+        &self,
+        items: &mut Vec<TreeItem>,
+        run_idx: usize,
+        jobs: &[Job],
+        job_idx: usize,
+        grouped: bool,
+    ) {
+        let Some(job_db_id) = jobs[job_idx].database_id else {
+            return;
+        };
+        let Some(run_id) = self.run_id_for(run_idx) else {
+            return;
+        };
+        let job_expanded = self.expanded_jobs.contains(&(run_id, job_db_id));
+        items.push(TreeItem {
+            level: TreeLevel::Job,
+            run_idx,
+            job_idx: Some(job_idx),
+            step_idx: None,
+            expanded: job_expanded,
+            grouped,
+            branch: None,
+        });
+        if job_expanded {
+            for (step_idx, step) in jobs[job_idx].steps.iter().enumerate() {
+                if self.hide_skipped && is_skipped(step.conclusion) {
+                    continue;
+                }
+                items.push(TreeItem {
+                    level: TreeLevel::Step,
+                    run_idx,
+                    job_idx: Some(job_idx),
+                    step_idx: Some(step_idx),
+                    expanded: false,
+                    grouped,
+                    branch: None,
+                });
+            }
+        }
+    }
+
+    /// Group name for `job`: its GitLab stage, else its matrix base name when
+    /// matrix grouping is on.
+    pub fn job_group<'a>(&self, job: &'a Job) -> Option<&'a str> {
+        if let Some(stage) = job.stage.as_deref() {
+            Some(stage)
+        } else if self.group_matrix {
+            matrix_base(&job.name)
+        } else {
+            None
+        }
+    }
+
+    /// Groups worth a header among `visible`: every stage, but only matrix
+    /// groups with two or more members — a lone matrix job gains nothing from
+    /// an extra level.
+    fn job_groups<'a>(&self, jobs: &'a [Job], visible: &[usize]) -> Vec<&'a str> {
+        let mut counts: Vec<(&str, usize, bool)> = Vec::new();
+        for &i in visible {
+            if let Some(name) = self.job_group(&jobs[i]) {
+                match counts.iter_mut().find(|(n, _, _)| *n == name) {
+                    Some((_, c, _)) => *c += 1,
+                    None => counts.push((name, 1, jobs[i].stage.is_some())),
+                }
+            }
+        }
+        counts
+            .into_iter()
+            .filter(|(_, c, is_stage)| *is_stage || *c >= 2)
+            .map(|(n, _, _)| n)
+            .collect()
+    }
+
+    pub fn toggle_matrix_groups(&mut self) {
+        self.group_matrix = !self.group_matrix;
+        self.rebuild_tree();
+    }
+
+    fn current_group_key(&self, item: &TreeItem) -> Option<(u64, String)> {
+        let run = self.runs.get(item.run_idx)?;
+        let job = run.jobs.as_ref()?.get(item.job_idx?)?;
+        Some((run.database_id, self.job_group(job)?.to_string()))
+    }
+
+    fn flip_group(&mut self, item: &TreeItem) {
+        if let Some(key) = self.current_group_key(item) {
+            if !self.toggled_groups.remove(&key) {
+                self.toggled_groups.insert(key);
+            }
         }
     }
 
     fn filter_predicate(&self, r: &WorkflowRun) -> bool {
+        // Startup and cache still load the list; only the watched run shows
+        if self.config.watch_run.is_some_and(|id| r.database_id != id) {
+            return false;
+        }
+        if self
+            .config
+            .since
+            .is_some_and(|since| r.created_at < since.cutoff(Utc::now()))
+        {
+            return false;
+        }
+        if self.event_filter.as_ref().is_some_and(|e| r.event != *e) {
+            return false;
+        }
+        if self
+            .actor_filter
+            .as_ref()
+            .is_some_and(|a| r.actor.as_ref() != Some(a))
+        {
+            return false;
+        }
+        if let Some(query) = &self.text_filter {
+            let query = query.to_lowercase();
+            if !r.display_title.to_lowercase().contains(&query)
+                && !r.name.to_lowercase().contains(&query)
+            {
+                return false;
+            }
+        }
         match self.filter {
             FilterMode::All => true,
             FilterMode::ActiveOnly => {
@@ -438,23 +1851,38 @@ impl AppState {
                         | RunStatus::Waiting
                         | RunStatus::Pending
                         | RunStatus::Requested
-                )
+                ) || (r.status == RunStatus::Unknown && self.config.unknown_is_active)
             }
             FilterMode::CurrentBranch => self
                 .config
                 .branch
                 .as_ref()
                 .is_some_and(|b| r.head_branch == *b),
+            FilterMode::FailuresOnly => r.conclusion.is_some_and(Conclusion::is_failure),
         }
     }
 
-    /// Returns (original_index_in_self.runs, &WorkflowRun) for filtered runs.
+    /// Returns (original_index_in_self.runs, &WorkflowRun) for filtered runs,
+    /// in `sort_mode` order, flipped when `reverse` is set.
     pub fn filtered_runs_indices(&self) -> Vec<(usize, &WorkflowRun)> {
-        self.runs
+        let mut runs: Vec<(usize, &WorkflowRun)> = self
+            .runs
             .iter()
             .enumerate()
             .filter(|(_, r)| self.filter_predicate(r))
-            .collect()
+            .collect();
+        match self.sort_mode {
+            SortMode::Recent => {}
+            SortMode::Duration => {
+                let now = Utc::now();
+                runs.sort_by_key(|(_, r)| std::cmp::Reverse(r.elapsed_secs_at(now)));
+            }
+            SortMode::Status => runs.sort_by_key(|(_, r)| status_rank(r)),
+        }
+        if self.reverse {
+            runs.reverse();
+        }
+        runs
     }
 
     pub fn move_cursor_up(&mut self) {
@@ -494,10 +1922,16 @@ impl AppState {
         for k in keys {
             self.expanded_jobs.remove(&k);
         }
+        self.toggled_groups.retain(|(r, _)| *r != run_id);
     }
 
     pub fn toggle_expand(&mut self) {
         if let Some(item) = self.tree_items.get(self.cursor).cloned() {
+            if item.level == TreeLevel::Branch {
+                self.flip_branch(&item);
+                self.rebuild_tree();
+                return;
+            }
             let Some(run_id) = self.run_id_for(item.run_idx) else {
                 return;
             };
@@ -509,6 +1943,7 @@ impl AppState {
                         self.expanded_runs.insert(run_id);
                     }
                 }
+                TreeLevel::Group => self.flip_group(&item),
                 TreeLevel::Job => {
                     if let Some(job_idx) = item.job_idx {
                         if let Some(job_db_id) = self.job_db_id_for(item.run_idx, job_idx) {
@@ -521,15 +1956,53 @@ impl AppState {
                         }
                     }
                 }
-                TreeLevel::Step | TreeLevel::Loading => {}
+                TreeLevel::Branch | TreeLevel::Step | TreeLevel::Loading => {}
             }
             self.rebuild_tree();
         }
     }
 
+    /// Collapses every run and job, keeping the cursor on the run it was inside.
+    pub fn collapse_all(&mut self) {
+        let current_run = self.current_run_id();
+        self.expanded_runs.clear();
+        self.expanded_jobs.clear();
+        self.toggled_groups.clear();
+        self.rebuild_tree();
+        if let Some(run_id) = current_run {
+            if let Some(i) = self.tree_items.iter().position(|item| {
+                self.runs
+                    .get(item.run_idx)
+                    .is_some_and(|r| r.database_id == run_id)
+            }) {
+                self.cursor = i;
+            }
+        }
+    }
+
+    /// Expands every run. Returns ids of runs whose jobs still need fetching.
+    pub fn expand_all(&mut self) -> Vec<u64> {
+        let mut needs_fetch = Vec::new();
+        for run in &self.runs {
+            self.expanded_runs.insert(run.database_id);
+            if run.jobs.is_none() {
+                needs_fetch.push(run.database_id);
+            }
+        }
+        self.rebuild_tree();
+        needs_fetch
+    }
+
     /// Returns Some((run_idx_in_self.runs, needs_job_fetch)) if expanded a run.
     pub fn expand_current(&mut self) -> Option<(usize, bool)> {
         if let Some(item) = self.tree_items.get(self.cursor).cloned() {
+            if item.level == TreeLevel::Branch {
+                if !item.expanded {
+                    self.flip_branch(&item);
+                    self.rebuild_tree();
+                }
+                return None;
+            }
             let run_id = self.run_id_for(item.run_idx)?;
             match item.level {
                 TreeLevel::Run => {
@@ -543,6 +2016,12 @@ impl AppState {
                         return Some((item.run_idx, needs_fetch));
                     }
                 }
+                TreeLevel::Group => {
+                    if !item.expanded {
+                        self.flip_group(&item);
+                        self.rebuild_tree();
+                    }
+                }
                 TreeLevel::Job => {
                     if let Some(job_idx) = item.job_idx {
                         if let Some(job_db_id) = self.job_db_id_for(item.run_idx, job_idx) {
@@ -554,7 +2033,7 @@ impl AppState {
                         }
                     }
                 }
-                TreeLevel::Step | TreeLevel::Loading => {}
+                TreeLevel::Branch | TreeLevel::Step | TreeLevel::Loading => {}
             }
         }
         None
@@ -562,14 +2041,32 @@ impl AppState {
 
     pub fn collapse_current(&mut self) {
         if let Some(item) = self.tree_items.get(self.cursor).cloned() {
+            if item.level == TreeLevel::Branch {
+                if item.expanded {
+                    self.flip_branch(&item);
+                    self.rebuild_tree();
+                }
+                return;
+            }
             let Some(run_id) = self.run_id_for(item.run_idx) else {
                 return;
             };
             match item.level {
+                TreeLevel::Run if !item.expanded && self.group_by_branch => {
+                    self.cursor_to_branch_header();
+                }
                 TreeLevel::Run => {
                     self.collapse_run_from_expanded(run_id);
                     self.rebuild_tree();
                 }
+                TreeLevel::Group => {
+                    if item.expanded {
+                        self.flip_group(&item);
+                        self.rebuild_tree();
+                    } else {
+                        self.cursor_to_parent_run(item.run_idx);
+                    }
+                }
                 TreeLevel::Job => {
                     if let Some(job_idx) = item.job_idx {
                         if let Some(job_db_id) = self.job_db_id_for(item.run_idx, job_idx) {
@@ -577,14 +2074,18 @@ impl AppState {
                             if self.expanded_jobs.contains(&key) {
                                 self.expanded_jobs.remove(&key);
                                 self.rebuild_tree();
-                            } else {
-                                // Go up to parent run
-                                for (i, ti) in self.tree_items.iter().enumerate() {
-                                    if ti.level == TreeLevel::Run && ti.run_idx == item.run_idx {
-                                        self.cursor = i;
-                                        break;
-                                    }
+                            } else if item.grouped {
+                                // Go up to the group this job is listed under
+                                let key = self.current_group_key(&item);
+                                if let Some(i) = self.tree_items.iter().position(|ti| {
+                                    ti.level == TreeLevel::Group
+                                        && ti.run_idx == item.run_idx
+                                        && self.current_group_key(ti) == key
+                                }) {
+                                    self.cursor = i;
                                 }
+                            } else {
+                                self.cursor_to_parent_run(item.run_idx);
                             }
                         }
                     }
@@ -601,23 +2102,68 @@ impl AppState {
                         }
                     }
                 }
-                TreeLevel::Loading => {}
+                TreeLevel::Branch | TreeLevel::Loading => {}
             }
         }
     }
 
+    fn cursor_to_parent_run(&mut self, run_idx: usize) {
+        if let Some(i) = self
+            .tree_items
+            .iter()
+            .position(|ti| ti.level == TreeLevel::Run && ti.run_idx == run_idx)
+        {
+            self.cursor = i;
+        }
+    }
+
     pub fn current_run_url(&self) -> Option<&str> {
         self.tree_items
             .get(self.cursor)
             .and_then(|item| self.runs.get(item.run_idx).map(|r| r.url.as_str()))
     }
 
+    /// Run URL on a run or group, `job.url` on a job. Steps have no URL of their
+    /// own, so they use their job's.
+    pub fn current_item_url(&self) -> Option<String> {
+        let item = self.tree_items.get(self.cursor)?;
+        let run = self.runs.get(item.run_idx)?;
+        let url = match item.level {
+            TreeLevel::Job | TreeLevel::Step => &run.jobs.as_ref()?.get(item.job_idx?)?.url,
+            TreeLevel::Branch | TreeLevel::Run | TreeLevel::Group | TreeLevel::Loading => &run.url,
+        };
+        Some(url.clone()).filter(|u| !u.is_empty())
+    }
+
+    pub fn current_run_pr_url(&self) -> Option<String> {
+        self.tree_items
+            .get(self.cursor)
+            .and_then(|item| self.runs.get(item.run_idx))
+            .and_then(WorkflowRun::pr_url)
+    }
+
+    /// Workflow name of the run under the cursor.
+    pub fn current_run_name(&self) -> Option<&str> {
+        self.tree_items
+            .get(self.cursor)
+            .and_then(|item| self.runs.get(item.run_idx).map(|r| r.name.as_str()))
+    }
+
     pub fn current_run_id(&self) -> Option<u64> {
         self.tree_items
             .get(self.cursor)
             .and_then(|item| self.runs.get(item.run_idx).map(|r| r.database_id))
     }
 
+    /// The cursor's run, if it finished with a failure worth copying logs from.
+    pub fn current_failed_run_id(&self) -> Option<u64> {
+        let item = self.tree_items.get(self.cursor)?;
+        self.runs
+            .get(item.run_idx)
+            .filter(|r| r.conclusion.is_some_and(Conclusion::is_failure))
+            .map(|r| r.database_id)
+    }
+
     pub fn current_run_status(&self) -> Option<RunStatus> {
         self.tree_items
             .get(self.cursor)
@@ -638,66 +2184,442 @@ impl AppState {
     }
 
     pub fn quick_select(&mut self, n: usize) {
-        // Select the nth visible run (1-indexed)
-        let mut run_count = 0;
-        for (i, item) in self.tree_items.iter().enumerate() {
-            if item.level == TreeLevel::Run {
-                run_count += 1;
-                if run_count == n {
-                    self.cursor = i;
-                    break;
-                }
-            }
+        if let Some(i) = self.nth_run_row(n) {
+            self.cursor = i;
         }
     }
 
-    pub fn cycle_filter(&mut self) {
-        self.filter = match self.filter {
-            FilterMode::All => FilterMode::ActiveOnly,
-            FilterMode::ActiveOnly => FilterMode::CurrentBranch,
-            FilterMode::CurrentBranch => FilterMode::All,
-        };
-        self.rebuild_tree();
+    /// Tree index of the nth visible run (1-indexed).
+    fn nth_run_row(&self, n: usize) -> Option<usize> {
+        self.tree_items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.level == TreeLevel::Run)
+            .nth(n.checked_sub(1)?)
+            .map(|(i, _)| i)
     }
 
-    pub fn prune_notifications(&mut self) {
-        let now = std::time::Instant::now();
-        self.notifications
-            .retain(|n| now.duration_since(n.timestamp).as_secs() < NOTIFICATION_TTL_SECS);
+    /// Appends a digit to the `<n>G` count.
+    pub fn push_count_digit(&mut self, digit: usize) {
+        let count = self.pending_count.unwrap_or(0);
+        self.pending_count = Some(count.saturating_mul(10).saturating_add(digit));
     }
 
-    pub fn is_loading(&self) -> bool {
-        self.loading_count > 0
+    /// `gg`.
+    pub fn jump_to_first(&mut self) {
+        self.cursor = 0;
     }
 
-    pub fn begin_loading(&mut self) {
-        self.loading_count = self.loading_count.saturating_add(1);
+    /// `G`.
+    pub fn jump_to_last(&mut self) {
+        self.cursor = self.tree_items.len().saturating_sub(1);
     }
 
-    pub fn end_loading(&mut self) {
-        self.loading_count = self.loading_count.saturating_sub(1);
+    /// `<n>G`: the nth tree row (1-indexed, job and step rows included, as in
+    /// vim), clamped to the last.
+    pub fn jump_to_row(&mut self, n: usize) {
+        self.cursor = n
+            .saturating_sub(1)
+            .min(self.tree_items.len().saturating_sub(1));
     }
 
-    pub fn close_overlay(&mut self) {
-        self.overlay = ActiveOverlay::None;
+    /// Tree index of the run shown with letter `label`.
+    pub fn jump_label_row(&self, label: char) -> Option<usize> {
+        let pos = JUMP_LABELS.chars().position(|c| c == label)?;
+        self.nth_run_row(QUICK_SELECT_MAX + pos + 1)
     }
 
-    pub fn add_notification(&mut self, run_id: u64, message: String) {
-        self.notifications.push(Notification {
-            run_id,
-            message,
-            timestamp: std::time::Instant::now(),
-        });
+    pub fn jump_to_label(&mut self, label: char) {
+        if let Some(i) = self.jump_label_row(label) {
+            self.cursor = i;
+        }
     }
 
-    pub fn prune_log_cache(&mut self) {
-        self.log_cache
-            .retain(|_, entry| entry.fetched_at.elapsed().as_secs() < LOG_CACHE_TTL_SECS);
+    /// Moves to the next failed run row after the cursor, wrapping. Expansion
+    /// state is left untouched.
+    pub fn next_failure(&mut self) {
+        let len = self.tree_items.len();
+        if let Some(i) = (1..=len)
+            .map(|off| (self.cursor + off) % len)
+            .find(|&i| self.is_failed_run_row(i))
+        {
+            self.cursor = i;
+        }
     }
 
-    pub fn update_runs(&mut self, new_runs: Vec<WorkflowRun>) {
-        self.runs = new_runs;
-        self.rebuild_tree();
+    /// Moves to the previous failed run row before the cursor, wrapping.
+    pub fn prev_failure(&mut self) {
+        let len = self.tree_items.len();
+        if let Some(i) = (1..=len)
+            .map(|off| (self.cursor + len - off) % len)
+            .find(|&i| self.is_failed_run_row(i))
+        {
+            self.cursor = i;
+        }
+    }
+
+    fn is_failed_run_row(&self, idx: usize) -> bool {
+        self.tree_items.get(idx).is_some_and(|item| {
+            item.level == TreeLevel::Run
+                && self
+                    .runs
+                    .get(item.run_idx)
+                    .is_some_and(|r| r.conclusion == Some(Conclusion::Failure))
+        })
+    }
+
+    pub fn cycle_filter(&mut self) {
+        self.filter = match self.filter {
+            FilterMode::All => FilterMode::ActiveOnly,
+            // Without a branch (detached HEAD) the branch filter would always be empty
+            FilterMode::ActiveOnly if self.config.branch.is_none() => FilterMode::FailuresOnly,
+            FilterMode::ActiveOnly => FilterMode::CurrentBranch,
+            FilterMode::CurrentBranch => FilterMode::FailuresOnly,
+            FilterMode::FailuresOnly => FilterMode::All,
+        };
+        self.rebuild_tree();
+    }
+
+    pub fn cycle_sort(&mut self) {
+        self.sort_mode = match self.sort_mode {
+            SortMode::Recent => SortMode::Duration,
+            SortMode::Duration => SortMode::Status,
+            SortMode::Status => SortMode::Recent,
+        };
+        self.rebuild_tree();
+    }
+
+    pub fn toggle_reverse(&mut self) {
+        self.reverse = !self.reverse;
+        self.rebuild_tree();
+    }
+
+    /// `s`: hide skipped/neutral jobs and steps, or show them again.
+    pub fn toggle_hide_skipped(&mut self) {
+        self.anchor_cursor();
+        self.hide_skipped = !self.hide_skipped;
+        self.rebuild_tree();
+    }
+
+    /// `B`: group runs by branch, or back to the flat list.
+    pub fn toggle_group_by_branch(&mut self) {
+        self.anchor_cursor();
+        self.group_by_branch = !self.group_by_branch;
+        self.rebuild_tree();
+    }
+
+    fn flip_branch(&mut self, item: &TreeItem) {
+        if let Some(name) = &item.branch {
+            if !self.collapsed_branches.remove(name) {
+                self.collapsed_branches.insert(name.clone());
+            }
+        }
+    }
+
+    /// Moves the cursor up to the header of the branch the cursor's run is under.
+    fn cursor_to_branch_header(&mut self) {
+        if let Some(i) = self.tree_items[..self.cursor]
+            .iter()
+            .rposition(|ti| ti.level == TreeLevel::Branch)
+        {
+            self.cursor = i;
+        }
+    }
+
+    /// `M`: my runs only, or back to everyone's.
+    pub fn toggle_my_runs(&mut self) {
+        if self.actor_filter.take().is_none() {
+            let Some(login) = self.current_user.clone() else {
+                self.set_error("Current user unknown (lookup failed or still running)".to_string());
+                return;
+            };
+            if !self.runs.is_empty() && self.runs.iter().all(|r| r.actor.is_none()) {
+                self.set_error("This backend doesn't report who triggered runs".to_string());
+                return;
+            }
+            self.actor_filter = Some(login);
+        }
+        self.rebuild_tree();
+    }
+
+    /// Cycles None → each distinct event in `runs` (sorted) → None.
+    pub fn cycle_event_filter(&mut self) {
+        let mut events: Vec<&str> = self.runs.iter().map(|r| r.event.as_str()).collect();
+        events.sort_unstable();
+        events.dedup();
+        let next = match &self.event_filter {
+            None => events.first(),
+            Some(current) => events
+                .iter()
+                .position(|e| e == current)
+                .and_then(|i| events.get(i + 1)),
+        };
+        self.event_filter = next.map(|e| (*e).to_string());
+        self.rebuild_tree();
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn toggle_desktop_notify(&mut self) {
+        self.desktop_notify = !self.desktop_notify;
+        let msg = if self.desktop_notify {
+            "Notifications unmuted"
+        } else {
+            "Notifications muted"
+        };
+        self.add_notification(0, msg.to_string());
+    }
+
+    pub fn set_rate_limited(&mut self, retry_in: u64) {
+        self.rate_limited_until =
+            Some(std::time::Instant::now() + std::time::Duration::from_secs(retry_in));
+    }
+
+    /// Seconds until the poller retries, rounded up; `None` when not rate-limited.
+    pub fn rate_limit_retry_in(&self) -> Option<u64> {
+        let until = self.rate_limited_until?;
+        let remaining = until.saturating_duration_since(std::time::Instant::now());
+        Some(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0))
+    }
+
+    pub fn prune_notifications(&mut self) {
+        let now = std::time::Instant::now();
+        self.notifications
+            .retain(|n| now.duration_since(n.timestamp).as_secs() < NOTIFICATION_TTL_SECS);
+        self.poll_summary
+            .take_if(|(_, at)| now.duration_since(*at).as_secs() >= NOTIFICATION_TTL_SECS);
+    }
+
+    /// A poll with nothing to report keeps the previous summary until it expires.
+    pub fn set_poll_summary(&mut self, summary: Option<String>) {
+        if let Some(summary) = summary {
+            self.poll_summary = Some((summary, std::time::Instant::now()));
+        }
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading_count > 0
+    }
+
+    pub fn begin_loading(&mut self) {
+        self.loading_count = self.loading_count.saturating_add(1);
+    }
+
+    pub fn end_loading(&mut self) {
+        self.loading_count = self.loading_count.saturating_sub(1);
+    }
+
+    /// Marks a manual refresh as started unless one is already in flight within
+    /// [`REFRESH_DEBOUNCE_SECS`]; `false` means the caller should skip it.
+    pub fn try_begin_refresh(&mut self) -> bool {
+        if is_refresh_debounced(self.refresh_in_flight.map(|t| t.elapsed())) {
+            return false;
+        }
+        self.refresh_in_flight = Some(std::time::Instant::now());
+        self.begin_loading();
+        true
+    }
+
+    pub fn end_refresh(&mut self) {
+        self.refresh_in_flight = None;
+        self.end_loading();
+    }
+
+    /// Esc on the `/` box also drops the filter it was editing.
+    pub fn close_overlay(&mut self) {
+        // Step views are narrowed, so their offsets don't apply to the full log
+        if let ActiveOverlay::Log(o) = &self.overlay {
+            if o.loading.is_none() && o.step.is_none() {
+                self.log_scroll.insert((o.run_id, o.job_id), o.scroll);
+            }
+        }
+        if matches!(&self.overlay, ActiveOverlay::Input(o) if o.purpose == InputPurpose::TextFilter)
+        {
+            self.text_filter = None;
+            self.rebuild_tree();
+        }
+        self.overlay = ActiveOverlay::None;
+    }
+
+    pub fn add_notification(&mut self, run_id: u64, message: String) {
+        if self.notification_history.len() == NOTIFICATION_HISTORY_MAX {
+            self.notification_history.pop_front();
+        }
+        self.notification_history.push_back(NotificationRecord {
+            run_id,
+            message: message.clone(),
+            at: Utc::now(),
+        });
+        self.notifications.push(Notification {
+            run_id,
+            message,
+            timestamp: std::time::Instant::now(),
+        });
+    }
+
+    /// Recent notifications, newest first, in the detail overlay.
+    pub fn open_notification_history(&mut self) {
+        let tz = self.config.timezone;
+        let mut lines: Vec<(String, String)> = self
+            .notification_history
+            .iter()
+            .rev()
+            .map(|n| (tz.format_time(n.at), n.message.clone()))
+            .collect();
+        if lines.is_empty() {
+            lines.push((String::new(), "No notifications yet".to_string()));
+        }
+        let title = format!("Notifications ({})", self.notification_history.len());
+        self.open_detail_overlay(title, lines);
+    }
+
+    /// Header freshness text (`updated 4s ago`) and whether the data looks stale:
+    /// no poll for over `STALE_POLL_FACTOR` intervals usually means a stuck
+    /// poller. Never stale while paused, since nothing is meant to arrive.
+    pub fn freshness(&self, now: std::time::Instant) -> Option<(String, bool)> {
+        let age = now.saturating_duration_since(self.last_poll?).as_secs();
+        let stale = !self.paused && age > self.poll_interval.saturating_mul(STALE_POLL_FACTOR);
+        let age = i64::try_from(age).unwrap_or(i64::MAX);
+        Some((format!("updated {} ago", format_duration(age)), stale))
+    }
+
+    /// `(completed, total)` jobs of an in-progress run whose jobs are loaded.
+    pub fn run_progress(run: &WorkflowRun) -> Option<(usize, usize)> {
+        if run.status != RunStatus::InProgress {
+            return None;
+        }
+        let jobs = run.jobs.as_ref().filter(|jobs| !jobs.is_empty())?;
+        let done = jobs
+            .iter()
+            .filter(|j| j.status == RunStatus::Completed)
+            .count();
+        Some((done, jobs.len()))
+    }
+
+    /// `--watch-run`: show only `run_id`, expanded so its jobs load with it.
+    pub fn focus_run(&mut self, run_id: u64) {
+        self.config.watch_run = Some(run_id);
+        self.expanded_runs.insert(run_id);
+        self.rebuild_tree();
+    }
+
+    /// The watched run once it has arrived without jobs; expansion normally
+    /// fetches them, but this run was expanded before it existed.
+    pub fn watch_run_needing_jobs(&self) -> Option<u64> {
+        let id = self.config.watch_run?;
+        self.runs
+            .iter()
+            .any(|r| r.database_id == id && r.jobs.is_none())
+            .then_some(id)
+    }
+
+    /// Under `--auto-expand`, expands the newest in-progress run and moves the
+    /// cursor onto it, once. Returns the run's id when its jobs need fetching.
+    pub fn auto_expand_newest_active(&mut self) -> Option<u64> {
+        if !std::mem::take(&mut self.config.auto_expand) {
+            return None;
+        }
+        let run = newest_in_progress(&self.runs)?;
+        let (run_id, needs_fetch) = (run.database_id, run.jobs.is_none());
+        self.expanded_runs.insert(run_id);
+        self.selected_run_id = Some(run_id);
+        self.selected_row_offset = 0;
+        self.rebuild_tree();
+        needs_fetch.then_some(run_id)
+    }
+
+    /// Name of the first failed step, or of the failed job when it has no failed
+    /// step (GitLab jobs never do). `None` until the run's jobs are loaded.
+    pub fn first_failure_label(run: &WorkflowRun) -> Option<String> {
+        let job = run
+            .jobs
+            .as_ref()?
+            .iter()
+            .find(|j| j.conclusion.is_some_and(Conclusion::is_failure))?;
+        let step = job
+            .steps
+            .iter()
+            .find(|s| s.conclusion.is_some_and(Conclusion::is_failure));
+        Some(step.map_or(&job.name, |s| &s.name).clone())
+    }
+
+    /// Jobs per conclusion, for the run line's `2✗/5` badge. Jobs without a
+    /// conclusion yet aren't counted. `None` until the run's jobs are loaded.
+    pub fn job_conclusion_counts(run: &WorkflowRun) -> Option<HashMap<Conclusion, usize>> {
+        let mut counts = HashMap::new();
+        for conclusion in run.jobs.as_ref()?.iter().filter_map(|j| j.conclusion) {
+            *counts.entry(conclusion).or_insert(0) += 1;
+        }
+        Some(counts)
+    }
+
+    /// Summed durations of the run's finished jobs — runner time, so parallel
+    /// jobs count in full where wall-clock `Duration` overlaps them. `None`
+    /// until jobs are loaded and at least one has finished.
+    pub fn run_billable_seconds(run: &WorkflowRun) -> Option<i64> {
+        let spans: Vec<i64> = run
+            .jobs
+            .as_ref()?
+            .iter()
+            .filter_map(|j| Some((j.completed_at? - j.started_at?).num_seconds().max(0)))
+            .collect();
+        (!spans.is_empty()).then(|| spans.iter().sum())
+    }
+
+    /// Notifies once per run that crosses `long_run_threshold`.
+    pub fn check_long_runs(&mut self, now: DateTime<Utc>) {
+        let Some(mins) = self.config.long_run_threshold else {
+            return;
+        };
+        let hits: Vec<(u64, u64)> = self
+            .runs
+            .iter()
+            .filter(|r| should_warn_long_run(r, now, mins, &self.long_run_warned))
+            .map(|r| (r.database_id, r.number))
+            .collect();
+        for &(run_id, number) in &hits {
+            self.long_run_warned.insert(run_id);
+            self.add_notification(run_id, format!("Run #{number} running >{mins}m"));
+        }
+    }
+
+    /// Flashes and/or arms the bell for a new failure, at most once per
+    /// `FAILURE_ALERT_COOLDOWN_SECS`.
+    pub fn alert_failure(&mut self, now: std::time::Instant) {
+        if !self.config.flash_on_failure && !self.config.bell_on_failure {
+            return;
+        }
+        let cooldown = std::time::Duration::from_secs(FAILURE_ALERT_COOLDOWN_SECS);
+        if self
+            .last_failure_alert
+            .is_some_and(|t| now.saturating_duration_since(t) < cooldown)
+        {
+            return;
+        }
+        self.last_failure_alert = Some(now);
+        if self.config.flash_on_failure {
+            self.flash_until = Some(now + std::time::Duration::from_millis(FLASH_DURATION_MS));
+        }
+        if self.config.bell_on_failure {
+            self.bell_pending = true;
+        }
+    }
+
+    pub fn is_flashing(&self, now: std::time::Instant) -> bool {
+        self.flash_until.is_some_and(|until| now < until)
+    }
+
+    pub fn prune_log_cache(&mut self) {
+        self.log_cache
+            .retain(|_, entry| entry.fetched_at.elapsed().as_secs() < LOG_CACHE_TTL_SECS);
+    }
+
+    pub fn update_runs(&mut self, new_runs: Vec<WorkflowRun>) {
+        self.anchor_cursor();
+        self.runs = new_runs;
+        self.rebuild_tree();
     }
 
     pub fn advance_spinner(&mut self) {
@@ -737,24 +2659,176 @@ impl AppState {
         run_id: u64,
         job_id: Option<u64>,
     ) {
-        let lines: Vec<String> = content
-            .lines()
-            .map(std::string::ToString::to_string)
-            .collect();
-        let lines = if lines.len() > LOG_MAX_LINES {
-            lines[lines.len() - LOG_MAX_LINES..].to_vec()
-        } else {
-            lines
-        };
+        let (lines, dropped_lines) = log_lines(content, self.config.log_max_lines);
+        let scroll = saved_log_scroll(&self.log_scroll, (run_id, job_id), lines.len());
         self.overlay = ActiveOverlay::Log(LogOverlay {
             title,
             lines,
+            dropped_lines,
+            scroll,
+            run_id,
+            job_id,
+            following: false,
+            loading: None,
+            step: None,
+            full: false,
+        });
+    }
+
+    /// Opens an empty overlay that shows fetch progress until
+    /// [`Self::refresh_log_overlay`] fills it.
+    pub fn open_log_overlay_loading(&mut self, title: String, run_id: u64, job_id: Option<u64>) {
+        self.overlay = ActiveOverlay::Log(LogOverlay {
+            title,
+            lines: Vec::new(),
+            dropped_lines: 0,
             scroll: 0,
             run_id,
             job_id,
+            following: false,
+            loading: Some(0),
+            step: None,
+            full: false,
         });
     }
 
+    /// Narrows the open log overlay to one step from the next refresh on.
+    pub fn set_log_step(&mut self, step: Option<String>) {
+        if let ActiveOverlay::Log(ref mut overlay) = self.overlay {
+            overlay.step = step;
+        }
+    }
+
+    /// Updates the byte count of a loading overlay for `(run_id, job_id)`.
+    pub fn set_log_progress(&mut self, run_id: u64, job_id: Option<u64>, bytes: usize) {
+        if let ActiveOverlay::Log(ref mut overlay) = self.overlay {
+            if overlay.run_id == run_id && overlay.job_id == job_id && overlay.loading.is_some() {
+                overlay.loading = Some(bytes);
+            }
+        }
+    }
+
+    /// Closes the overlay if it is still waiting on the fetch for `(run_id, job_id)`.
+    pub fn abort_log_loading(&mut self, run_id: u64, job_id: Option<u64>) {
+        if self
+            .log_overlay_ref()
+            .is_some_and(|o| o.run_id == run_id && o.job_id == job_id && o.loading.is_some())
+        {
+            self.overlay = ActiveOverlay::None;
+        }
+    }
+
+    /// Whether a log fetch for `(run_id, job_id)` still has anywhere to land.
+    /// Only the open overlay showing that log wants it; once the user closes
+    /// it or moves on, the fetch is aborted and a late result dropped.
+    pub fn log_result_wanted(&self, run_id: u64, job_id: Option<u64>) -> bool {
+        self.log_overlay_ref()
+            .is_some_and(|o| o.run_id == run_id && o.job_id == job_id)
+    }
+
+    /// Replaces the content of the open overlay in place if it shows
+    /// `(run_id, job_id)`. Returns `false` (no-op) otherwise.
+    pub fn refresh_log_overlay(
+        &mut self,
+        run_id: u64,
+        job_id: Option<u64>,
+        content: &str,
+        visible_height: usize,
+    ) -> bool {
+        let ActiveOverlay::Log(ref mut overlay) = self.overlay else {
+            return false;
+        };
+        if overlay.run_id != run_id || overlay.job_id != job_id {
+            return false;
+        }
+        let was_at_bottom = overlay.scroll >= overlay.lines.len().saturating_sub(visible_height)
+            && overlay.loading.is_none();
+        let old_dropped = overlay.dropped_lines;
+        let section = overlay
+            .step
+            .as_deref()
+            .and_then(|step| step_log_section(content, step));
+        let max_lines = if overlay.full {
+            usize::MAX
+        } else {
+            self.config.log_max_lines
+        };
+        (overlay.lines, overlay.dropped_lines) =
+            log_lines(section.as_deref().unwrap_or(content), max_lines);
+        if overlay.loading.is_some() && overlay.step.is_none() {
+            overlay.scroll =
+                saved_log_scroll(&self.log_scroll, (run_id, job_id), overlay.lines.len());
+        } else if overlay.loading.is_none() {
+            // Lines gained or lost at the top: keep the same line in view
+            overlay.scroll = (overlay.scroll + old_dropped).saturating_sub(overlay.dropped_lines);
+        }
+        overlay.loading = None;
+        let max_scroll = overlay.lines.len().saturating_sub(visible_height);
+        overlay.scroll = if was_at_bottom {
+            max_scroll
+        } else {
+            overlay.scroll.min(max_scroll)
+        };
+        true
+    }
+
+    /// `a` in the log overlay. The full log has to be fetched again without the
+    /// tail cut, so switching to it returns the `(run_id, job_id)` to fetch;
+    /// switching back trims in place.
+    pub fn toggle_full_log(&mut self, visible_height: usize) -> Option<(u64, Option<u64>)> {
+        let max_lines = self.config.log_max_lines;
+        let ActiveOverlay::Log(ref mut overlay) = self.overlay else {
+            return None;
+        };
+        if overlay.loading.is_some() {
+            return None;
+        }
+        overlay.full = !overlay.full;
+        if overlay.full {
+            return Some((overlay.run_id, overlay.job_id));
+        }
+        if overlay.lines.len() > max_lines {
+            let cut = overlay.lines.len() - max_lines;
+            overlay.lines.drain(..cut);
+            overlay.dropped_lines += cut;
+            overlay.scroll = overlay.scroll.saturating_sub(cut);
+        }
+        overlay.scroll = overlay
+            .scroll
+            .min(overlay.lines.len().saturating_sub(visible_height));
+        None
+    }
+
+    /// Line cap for the next fetch of the open log: none in full mode, nor for
+    /// a step, which is narrowed out of the whole log before the tail is cut.
+    pub fn log_fetch_max_lines(&self) -> usize {
+        if self
+            .log_overlay_ref()
+            .is_some_and(|o| o.full || o.step.is_some())
+        {
+            usize::MAX
+        } else {
+            self.config.log_max_lines
+        }
+    }
+
+    pub fn toggle_log_line_numbers(&mut self) {
+        self.log_line_numbers = !self.log_line_numbers;
+    }
+
+    pub fn toggle_log_follow(&mut self) {
+        if let ActiveOverlay::Log(ref mut overlay) = self.overlay {
+            overlay.following = !overlay.following;
+        }
+    }
+
+    /// `(run_id, job_id)` of the log overlay when follow mode is on.
+    pub fn followed_log(&self) -> Option<(u64, Option<u64>)> {
+        self.log_overlay_ref()
+            .filter(|o| o.following)
+            .map(|o| (o.run_id, o.job_id))
+    }
+
     pub fn close_log_overlay(&mut self) {
         if matches!(self.overlay, ActiveOverlay::Log(_)) {
             self.overlay = ActiveOverlay::None;
@@ -810,7 +2884,7 @@ impl AppState {
 
     pub fn confirm_action(&self) -> Option<ConfirmAction> {
         if let ActiveOverlay::Confirm(ref overlay) = self.overlay {
-            Some(overlay.action)
+            Some(overlay.action.clone())
         } else {
             None
         }
@@ -830,38 +2904,301 @@ impl AppState {
         }
     }
 
-    /// Returns a display title for the current run (e.g. `"CI #42"`).
-    pub fn current_run_display_title(&self) -> Option<String> {
-        self.tree_items.get(self.cursor).and_then(|item| {
-            self.runs
-                .get(item.run_idx)
-                .map(|r| format!("{} #{}", r.name, r.number))
-        })
+    // --- Batch selection methods ---
+
+    /// Marks or unmarks the run under the cursor (or the run owning the cursor row).
+    pub fn toggle_selected(&mut self) {
+        if let Some(run_id) = self.current_run_id() {
+            if !self.selected_runs.remove(&run_id) {
+                self.selected_runs.insert(run_id);
+            }
+        }
     }
 
-    /// Remove a run by ID: prune expanded sets, log cache, run errors,
-    /// close any overlay referencing this run, rebuild tree, and clamp cursor.
-    pub fn remove_run(&mut self, run_id: u64) {
-        let Some(idx) = self.runs.iter().position(|r| r.database_id == run_id) else {
+    pub fn clear_selection(&mut self) {
+        self.selected_runs.clear();
+    }
+
+    /// Selected runs that `op` may act on, sorted, plus how many were skipped.
+    /// Selections no longer in `runs` count as skipped.
+    pub fn batch_targets(&self, op: BatchOp) -> (Vec<u64>, usize) {
+        let mut eligible: Vec<u64> = self
+            .runs
+            .iter()
+            .filter(|r| self.selected_runs.contains(&r.database_id) && op.allows(r.status))
+            .map(|r| r.database_id)
+            .collect();
+        eligible.sort_unstable();
+        let skipped = self.selected_runs.len() - eligible.len();
+        (eligible, skipped)
+    }
+
+    /// Opens the batch confirm, or sets an error when nothing is eligible.
+    pub fn open_batch_confirm(&mut self, op: BatchOp) {
+        if self.selected_runs.is_empty() {
+            self.set_error("No runs selected (v to select)".to_string());
+            return;
+        }
+        let (run_ids, skipped) = self.batch_targets(op);
+        if run_ids.is_empty() {
+            self.set_error(format!(
+                "None of the {skipped} selected runs can be {}",
+                op.past_tense().to_lowercase()
+            ));
             return;
+        }
+        let noun = if run_ids.len() == 1 { "run" } else { "runs" };
+        let skipped_note = if skipped > 0 {
+            format!(" ({skipped} skipped)")
+        } else {
+            String::new()
         };
-        self.runs.remove(idx);
-
-        // Prune expanded state
-        self.expanded_runs.remove(&run_id);
-        self.expanded_jobs.retain(|(r, _)| *r != run_id);
+        self.open_confirm_overlay(
+            format!("Confirm {}", op.verb()),
+            format!(
+                "{} {} selected {noun}?{skipped_note}",
+                op.verb(),
+                run_ids.len()
+            ),
+            ConfirmAction::Batch {
+                op,
+                run_ids,
+                from_marks: true,
+            },
+        );
+    }
 
-        // Prune log cache entries for this run
+    /// Every in-progress run, marked or not, sorted; what `K` cancels.
+    pub fn active_run_ids(&self) -> Vec<u64> {
+        let mut ids: Vec<u64> = self
+            .runs
+            .iter()
+            .filter(|r| BatchOp::Cancel.allows(r.status))
+            .map(|r| r.database_id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// `K`: the batch cancel confirm over [`Self::active_run_ids`], for backing
+    /// out of a bad push without marking each run.
+    pub fn open_cancel_all_confirm(&mut self) {
+        let run_ids = self.active_run_ids();
+        if run_ids.is_empty() {
+            self.set_error("No runs in progress".to_string());
+            return;
+        }
+        let message = match run_ids.len() {
+            1 => "Cancel the 1 in-progress run?".to_string(),
+            n => format!("Cancel all {n} in-progress runs?"),
+        };
+        self.open_confirm_overlay(
+            "Confirm Cancel All".to_string(),
+            message,
+            ConfirmAction::Batch {
+                op: BatchOp::Cancel,
+                run_ids,
+                from_marks: false,
+            },
+        );
+    }
+
+    /// Reports a finished batch: each run gets its own notification (so the
+    /// failures are marked in the tree), then the summary, or an error if any failed.
+    pub fn report_batch_result(
+        &mut self,
+        op: BatchOp,
+        succeeded: &[u64],
+        failed: &[(u64, String)],
+    ) {
+        // Deleted runs are about to leave the tree; nothing to mark
+        if op == BatchOp::Cancel {
+            for &run_id in succeeded {
+                self.add_notification(run_id, "Run cancelled".to_string());
+            }
+        }
+        for (run_id, err) in failed {
+            self.add_notification(*run_id, format!("{} failed: {err}", op.verb()));
+        }
+        let summary = op.summary(succeeded.len(), failed);
+        if failed.is_empty() {
+            self.add_notification(0, summary);
+        } else {
+            self.set_error(summary);
+        }
+    }
+
+    // --- Input overlay methods ---
+
+    pub fn has_input_overlay(&self) -> bool {
+        matches!(self.overlay, ActiveOverlay::Input(_))
+    }
+
+    pub fn open_input_overlay(
+        &mut self,
+        title: String,
+        prompt: String,
+        initial: String,
+        purpose: InputPurpose,
+    ) {
+        self.overlay = ActiveOverlay::Input(InputOverlay {
+            title,
+            prompt,
+            value: initial,
+            purpose,
+        });
+    }
+
+    pub fn has_workflow_picker(&self) -> bool {
+        matches!(self.overlay, ActiveOverlay::WorkflowPicker(_))
+    }
+
+    pub fn open_workflow_picker(&mut self) {
+        let mut workflows: Vec<String> = self
+            .seen_workflows
+            .iter()
+            .chain(self.runs.iter().map(|r| &r.name))
+            .cloned()
+            .collect();
+        workflows.sort();
+        workflows.dedup();
+        self.overlay = ActiveOverlay::WorkflowPicker(WorkflowPicker {
+            query: String::new(),
+            workflows,
+            selected: 0,
+        });
+    }
+
+    pub fn picker_move_up(&mut self) {
+        if let ActiveOverlay::WorkflowPicker(ref mut picker) = self.overlay {
+            picker.selected = picker.selected.saturating_sub(1);
+        }
+    }
+
+    pub fn picker_move_down(&mut self) {
+        if let ActiveOverlay::WorkflowPicker(ref mut picker) = self.overlay {
+            let last = picker.entries().len().saturating_sub(1);
+            picker.selected = (picker.selected + 1).min(last);
+        }
+    }
+
+    /// Closes the picker. Outer `None`: no picker open, or nothing matched;
+    /// inner `None`: "all workflows".
+    pub fn take_workflow_pick(&mut self) -> Option<Option<String>> {
+        if !self.has_workflow_picker() {
+            return None;
+        }
+        match std::mem::replace(&mut self.overlay, ActiveOverlay::None) {
+            ActiveOverlay::WorkflowPicker(picker) => picker
+                .entries()
+                .get(picker.selected)
+                .map(|entry| entry.map(str::to_string)),
+            _ => None,
+        }
+    }
+
+    pub fn open_text_filter(&mut self) {
+        let current = self.text_filter.clone().unwrap_or_default();
+        self.open_input_overlay(
+            "Filter runs".to_string(),
+            "/".to_string(),
+            current,
+            InputPurpose::TextFilter,
+        );
+    }
+
+    pub fn input_push(&mut self, c: char) {
+        match self.overlay {
+            ActiveOverlay::Input(ref mut overlay) => overlay.value.push(c),
+            ActiveOverlay::WorkflowPicker(ref mut picker) => {
+                picker.query.push(c);
+                picker.selected = 0;
+            }
+            _ => {}
+        }
+        self.sync_text_filter();
+    }
+
+    pub fn input_pop(&mut self) {
+        match self.overlay {
+            ActiveOverlay::Input(ref mut overlay) => {
+                overlay.value.pop();
+            }
+            ActiveOverlay::WorkflowPicker(ref mut picker) => {
+                picker.query.pop();
+                picker.selected = 0;
+            }
+            _ => {}
+        }
+        self.sync_text_filter();
+    }
+
+    /// Mirrors the `/` box into `text_filter` so the tree narrows as you type.
+    fn sync_text_filter(&mut self) {
+        let ActiveOverlay::Input(ref overlay) = self.overlay else {
+            return;
+        };
+        if overlay.purpose != InputPurpose::TextFilter {
+            return;
+        }
+        let query = overlay.value.trim();
+        self.text_filter = (!query.is_empty()).then(|| query.to_string());
+        self.rebuild_tree();
+    }
+
+    /// Closes the input overlay, returning its purpose and trimmed value.
+    pub fn take_input(&mut self) -> Option<(InputPurpose, String)> {
+        if !self.has_input_overlay() {
+            return None;
+        }
+        match std::mem::replace(&mut self.overlay, ActiveOverlay::None) {
+            ActiveOverlay::Input(overlay) => {
+                Some((overlay.purpose, overlay.value.trim().to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a display title for the current run (e.g. `"CI #42"`).
+    pub fn current_run_display_title(&self) -> Option<String> {
+        self.tree_items.get(self.cursor).and_then(|item| {
+            self.runs
+                .get(item.run_idx)
+                .map(|r| format!("{} #{}", r.name, r.number))
+        })
+    }
+
+    /// Remove a run by ID: prune expanded sets, log cache, run errors,
+    /// close any overlay referencing this run, rebuild tree, and clamp cursor.
+    pub fn remove_run(&mut self, run_id: u64) {
+        let Some(idx) = self.runs.iter().position(|r| r.database_id == run_id) else {
+            return;
+        };
+        self.anchor_cursor();
+        self.runs.remove(idx);
+
+        // Prune expanded state
+        self.expanded_runs.remove(&run_id);
+        self.expanded_jobs.retain(|(r, _)| *r != run_id);
+        self.toggled_groups.retain(|(r, _)| *r != run_id);
+
+        // Prune log cache entries for this run
         self.log_cache.retain(|(r, _), _| *r != run_id);
+        self.log_scroll.retain(|(r, _), _| *r != run_id);
 
         // Prune run errors
         self.run_errors.remove(&run_id);
+        self.selected_runs.remove(&run_id);
 
         // Close overlay if it references this run
         let should_close = match &self.overlay {
             ActiveOverlay::Log(o) => o.run_id == run_id,
             ActiveOverlay::Confirm(o) => match o.action {
-                ConfirmAction::CancelRun(id) | ConfirmAction::DeleteRun(id) => id == run_id,
+                ConfirmAction::CancelRun(id)
+                | ConfirmAction::DeleteRun(id)
+                | ConfirmAction::ApproveRun(id)
+                | ConfirmAction::RerunRun(id) => id == run_id,
+                ConfirmAction::DispatchWorkflow { .. } | ConfirmAction::Batch { .. } => false,
             },
             _ => false,
         };
@@ -872,17 +3209,58 @@ impl AppState {
         self.rebuild_tree();
     }
 
+    /// Id of the run under the cursor (or its placeholder row) if its job fetch failed.
+    pub fn current_run_error(&self) -> Option<u64> {
+        let item = self.tree_items.get(self.cursor)?;
+        let run_id = self.run_id_for(item.run_idx)?;
+        self.run_errors.contains_key(&run_id).then_some(run_id)
+    }
+
+    /// Clears the cursor run's fetch error and returns its id for the caller to
+    /// re-fetch jobs. The run is expanded so the `Loading…` row shows progress.
+    pub fn retry_current(&mut self) -> Option<u64> {
+        let run_id = self.current_run_error()?;
+        self.run_errors.remove(&run_id);
+        self.expanded_runs.insert(run_id);
+        self.rebuild_tree();
+        Some(run_id)
+    }
+
+    /// `J`: drops the cursor run's jobs and expands it so the tree shows them
+    /// loading; the caller fetches. Unlike a poll, nothing is carried over.
+    pub fn refresh_current_jobs(&mut self) -> Option<u64> {
+        let item = self.tree_items.get(self.cursor)?;
+        let run = self.runs.get_mut(item.run_idx)?;
+        let run_id = run.database_id;
+        run.jobs = None;
+        self.run_errors.remove(&run_id);
+        self.expanded_runs.insert(run_id);
+        self.selected_run_id = Some(run_id);
+        self.selected_row_offset = 0;
+        self.rebuild_tree();
+        Some(run_id)
+    }
+
     pub fn current_item_ids(&self) -> Option<(u64, Option<u64>)> {
         let item = self.tree_items.get(self.cursor)?;
         let run = self.runs.get(item.run_idx)?;
         let run_id = run.database_id;
         match item.level {
-            TreeLevel::Run => Some((run_id, None)),
+            TreeLevel::Run | TreeLevel::Group => Some((run_id, None)),
             TreeLevel::Job | TreeLevel::Step => {
                 let job = run.jobs.as_ref()?.get(item.job_idx?)?;
                 Some((run_id, job.database_id))
             }
-            TreeLevel::Loading => None,
+            TreeLevel::Branch | TreeLevel::Loading => None,
+        }
+    }
+
+    /// `(number, name)` of the step under the cursor, for step-level logs.
+    pub fn current_item_step(&self) -> Option<(u64, String)> {
+        let item = self.tree_items.get(self.cursor)?;
+        match self.resolve_item(item)? {
+            ResolvedItem::Step(step) => Some((step.number, step.name.clone())),
+            _ => None,
         }
     }
 
@@ -895,8 +3273,12 @@ impl AppState {
         };
         match resolved {
             ResolvedItem::Run(r) => r.conclusion == Some(Conclusion::Failure),
+            ResolvedItem::Group { jobs, .. } => jobs
+                .iter()
+                .any(|j| j.conclusion == Some(Conclusion::Failure)),
             ResolvedItem::Job(j) => j.conclusion == Some(Conclusion::Failure),
             ResolvedItem::Step(s) => s.conclusion == Some(Conclusion::Failure),
+            ResolvedItem::Branch { .. } => false,
         }
     }
 
@@ -914,25 +3296,333 @@ impl AppState {
 }
 
 #[cfg(test)]
+// Positional format args predate the lint; left as written.
+#[allow(clippy::uninlined_format_args)]
 mod tests {
     use super::*;
     use chrono::Utc;
 
     fn make_run(id: u64, status: RunStatus, conclusion: Option<Conclusion>) -> WorkflowRun {
-        WorkflowRun {
-            database_id: id,
-            display_title: format!("Run {}", id),
-            name: "CI".to_string(),
-            head_branch: "main".to_string(),
-            status,
-            conclusion,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            event: "push".to_string(),
-            number: id,
-            url: format!("https://github.com/test/repo/actions/runs/{}", id),
-            jobs: None,
+        crate::mock::run(id, status, conclusion)
+    }
+
+    #[test]
+    fn superseded_by_finds_next_run_of_same_workflow_and_branch() {
+        let base = Utc::now();
+        let at = |id: u64, mins: i64, conclusion: Option<Conclusion>| {
+            let mut run = make_run(id, RunStatus::Completed, conclusion);
+            run.created_at = base + chrono::Duration::minutes(mins);
+            run
+        };
+        let mut other_branch = at(5, 1, Some(Conclusion::Success));
+        other_branch.head_branch = "feature".into();
+        let mut other_workflow = at(6, 1, Some(Conclusion::Success));
+        other_workflow.name = "Deploy".into();
+        let runs = vec![
+            at(1, 0, Some(Conclusion::Cancelled)),
+            other_branch,
+            other_workflow,
+            at(3, 2, Some(Conclusion::Cancelled)),
+            at(4, 3, None),
+            at(2, 9, Some(Conclusion::Success)),
+        ];
+        let superseded = superseded_runs(&runs);
+        let by = |id: u64| superseded.get(&id).copied();
+        assert_eq!(by(1), Some(3), "oldest newer run wins");
+        assert_eq!(by(3), Some(4));
+        assert_eq!(by(2), None, "not cancelled");
+        // Newest in its group: a plain cancel
+        let lone = vec![at(7, 0, Some(Conclusion::Cancelled))];
+        assert!(superseded_runs(&lone).is_empty());
+        assert_eq!(conclusion_label(&lone[0], None), "Cancelled");
+        assert_eq!(
+            conclusion_label(&runs[0], by(1)),
+            "Cancelled (superseded by #3)"
+        );
+
+        let state = state_with_runs(runs.clone());
+        assert_eq!(state.superseded, superseded, "kept up by rebuild_tree");
+    }
+
+    fn with_jobs(mut run: WorkflowRun) -> WorkflowRun {
+        run.jobs = Some(vec![make_job("build", run.status, run.conclusion)]);
+        run
+    }
+
+    #[test]
+    fn carry_over_keeps_jobs_of_unchanged_runs() {
+        let old = vec![with_jobs(make_run(1, RunStatus::InProgress, None))];
+        let mut new = vec![make_run(1, RunStatus::InProgress, None)];
+        new[0].updated_at = old[0].updated_at;
+        let refetch = carry_over_jobs(&old, &mut new, |_| true);
+        assert!(refetch.is_empty());
+        assert!(new[0].jobs.is_some());
+    }
+
+    #[test]
+    fn carry_over_refetches_changed_runs_only_when_wanted() {
+        let old = vec![
+            with_jobs(make_run(1, RunStatus::InProgress, None)),
+            with_jobs(make_run(2, RunStatus::InProgress, None)),
+        ];
+        let mut new = vec![
+            make_run(1, RunStatus::InProgress, None),
+            make_run(2, RunStatus::InProgress, None),
+        ];
+        for run in &mut new {
+            run.updated_at = old[0].updated_at + chrono::Duration::seconds(5);
+        }
+        let refetch = carry_over_jobs(&old, &mut new, |r| r.database_id == 1);
+        assert_eq!(refetch, vec![1]);
+        assert!(new.iter().all(|r| r.jobs.is_none()), "stale jobs dropped");
+    }
+
+    #[test]
+    fn carry_over_ignores_updated_at_bumps_on_finished_runs() {
+        let done = || make_run(1, RunStatus::Completed, Some(Conclusion::Failure));
+        let old = vec![with_jobs(done())];
+        let mut bumped = done();
+        bumped.updated_at = old[0].updated_at + chrono::Duration::minutes(3);
+        let mut new = vec![bumped.clone()];
+        assert!(carry_over_jobs(&old, &mut new, |_| true).is_empty());
+        assert!(new[0].jobs.is_some());
+
+        // A rerun (new attempt) or a run that only just finished is different
+        let mut rerun = bumped.clone();
+        rerun.attempt = 2;
+        let mut new = vec![rerun];
+        assert_eq!(carry_over_jobs(&old, &mut new, |_| true), vec![1]);
+        let running = vec![with_jobs(make_run(1, RunStatus::InProgress, None))];
+        let mut new = vec![bumped];
+        assert_eq!(carry_over_jobs(&running, &mut new, |_| true), vec![1]);
+    }
+
+    #[test]
+    fn freshness_turns_stale_after_two_intervals() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        let now = std::time::Instant::now();
+        assert_eq!(state.freshness(now), None, "never polled");
+
+        state.poll_interval = 10;
+        state.last_poll = Some(now);
+        let at = |secs| now + std::time::Duration::from_secs(secs);
+        assert_eq!(
+            state.freshness(at(4)),
+            Some(("updated 4s ago".into(), false))
+        );
+        assert_eq!(
+            state.freshness(at(20)),
+            Some(("updated 20s ago".into(), false))
+        );
+        assert_eq!(
+            state.freshness(at(21)),
+            Some(("updated 21s ago".into(), true))
+        );
+
+        state.paused = true;
+        assert_eq!(
+            state.freshness(at(95)),
+            Some(("updated 1m 35s ago".into(), false))
+        );
+    }
+
+    #[test]
+    fn run_progress_counts_completed_jobs_of_running_runs() {
+        let mut run = make_run(1, RunStatus::InProgress, None);
+        assert_eq!(AppState::run_progress(&run), None, "jobs not fetched");
+        run.jobs = Some(Vec::new());
+        assert_eq!(AppState::run_progress(&run), None);
+
+        run.jobs = Some(vec![
+            make_job("lint", RunStatus::Completed, Some(Conclusion::Success)),
+            make_job("test", RunStatus::Completed, Some(Conclusion::Failure)),
+            make_job("build", RunStatus::InProgress, None),
+            make_job("deploy", RunStatus::Queued, None),
+            make_job("docs", RunStatus::Waiting, None),
+        ]);
+        assert_eq!(AppState::run_progress(&run), Some((2, 5)));
+
+        run.status = RunStatus::Completed;
+        assert_eq!(AppState::run_progress(&run), None, "only while running");
+    }
+
+    #[test]
+    fn notification_history_outlives_pruning() {
+        let mut state = state_with_runs(vec![]);
+        state.add_notification(1, "Run #1 succeeded".to_string());
+        state.add_notification(0, "Copied to clipboard".to_string());
+        for n in &mut state.notifications {
+            n.timestamp = std::time::Instant::now()
+                .checked_sub(std::time::Duration::from_secs(NOTIFICATION_TTL_SECS + 1))
+                .unwrap();
+        }
+        state.prune_notifications();
+        assert!(state.notifications.is_empty());
+        assert_eq!(state.notification_history.len(), 2);
+
+        state.open_notification_history();
+        let ActiveOverlay::Detail(overlay) = &state.overlay else {
+            panic!("expected the detail overlay");
+        };
+        assert_eq!(overlay.title, "Notifications (2)");
+        assert_eq!(overlay.lines[0].1, "Copied to clipboard", "newest first");
+        assert_eq!(overlay.lines[1].1, "Run #1 succeeded");
+    }
+
+    #[test]
+    fn poll_summary_stays_out_of_run_notifications() {
+        let mut state = state_with_runs(vec![]);
+        state.add_notification(7, "Run #7 failed".to_string());
+        state.set_poll_summary(Some("1 failed".to_string()));
+        state.set_poll_summary(None);
+        assert_eq!(state.notifications.len(), 1);
+        assert_eq!(state.notification_history.len(), 1);
+        assert_eq!(state.poll_summary.as_ref().unwrap().0, "1 failed");
+
+        state.poll_summary.as_mut().unwrap().1 = std::time::Instant::now()
+            .checked_sub(std::time::Duration::from_secs(NOTIFICATION_TTL_SECS + 1))
+            .unwrap();
+        state.prune_notifications();
+        assert!(state.poll_summary.is_none());
+        assert_eq!(
+            state.notifications.len(),
+            1,
+            "run notification is still fresh"
+        );
+    }
+
+    #[test]
+    fn notification_history_is_capped() {
+        let mut state = state_with_runs(vec![]);
+        for i in 0..NOTIFICATION_HISTORY_MAX + 5 {
+            state.add_notification(0, format!("n{i}"));
         }
+        assert_eq!(state.notification_history.len(), NOTIFICATION_HISTORY_MAX);
+        assert_eq!(state.notification_history.front().unwrap().message, "n5");
+    }
+
+    #[test]
+    fn empty_notification_history_says_so() {
+        let mut state = state_with_runs(vec![]);
+        state.open_notification_history();
+        let ActiveOverlay::Detail(overlay) = &state.overlay else {
+            panic!("expected the detail overlay");
+        };
+        assert_eq!(overlay.lines[0].1, "No notifications yet");
+    }
+
+    fn timed_job(name: &str, start_min: i64, end_min: i64) -> Job {
+        let base = Utc::now() - chrono::Duration::hours(1);
+        let mut job = make_job(name, RunStatus::Completed, Some(Conclusion::Success));
+        job.started_at = Some(base + chrono::Duration::minutes(start_min));
+        job.completed_at = Some(base + chrono::Duration::minutes(end_min));
+        job
+    }
+
+    #[test]
+    fn run_billable_seconds_counts_parallel_jobs_in_full() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        assert_eq!(
+            AppState::run_billable_seconds(&run),
+            None,
+            "jobs not fetched"
+        );
+        // Three 10-minute jobs side by side: 10m of wall clock, 30m of runners
+        run.jobs = Some(vec![
+            timed_job("a", 0, 10),
+            timed_job("b", 0, 10),
+            timed_job("c", 0, 10),
+        ]);
+        assert_eq!(AppState::run_billable_seconds(&run), Some(1800));
+    }
+
+    #[test]
+    fn run_billable_seconds_sums_serial_jobs_and_skips_unfinished() {
+        let mut run = make_run(1, RunStatus::InProgress, None);
+        let mut running = make_job("deploy", RunStatus::InProgress, None);
+        running.completed_at = None;
+        run.jobs = Some(vec![
+            timed_job("build", 0, 4),
+            timed_job("test", 4, 9),
+            running,
+        ]);
+        assert_eq!(AppState::run_billable_seconds(&run), Some(540));
+
+        let mut queued = make_job("lint", RunStatus::Queued, None);
+        queued.started_at = None;
+        queued.completed_at = None;
+        run.jobs = Some(vec![queued]);
+        assert_eq!(AppState::run_billable_seconds(&run), None);
+    }
+
+    #[test]
+    fn job_conclusion_counts_groups_mixed_jobs() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Failure));
+        assert_eq!(AppState::job_conclusion_counts(&run), None);
+
+        run.jobs = Some(vec![
+            make_job("a", RunStatus::Completed, Some(Conclusion::Success)),
+            make_job("b", RunStatus::Completed, Some(Conclusion::Failure)),
+            make_job("c", RunStatus::Completed, Some(Conclusion::Success)),
+            make_job("d", RunStatus::Completed, Some(Conclusion::TimedOut)),
+            make_job("e", RunStatus::Completed, Some(Conclusion::Failure)),
+            make_job("f", RunStatus::Completed, Some(Conclusion::Skipped)),
+            make_job("g", RunStatus::InProgress, None),
+        ]);
+        let counts = AppState::job_conclusion_counts(&run).unwrap();
+        assert_eq!(counts.len(), 4);
+        assert_eq!(counts[&Conclusion::Success], 2);
+        assert_eq!(counts[&Conclusion::Failure], 2);
+        assert_eq!(counts[&Conclusion::TimedOut], 1);
+        assert_eq!(counts[&Conclusion::Skipped], 1);
+        assert_eq!(counts.values().sum::<usize>(), 6, "unfinished job skipped");
+
+        run.jobs = Some(vec![]);
+        assert_eq!(AppState::job_conclusion_counts(&run), Some(HashMap::new()));
+    }
+
+    #[test]
+    fn first_failure_label_prefers_failed_step() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Failure));
+        assert_eq!(
+            AppState::first_failure_label(&run),
+            None,
+            "jobs not fetched"
+        );
+
+        let mut test = make_job("test", RunStatus::Completed, Some(Conclusion::Failure));
+        test.steps[1].conclusion = Some(Conclusion::Failure);
+        run.jobs = Some(vec![
+            make_job("lint", RunStatus::Completed, Some(Conclusion::Success)),
+            test,
+        ]);
+        assert_eq!(
+            AppState::first_failure_label(&run),
+            Some("Build".to_string())
+        );
+    }
+
+    #[test]
+    fn first_failure_label_falls_back_to_job_without_steps() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Failure));
+        let mut job = make_job("deploy", RunStatus::Completed, Some(Conclusion::Failure));
+        job.steps.clear();
+        run.jobs = Some(vec![job]);
+        assert_eq!(
+            AppState::first_failure_label(&run),
+            Some("deploy".to_string())
+        );
+    }
+
+    #[test]
+    fn first_failure_label_none_for_success_run() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        run.jobs = Some(vec![make_job(
+            "lint",
+            RunStatus::Completed,
+            Some(Conclusion::Success),
+        )]);
+        assert_eq!(AppState::first_failure_label(&run), None);
     }
 
     fn make_run_on_branch(id: u64, branch: &str) -> WorkflowRun {
@@ -944,9 +3634,6 @@ mod tests {
     fn make_job(name: &str, status: RunStatus, conclusion: Option<Conclusion>) -> Job {
         Job {
             database_id: Some(1),
-            name: name.to_string(),
-            status,
-            conclusion,
             started_at: Some(Utc::now()),
             completed_at: Some(Utc::now()),
             url: "https://github.com/test/repo/actions/runs/1/jobs/1".to_string(),
@@ -968,6 +3655,7 @@ mod tests {
                     completed_at: None,
                 },
             ],
+            ..crate::mock::job(name, status, conclusion)
         }
     }
 
@@ -978,55 +3666,559 @@ mod tests {
         state
     }
 
-    // --- Cursor movement ---
+    // --- Notify filter ---
 
     #[test]
-    fn cursor_up_at_zero_stays() {
-        let mut state = state_with_runs(vec![
-            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
-            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
-        ]);
-        assert_eq!(state.cursor, 0);
-        state.move_cursor_up();
-        assert_eq!(state.cursor, 0);
+    fn notify_on_parses_and_filters() {
+        assert_eq!("failures".parse::<NotifyOn>(), Ok(NotifyOn::Failures));
+        assert!("sometimes".parse::<NotifyOn>().is_err());
+        assert!(NotifyOn::All.allows(false));
+        assert!(NotifyOn::Failures.allows(true));
+        assert!(!NotifyOn::Failures.allows(false));
+        assert!(!NotifyOn::None.allows(true));
     }
 
-    #[test]
-    fn cursor_down_advances() {
-        let mut state = state_with_runs(vec![
-            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
-            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
-        ]);
-        state.move_cursor_down();
-        assert_eq!(state.cursor, 1);
-    }
+    // --- Poll intervals ---
 
     #[test]
-    fn cursor_down_at_end_stays() {
-        let mut state = state_with_runs(vec![
-            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
-            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
-        ]);
-        state.move_cursor_down();
-        state.move_cursor_down(); // at end
-        assert_eq!(state.cursor, 1);
+    fn intervals_reject_zero_and_misordered() {
+        assert!(Intervals::new(0, 10, 30).is_err());
+        assert!(Intervals::new(3, 10, 0).is_err());
+        assert!(Intervals::new(10, 3, 30).is_err());
+        assert!(Intervals::new(3, 40, 30).is_err());
+        assert_eq!(
+            Intervals::new(5, 5, 5),
+            Ok(Intervals {
+                active: 5,
+                recent: 5,
+                idle: 5
+            })
+        );
     }
 
     #[test]
-    fn cursor_up_on_empty_state() {
-        let mut state = state_with_runs(vec![]);
-        state.move_cursor_up();
-        assert_eq!(state.cursor, 0);
+    fn select_poll_interval_active_wins_over_recent() {
+        let cfg = Intervals::new(5, 20, 120).unwrap();
+        assert_eq!(select_poll_interval(true, true, &cfg), 5);
+        assert_eq!(select_poll_interval(true, false, &cfg), 5);
     }
 
     #[test]
-    fn cursor_down_on_empty_state() {
-        let mut state = state_with_runs(vec![]);
-        state.move_cursor_down();
-        assert_eq!(state.cursor, 0);
+    fn select_poll_interval_recent_then_idle() {
+        let cfg = Intervals::new(5, 20, 120).unwrap();
+        assert_eq!(select_poll_interval(false, true, &cfg), 20);
+        assert_eq!(select_poll_interval(false, false, &cfg), 120);
     }
 
-    // --- Tree rebuild ---
+    #[test]
+    fn select_poll_interval_defaults_match_constants() {
+        let cfg = Intervals::default();
+        assert_eq!(
+            select_poll_interval(true, false, &cfg),
+            POLL_INTERVAL_ACTIVE
+        );
+        assert_eq!(
+            select_poll_interval(false, true, &cfg),
+            POLL_INTERVAL_RECENT
+        );
+        assert_eq!(select_poll_interval(false, false, &cfg), POLL_INTERVAL_IDLE);
+    }
+
+    #[test]
+    fn recent_threshold_edges() {
+        assert!(is_recent(0));
+        assert!(is_recent(POLL_RECENT_THRESHOLD_SECS - 1));
+        assert!(!is_recent(POLL_RECENT_THRESHOLD_SECS));
+        assert!(!is_recent(POLL_RECENT_THRESHOLD_SECS + 1));
+    }
+
+    // --- Refresh debounce ---
+
+    #[test]
+    fn refresh_debounce_window() {
+        use std::time::Duration;
+        assert!(!is_refresh_debounced(None));
+        assert!(is_refresh_debounced(Some(Duration::ZERO)));
+        assert!(is_refresh_debounced(Some(Duration::from_millis(9_999))));
+        assert!(!is_refresh_debounced(Some(Duration::from_secs(
+            REFRESH_DEBOUNCE_SECS
+        ))));
+    }
+
+    #[test]
+    fn second_refresh_while_in_flight_is_skipped() {
+        let mut state = state_with_runs(vec![]);
+        assert!(state.try_begin_refresh());
+        assert!(!state.try_begin_refresh());
+        assert_eq!(state.loading_count, 1);
+        state.end_refresh();
+        assert!(!state.is_loading());
+        assert!(state.try_begin_refresh());
+    }
+
+    // --- Relative time ---
+
+    #[test]
+    fn format_relative_boundaries() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-01-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let ago = |secs| format_relative_to(now - chrono::Duration::seconds(secs), now);
+        assert_eq!(ago(0), "just now");
+        assert_eq!(ago(59), "just now");
+        assert_eq!(ago(60), "1m ago");
+        assert_eq!(ago(3599), "59m ago");
+        assert_eq!(ago(3600), "1h ago");
+        assert_eq!(ago(86_399), "23h ago");
+        assert_eq!(ago(86_400), "1d ago");
+        assert_eq!(ago(3 * 86_400 + 5), "3d ago");
+    }
+
+    #[test]
+    fn format_relative_future_is_just_now() {
+        let now = Utc::now();
+        assert_eq!(
+            format_relative_to(now + chrono::Duration::seconds(30), now),
+            "just now"
+        );
+    }
+
+    // --- Rate limit ---
+
+    #[test]
+    fn rate_limit_is_low_under_ten_percent() {
+        let quota = |remaining| RateLimit {
+            limit: 5000,
+            remaining,
+            reset: Utc::now(),
+        };
+        assert!(!quota(5000).is_low());
+        assert!(!quota(500).is_low());
+        assert!(quota(499).is_low());
+        assert!(quota(0).is_low());
+    }
+
+    // --- Display timezone ---
+
+    #[cfg(feature = "named-tz")]
+    #[test]
+    fn display_tz_converts_known_instant() {
+        let dt = chrono::DateTime::parse_from_rfc3339("2026-07-01T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(DisplayTz::Utc.format(dt), "2026-07-01 10:30:00 UTC");
+        let madrid: DisplayTz = "Europe/Madrid".parse().unwrap();
+        assert_eq!(madrid.format(dt), "2026-07-01 12:30:00 CEST");
+        let ny: DisplayTz = "America/New_York".parse().unwrap();
+        assert_eq!(ny.format(dt), "2026-07-01 06:30:00 EDT");
+        let kolkata: DisplayTz = "Asia/Kolkata".parse().unwrap();
+        assert_eq!(kolkata.format(dt), "2026-07-01 16:00:00 IST");
+    }
+
+    #[test]
+    fn display_tz_parse() {
+        assert_eq!("utc".parse::<DisplayTz>(), Ok(DisplayTz::Utc));
+        assert_eq!("local".parse::<DisplayTz>(), Ok(DisplayTz::Local));
+        assert!("Mars/Olympus".parse::<DisplayTz>().is_err());
+    }
+
+    #[test]
+    fn since_parse_relative_and_absolute() {
+        let now = Utc::now();
+        let day: Since = "24h".parse().unwrap();
+        assert_eq!(day.cutoff(now), now - chrono::Duration::hours(24));
+        assert_eq!(
+            "7d".parse::<Since>(),
+            Ok(Since::Within(chrono::Duration::days(7)))
+        );
+        assert_eq!(
+            "30m".parse::<Since>(),
+            Ok(Since::Within(chrono::Duration::minutes(30)))
+        );
+        assert_eq!(
+            "2w".parse::<Since>(),
+            Ok(Since::Within(chrono::Duration::weeks(2)))
+        );
+
+        let midnight = "2026-01-31T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!("2026-01-31".parse::<Since>(), Ok(Since::After(midnight)));
+        let with_offset = "2026-01-31T02:00:00+02:00".parse::<Since>().unwrap();
+        assert_eq!(with_offset.cutoff(now), midnight);
+    }
+
+    #[test]
+    fn since_parse_rejects_garbage() {
+        for bad in [
+            "",
+            "h",
+            "24",
+            "0h",
+            "-3d",
+            "5y",
+            "yesterday",
+            "2026-13-01",
+            "9999999999999w",
+        ] {
+            let err = bad.parse::<Since>().unwrap_err();
+            assert!(err.contains("expected a duration"), "{bad}: {err}");
+        }
+    }
+
+    #[test]
+    fn since_hides_older_runs() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        let mut old = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        old.created_at = Utc::now() - chrono::Duration::hours(30);
+        let fresh = make_run(2, RunStatus::Completed, Some(Conclusion::Success));
+        state.runs = vec![old, fresh];
+        state.config.since = Some("24h".parse().unwrap());
+        let ids: Vec<u64> = state
+            .filtered_runs_indices()
+            .iter()
+            .map(|(_, r)| r.database_id)
+            .collect();
+        assert_eq!(ids, vec![2]);
+    }
+
+    #[test]
+    fn focus_run_shows_only_that_run_expanded() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::InProgress, None),
+            make_run(3, RunStatus::Completed, Some(Conclusion::Failure)),
+        ]);
+        state.focus_run(2);
+        assert_eq!(state.tree_items[0].level, TreeLevel::Run);
+        assert_eq!(state.runs[state.tree_items[0].run_idx].database_id, 2);
+        assert!(state
+            .tree_items
+            .iter()
+            .all(|item| state.runs[item.run_idx].database_id == 2));
+        // Expanded, waiting on jobs
+        assert_eq!(state.tree_items[1].level, TreeLevel::Loading);
+        assert_eq!(state.watch_run_needing_jobs(), Some(2));
+
+        state.runs[1].jobs = Some(vec![make_job("build", RunStatus::InProgress, None)]);
+        state.rebuild_tree();
+        assert_eq!(state.watch_run_needing_jobs(), None);
+        assert_eq!(state.tree_items[1].level, TreeLevel::Job);
+    }
+
+    #[test]
+    fn watch_run_needing_jobs_waits_for_the_run() {
+        let mut state = state_with_runs(vec![make_run(
+            1,
+            RunStatus::Completed,
+            Some(Conclusion::Success),
+        )]);
+        assert_eq!(state.watch_run_needing_jobs(), None);
+        state.focus_run(9);
+        assert!(state.tree_items.is_empty());
+        assert_eq!(state.watch_run_needing_jobs(), None);
+    }
+
+    #[test]
+    fn auto_expand_picks_newest_in_progress_run() {
+        let base = Utc::now();
+        let mut runs = vec![
+            make_run(1, RunStatus::InProgress, None),
+            make_run(2, RunStatus::Queued, None),
+            make_run(3, RunStatus::InProgress, None),
+            make_run(4, RunStatus::Completed, Some(Conclusion::Failure)),
+        ];
+        for (run, mins) in runs.iter_mut().zip([10, 1, 5, 0]) {
+            run.created_at = base - chrono::Duration::minutes(mins);
+        }
+        assert_eq!(newest_in_progress(&runs).map(|r| r.database_id), Some(3));
+        assert!(newest_in_progress(&runs[1..2]).is_none());
+        assert!(newest_in_progress(&[]).is_none());
+
+        let mut state = state_with_runs(runs);
+        assert_eq!(state.auto_expand_newest_active(), None);
+        assert!(state.expanded_runs.is_empty());
+
+        state.config.auto_expand = true;
+        assert_eq!(state.auto_expand_newest_active(), Some(3));
+        assert!(state.expanded_runs.contains(&3));
+        assert_eq!(state.current_run_id(), Some(3));
+        // Only the first poll auto-expands
+        assert!(!state.config.auto_expand);
+        state.expanded_runs.clear();
+        assert_eq!(state.auto_expand_newest_active(), None);
+    }
+
+    // --- Duration labels ---
+
+    #[test]
+    fn queued_run_shows_queue_time() {
+        let mut run = make_run(1, RunStatus::Queued, None);
+        run.created_at = Utc::now() - chrono::Duration::seconds(125);
+        assert!(run.is_queued());
+        assert!(run.duration_label().starts_with("queued 2m"));
+    }
+
+    #[test]
+    fn running_run_shows_run_time() {
+        let mut run = make_run(1, RunStatus::InProgress, None);
+        run.created_at = Utc::now() - chrono::Duration::seconds(125);
+        assert!(!run.is_queued());
+        assert!(run.duration_label().starts_with("2m"));
+
+        run.status = RunStatus::Completed;
+        run.updated_at = run.created_at + chrono::Duration::seconds(30);
+        assert_eq!(run.duration_label(), "30s");
+    }
+
+    #[test]
+    fn unknown_timestamps_show_unknown_duration() {
+        let mut run = make_run(1, RunStatus::Queued, None);
+        run.timestamps_unknown = true;
+        assert_eq!(run.duration_label(), "unknown");
+        assert_eq!(run.elapsed_secs(), 0);
+    }
+
+    // --- Matrix grouping ---
+
+    fn matrix_run() -> WorkflowRun {
+        let names = [
+            "lint",
+            "test (ubuntu, 1.70)",
+            "test (macos, 1.70)",
+            "build (x86)",
+            "test (windows, stable)",
+        ];
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Failure));
+        run.jobs = Some(
+            names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let mut job = make_job(name, RunStatus::Completed, Some(Conclusion::Success));
+                    job.database_id = Some(i as u64 + 10);
+                    job
+                })
+                .collect(),
+        );
+        run.jobs.as_mut().unwrap()[2].conclusion = Some(Conclusion::Failure);
+        run
+    }
+
+    fn level_names(state: &AppState) -> Vec<(TreeLevel, String)> {
+        state
+            .tree_items
+            .iter()
+            .map(|item| {
+                let name = match state.resolve_item(item) {
+                    Some(ResolvedItem::Run(r)) => r.display_title.clone(),
+                    Some(ResolvedItem::Branch { name, .. } | ResolvedItem::Group { name, .. }) => {
+                        name.to_string()
+                    }
+                    Some(ResolvedItem::Job(j)) => j.name.clone(),
+                    Some(ResolvedItem::Step(s)) => s.name.clone(),
+                    None => String::new(),
+                };
+                (item.level, name)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matrix_base_parses_parenthesized_suffix() {
+        assert_eq!(matrix_base("test (ubuntu, 1.70)"), Some("test"));
+        assert_eq!(matrix_base("build"), None);
+        assert_eq!(matrix_base("deploy (prod"), None);
+        assert_eq!(matrix_base(" (x)"), None);
+    }
+
+    #[test]
+    fn matrix_jobs_flat_when_grouping_off() {
+        let mut state = state_with_runs(vec![matrix_run()]);
+        state.expanded_runs.insert(1);
+        state.rebuild_tree();
+        assert_eq!(state.tree_items.len(), 6);
+        assert!(state.tree_items[1..]
+            .iter()
+            .all(|i| i.level == TreeLevel::Job && !i.grouped));
+    }
+
+    #[test]
+    fn matrix_jobs_grouped_under_base_name() {
+        let mut state = state_with_runs(vec![matrix_run()]);
+        state.expanded_runs.insert(1);
+        state.toggle_matrix_groups();
+        // Singletons stay flat, the group sits where its first member was
+        assert_eq!(
+            level_names(&state),
+            vec![
+                (TreeLevel::Run, "Run 1".to_string()),
+                (TreeLevel::Job, "lint".to_string()),
+                (TreeLevel::Group, "test".to_string()),
+                (TreeLevel::Job, "build (x86)".to_string()),
+            ]
+        );
+
+        state.cursor = 2;
+        state.expand_current();
+        assert_eq!(state.tree_items.len(), 7);
+        let members: Vec<_> = state.tree_items[3..6].iter().collect();
+        assert!(members
+            .iter()
+            .all(|i| i.level == TreeLevel::Job && i.grouped));
+        assert_eq!(level_names(&state)[5].1, "test (windows, stable)");
+        assert_eq!(level_names(&state)[6].1, "build (x86)");
+    }
+
+    #[test]
+    fn matrix_group_aggregates_failure() {
+        let mut state = state_with_runs(vec![matrix_run()]);
+        state.expanded_runs.insert(1);
+        state.toggle_matrix_groups();
+        state.cursor = 2;
+        let Some(ResolvedItem::Group { jobs, .. }) = state.resolve_item(&state.tree_items[2])
+        else {
+            panic!("expected group");
+        };
+        assert_eq!(jobs.len(), 3);
+        assert_eq!(
+            aggregate_status(&jobs),
+            (RunStatus::Completed, Some(Conclusion::Failure))
+        );
+        assert!(state.current_item_is_failed());
+    }
+
+    #[test]
+    fn aggregate_status_running_and_success() {
+        let running = make_job("a", RunStatus::InProgress, None);
+        let done = make_job("b", RunStatus::Completed, Some(Conclusion::Success));
+        assert_eq!(
+            aggregate_status(&[&running, &done]),
+            (RunStatus::InProgress, None)
+        );
+        assert_eq!(
+            aggregate_status(&[&done, &done]),
+            (RunStatus::Completed, Some(Conclusion::Success))
+        );
+    }
+
+    #[test]
+    fn stage_jobs_grouped_in_api_order() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        let stages = [
+            ("compile", "build"),
+            ("unit", "test"),
+            ("lint", "build"),
+            ("e2e", "test"),
+        ];
+        run.jobs = Some(
+            stages
+                .iter()
+                .enumerate()
+                .map(|(i, (name, stage))| {
+                    let mut job = make_job(name, RunStatus::Completed, Some(Conclusion::Success));
+                    job.database_id = Some(i as u64 + 10);
+                    job.stage = Some((*stage).to_string());
+                    job
+                })
+                .collect(),
+        );
+        let mut state = state_with_runs(vec![run]);
+        state.expanded_runs.insert(1);
+        state.rebuild_tree();
+        // Stages start expanded, even with matrix grouping off
+        let names: Vec<String> = level_names(&state).into_iter().map(|(_, n)| n).collect();
+        assert_eq!(
+            names,
+            vec!["Run 1", "build", "compile", "lint", "test", "unit", "e2e"]
+        );
+        assert_eq!(state.tree_items[1].level, TreeLevel::Group);
+        assert!(state.tree_items[2].grouped);
+
+        state.cursor = 1;
+        state.collapse_current();
+        let names: Vec<String> = level_names(&state).into_iter().map(|(_, n)| n).collect();
+        assert_eq!(names, vec!["Run 1", "build", "test", "unit", "e2e"]);
+    }
+
+    #[test]
+    fn jobs_without_stage_stay_flat() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        run.jobs = Some(vec![
+            make_job("build", RunStatus::Completed, Some(Conclusion::Success)),
+            make_job("test", RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        let mut state = state_with_runs(vec![run]);
+        state.expanded_runs.insert(1);
+        state.rebuild_tree();
+        assert!(state.tree_items[1..]
+            .iter()
+            .all(|i| i.level == TreeLevel::Job && !i.grouped));
+    }
+
+    #[test]
+    fn collapse_on_grouped_job_moves_to_group() {
+        let mut state = state_with_runs(vec![matrix_run()]);
+        state.expanded_runs.insert(1);
+        state.toggle_matrix_groups();
+        state.cursor = 2;
+        state.expand_current();
+        state.cursor = 4;
+        state.collapse_current();
+        assert_eq!(state.cursor, 2);
+        state.collapse_current();
+        assert_eq!(state.tree_items.len(), 4);
+        state.collapse_current();
+        assert_eq!(state.cursor, 0);
+    }
+
+    // --- Cursor movement ---
+
+    #[test]
+    fn cursor_up_at_zero_stays() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        assert_eq!(state.cursor, 0);
+        state.move_cursor_up();
+        assert_eq!(state.cursor, 0);
+    }
+
+    #[test]
+    fn cursor_down_advances() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        state.move_cursor_down();
+        assert_eq!(state.cursor, 1);
+    }
+
+    #[test]
+    fn cursor_down_at_end_stays() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        state.move_cursor_down();
+        state.move_cursor_down(); // at end
+        assert_eq!(state.cursor, 1);
+    }
+
+    #[test]
+    fn cursor_up_on_empty_state() {
+        let mut state = state_with_runs(vec![]);
+        state.move_cursor_up();
+        assert_eq!(state.cursor, 0);
+    }
+
+    #[test]
+    fn cursor_down_on_empty_state() {
+        let mut state = state_with_runs(vec![]);
+        state.move_cursor_down();
+        assert_eq!(state.cursor, 0);
+    }
+
+    // --- Tree rebuild ---
 
     #[test]
     fn rebuild_creates_run_items() {
@@ -1197,6 +4389,33 @@ mod tests {
         assert_eq!(state.tree_items[0].run_idx, 1);
     }
 
+    #[test]
+    fn filter_failures_only_hides_success_and_active() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(3, RunStatus::InProgress, None),
+        ]);
+        state.filter = FilterMode::FailuresOnly;
+        state.rebuild_tree();
+        assert_eq!(state.tree_items.len(), 1);
+        assert_eq!(state.tree_items[0].run_idx, 1);
+    }
+
+    #[test]
+    fn filter_failures_only_includes_all_failure_conclusions() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::TimedOut)),
+            make_run(3, RunStatus::Completed, Some(Conclusion::StartupFailure)),
+            make_run(4, RunStatus::Completed, Some(Conclusion::Cancelled)),
+            make_run(5, RunStatus::Completed, Some(Conclusion::Skipped)),
+        ]);
+        state.filter = FilterMode::FailuresOnly;
+        state.rebuild_tree();
+        assert_eq!(state.tree_items.len(), 3);
+    }
+
     #[test]
     fn filter_active_includes_all_active_statuses() {
         let mut state = state_with_runs(vec![
@@ -1242,25 +4461,504 @@ mod tests {
         state.cycle_filter();
         assert_eq!(state.filter, FilterMode::CurrentBranch);
         state.cycle_filter();
+        assert_eq!(state.filter, FilterMode::FailuresOnly);
+        state.cycle_filter();
         assert_eq!(state.filter, FilterMode::All);
     }
 
-    // --- Quick select ---
-
     #[test]
-    fn quick_select_first() {
-        let mut state = state_with_runs(vec![
-            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
-            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
-            make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
-        ]);
-        state.quick_select(1);
-        assert_eq!(state.cursor, 0);
+    fn cycle_filter_skips_branch_without_one() {
+        let mut state = AppState::new("test/repo".to_string(), None, 20, None);
+        state.cycle_filter();
+        assert_eq!(state.filter, FilterMode::ActiveOnly);
+        state.cycle_filter();
+        assert_eq!(state.filter, FilterMode::FailuresOnly);
     }
 
-    #[test]
-    fn quick_select_second() {
-        let mut state = state_with_runs(vec![
+    // --- Sort modes ---
+
+    fn sorted_ids(state: &AppState) -> Vec<u64> {
+        state
+            .filtered_runs_indices()
+            .iter()
+            .map(|(_, r)| r.database_id)
+            .collect()
+    }
+
+    fn sort_fixture() -> AppState {
+        let now = Utc::now();
+        let mut short = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        short.created_at = now - chrono::Duration::seconds(600);
+        short.updated_at = short.created_at + chrono::Duration::seconds(30);
+        let mut failed = make_run(2, RunStatus::Completed, Some(Conclusion::Failure));
+        failed.created_at = now - chrono::Duration::seconds(600);
+        failed.updated_at = failed.created_at + chrono::Duration::seconds(300);
+        let mut running = make_run(3, RunStatus::InProgress, None);
+        running.created_at = now - chrono::Duration::seconds(120);
+        let mut queued = make_run(4, RunStatus::Queued, None);
+        queued.created_at = now - chrono::Duration::seconds(10);
+        state_with_runs(vec![short, failed, running, queued])
+    }
+
+    #[test]
+    fn sort_recent_keeps_api_order() {
+        assert_eq!(sorted_ids(&sort_fixture()), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn sort_duration_longest_first_counting_running_time() {
+        let mut state = sort_fixture();
+        state.cycle_sort();
+        assert_eq!(state.sort_mode, SortMode::Duration);
+        assert_eq!(sorted_ids(&state), vec![2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn sort_status_puts_active_runs_first() {
+        let mut state = sort_fixture();
+        state.sort_mode = SortMode::Status;
+        state.rebuild_tree();
+        assert_eq!(sorted_ids(&state), vec![3, 4, 2, 1]);
+        assert_eq!(state.runs[state.tree_items[0].run_idx].database_id, 3);
+    }
+
+    /// `(branch header or run id, level)` per tree row.
+    fn branch_rows(state: &AppState) -> Vec<(String, TreeLevel)> {
+        state
+            .tree_items
+            .iter()
+            .map(|item| match &item.branch {
+                Some(name) => (name.clone(), item.level),
+                None => (state.runs[item.run_idx].database_id.to_string(), item.level),
+            })
+            .collect()
+    }
+
+    fn branch_fixture() -> AppState {
+        let base = Utc::now();
+        let mut runs = vec![
+            make_run_on_branch(5, "main"),
+            make_run_on_branch(4, "feature-a"),
+            make_run_on_branch(3, "main"),
+            make_run_on_branch(2, "feature-b"),
+        ];
+        // feature-b saw the latest activity, then main, then feature-a
+        for (run, mins) in runs.iter_mut().zip([2, 9, 20, 1]) {
+            run.updated_at = base - chrono::Duration::minutes(mins);
+        }
+        let mut state = state_with_runs(runs);
+        state.toggle_group_by_branch();
+        state
+    }
+
+    #[test]
+    fn group_by_branch_nests_runs_under_headers() {
+        let state = branch_fixture();
+        let b = |s: &str| (s.to_string(), TreeLevel::Branch);
+        let r = |id: u64| (id.to_string(), TreeLevel::Run);
+        assert_eq!(
+            branch_rows(&state),
+            vec![
+                b("feature-b"),
+                r(2),
+                b("main"),
+                r(5),
+                r(3),
+                b("feature-a"),
+                r(4),
+            ]
+        );
+        let Some(ResolvedItem::Branch { name, runs }) = state.resolve_item(&state.tree_items[2])
+        else {
+            panic!("expected a branch row");
+        };
+        assert_eq!(name, "main");
+        assert_eq!(runs.len(), 2);
+    }
+
+    #[test]
+    fn branch_header_has_no_run_to_act_on() {
+        let mut state = branch_fixture();
+        state.cursor = 0;
+        assert_eq!(state.current_run_id(), None);
+        assert_eq!(state.current_item_ids(), None);
+        state.move_cursor_down();
+        assert_eq!(state.current_run_id(), Some(2));
+    }
+
+    #[test]
+    fn collapsing_a_branch_hides_its_runs() {
+        let mut state = branch_fixture();
+        state.cursor = 2; // main
+        state.toggle_expand();
+        let rows = branch_rows(&state);
+        assert_eq!(rows.len(), 5);
+        assert!(!rows.contains(&("5".to_string(), TreeLevel::Run)));
+        assert!(!rows.contains(&("3".to_string(), TreeLevel::Run)));
+        assert_eq!(state.cursor, 2);
+        assert!(!state.tree_items[2].expanded);
+
+        state.expand_current();
+        assert_eq!(branch_rows(&state).len(), 7);
+
+        // `h` on a collapsed run climbs to its branch, a second `h` folds it
+        state.cursor = 4; // run 3 under main
+        state.collapse_current();
+        assert_eq!(state.cursor, 2);
+        state.collapse_current();
+        assert_eq!(branch_rows(&state).len(), 5);
+
+        state.toggle_group_by_branch();
+        assert!(state
+            .tree_items
+            .iter()
+            .all(|item| item.level == TreeLevel::Run));
+        assert_eq!(state.tree_items.len(), 4);
+    }
+
+    #[test]
+    fn reverse_flips_run_order_with_jobs_attached() {
+        let mut state = sort_fixture();
+        state.runs[0].jobs = Some(vec![
+            make_job("build", RunStatus::Completed, Some(Conclusion::Success)),
+            make_job("test", RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        state.expanded_runs.insert(1);
+        state.rebuild_tree();
+        state.move_cursor_down();
+        assert_eq!(state.tree_items[state.cursor].level, TreeLevel::Job);
+
+        state.toggle_reverse();
+        assert_eq!(sorted_ids(&state), vec![4, 3, 2, 1]);
+        let rows: Vec<(u64, TreeLevel)> = state
+            .tree_items
+            .iter()
+            .map(|item| (state.runs[item.run_idx].database_id, item.level))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (4, TreeLevel::Run),
+                (3, TreeLevel::Run),
+                (2, TreeLevel::Run),
+                (1, TreeLevel::Run),
+                (1, TreeLevel::Job),
+                (1, TreeLevel::Job),
+            ]
+        );
+        // Cursor stays on the same job row; numbering follows the new order
+        assert_eq!(state.cursor, 4);
+        state.quick_select(1);
+        assert_eq!(state.current_run_id(), Some(4));
+
+        // Independent of the sort mode
+        state.sort_mode = SortMode::Duration;
+        state.rebuild_tree();
+        assert_eq!(sorted_ids(&state), vec![4, 1, 3, 2]);
+        state.toggle_reverse();
+        assert_eq!(sorted_ids(&state), vec![2, 3, 1, 4]);
+    }
+
+    fn run_by(id: u64, actor: Option<&str>) -> WorkflowRun {
+        let mut run = make_run(id, RunStatus::Completed, Some(Conclusion::Success));
+        run.actor = actor.map(str::to_string);
+        run
+    }
+
+    #[test]
+    fn actor_filter_keeps_only_that_login() {
+        let mut state = state_with_runs(vec![
+            run_by(1, Some("alice")),
+            run_by(2, Some("bob")),
+            run_by(3, None),
+            run_by(4, Some("alice")),
+        ]);
+        assert_eq!(sorted_ids(&state), vec![1, 2, 3, 4]);
+
+        state.actor_filter = Some("alice".to_string());
+        assert_eq!(sorted_ids(&state), vec![1, 4], "unknown actor excluded");
+        state.actor_filter = Some("carol".to_string());
+        assert!(sorted_ids(&state).is_empty());
+    }
+
+    #[test]
+    fn toggle_my_runs_needs_a_known_user_and_actors() {
+        let mut state = state_with_runs(vec![run_by(1, Some("alice")), run_by(2, Some("bob"))]);
+        state.toggle_my_runs();
+        assert_eq!(state.actor_filter, None);
+        assert!(state.error.is_some());
+
+        state.current_user = Some("alice".to_string());
+        state.toggle_my_runs();
+        assert_eq!(state.actor_filter.as_deref(), Some("alice"));
+        assert_eq!(state.tree_items.len(), 1);
+        state.toggle_my_runs();
+        assert_eq!(state.actor_filter, None);
+        assert_eq!(state.tree_items.len(), 2);
+
+        let mut anonymous = state_with_runs(vec![run_by(1, None)]);
+        anonymous.current_user = Some("alice".to_string());
+        anonymous.toggle_my_runs();
+        assert_eq!(anonymous.actor_filter, None, "backend without actors");
+    }
+
+    #[test]
+    fn cycle_sort_wraps_around() {
+        let mut state = sort_fixture();
+        state.cycle_sort();
+        state.cycle_sort();
+        state.cycle_sort();
+        assert_eq!(state.sort_mode, SortMode::Recent);
+    }
+
+    // --- Collapse / expand all ---
+
+    #[test]
+    fn collapse_all_clears_expanded_sets() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        run.jobs = Some(vec![make_job(
+            "build",
+            RunStatus::Completed,
+            Some(Conclusion::Success),
+        )]);
+        let mut state = state_with_runs(vec![
+            run,
+            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        state.expanded_runs.insert(1);
+        state.expanded_jobs.insert((1, 1));
+        state.rebuild_tree();
+        state.cursor = state.tree_items.len() - 1;
+
+        state.collapse_all();
+        assert!(state.expanded_runs.is_empty());
+        assert!(state.expanded_jobs.is_empty());
+        assert_eq!(state.tree_items.len(), 2);
+        assert!(state.cursor < state.tree_items.len());
+    }
+
+    #[test]
+    fn collapse_all_keeps_cursor_on_parent_run() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        run.jobs = Some(vec![
+            make_job("build", RunStatus::Completed, Some(Conclusion::Success)),
+            make_job("test", RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        let mut state = state_with_runs(vec![
+            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
+            run,
+        ]);
+        state.expanded_runs.insert(1);
+        state.rebuild_tree();
+        state.cursor = 3; // second job of run 1
+        state.collapse_all();
+        assert_eq!(state.cursor, 1);
+    }
+
+    #[test]
+    fn expand_all_reports_runs_needing_fetch() {
+        let mut fetched = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        fetched.jobs = Some(vec![make_job(
+            "build",
+            RunStatus::Completed,
+            Some(Conclusion::Success),
+        )]);
+        let mut state = state_with_runs(vec![
+            fetched,
+            make_run(2, RunStatus::InProgress, None),
+            make_run(3, RunStatus::Queued, None),
+        ]);
+        let needs_fetch = state.expand_all();
+        assert_eq!(needs_fetch, vec![2, 3]);
+        assert_eq!(state.expanded_runs.len(), 3);
+        // run 1 + job, run 2 + loading, run 3 + loading
+        assert_eq!(state.tree_items.len(), 6);
+    }
+
+    // --- Failure navigation ---
+
+    fn failure_mix_state() -> AppState {
+        state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(4, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(5, RunStatus::InProgress, None),
+        ])
+    }
+
+    #[test]
+    fn next_failure_moves_forward() {
+        let mut state = failure_mix_state();
+        state.next_failure();
+        assert_eq!(state.cursor, 1);
+        state.next_failure();
+        assert_eq!(state.cursor, 3);
+    }
+
+    #[test]
+    fn next_failure_wraps_around() {
+        let mut state = failure_mix_state();
+        state.cursor = 4;
+        state.next_failure();
+        assert_eq!(state.cursor, 1);
+    }
+
+    #[test]
+    fn prev_failure_moves_backward_and_wraps() {
+        let mut state = failure_mix_state();
+        state.cursor = 2;
+        state.prev_failure();
+        assert_eq!(state.cursor, 1);
+        state.prev_failure();
+        assert_eq!(state.cursor, 3);
+    }
+
+    #[test]
+    fn next_failure_single_failure_stays_put() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        state.next_failure();
+        assert_eq!(state.cursor, 0);
+    }
+
+    #[test]
+    fn next_failure_no_failures_is_noop() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::InProgress, None),
+        ]);
+        state.cursor = 1;
+        state.next_failure();
+        assert_eq!(state.cursor, 1);
+        state.prev_failure();
+        assert_eq!(state.cursor, 1);
+    }
+
+    #[test]
+    fn next_failure_does_not_expand() {
+        let mut state = failure_mix_state();
+        state.next_failure();
+        assert!(state.expanded_runs.is_empty());
+        assert_eq!(state.tree_items.len(), 5);
+    }
+
+    #[test]
+    fn next_failure_on_empty_tree_is_noop() {
+        let mut state = state_with_runs(vec![]);
+        state.next_failure();
+        state.prev_failure();
+        assert_eq!(state.cursor, 0);
+    }
+
+    fn make_run_with_event(id: u64, event: &str) -> WorkflowRun {
+        let mut run = make_run(id, RunStatus::Completed, Some(Conclusion::Success));
+        run.event = event.to_string();
+        run
+    }
+
+    #[test]
+    fn event_filter_shows_only_matching_runs() {
+        let mut state = state_with_runs(vec![
+            make_run_with_event(1, "push"),
+            make_run_with_event(2, "schedule"),
+            make_run_with_event(3, "push"),
+        ]);
+        state.event_filter = Some("push".to_string());
+        state.rebuild_tree();
+        assert_eq!(state.tree_items.len(), 2);
+        assert_eq!(state.tree_items[0].run_idx, 0);
+        assert_eq!(state.tree_items[1].run_idx, 2);
+    }
+
+    #[test]
+    fn event_filter_combines_with_filter_mode() {
+        let mut state = state_with_runs(vec![
+            make_run_with_event(1, "push"),
+            make_run(2, RunStatus::InProgress, None),
+        ]);
+        state.filter = FilterMode::ActiveOnly;
+        state.event_filter = Some("push".to_string());
+        state.rebuild_tree();
+        assert_eq!(state.tree_items.len(), 1);
+        assert_eq!(state.tree_items[0].run_idx, 1);
+    }
+
+    #[test]
+    fn cycle_event_filter_visits_distinct_events_then_clears() {
+        let mut state = state_with_runs(vec![
+            make_run_with_event(1, "schedule"),
+            make_run_with_event(2, "push"),
+            make_run_with_event(3, "push"),
+        ]);
+        state.cycle_event_filter();
+        assert_eq!(state.event_filter.as_deref(), Some("push"));
+        assert_eq!(state.tree_items.len(), 2);
+        state.cycle_event_filter();
+        assert_eq!(state.event_filter.as_deref(), Some("schedule"));
+        assert_eq!(state.tree_items.len(), 1);
+        state.cycle_event_filter();
+        assert_eq!(state.event_filter, None);
+        assert_eq!(state.tree_items.len(), 3);
+    }
+
+    #[test]
+    fn cycle_event_filter_resets_when_event_disappears() {
+        let mut state = state_with_runs(vec![make_run_with_event(1, "push")]);
+        state.event_filter = Some("schedule".to_string());
+        state.cycle_event_filter();
+        assert_eq!(state.event_filter, None);
+    }
+
+    #[test]
+    fn toggle_desktop_notify_flips_flag_and_reports() {
+        let mut state = state_with_runs(vec![]);
+        assert!(state.desktop_notify);
+        state.toggle_desktop_notify();
+        assert!(!state.desktop_notify);
+        assert_eq!(state.notifications[0].message, "Notifications muted");
+        state.toggle_desktop_notify();
+        assert!(state.desktop_notify);
+        assert_eq!(state.notifications[1].message, "Notifications unmuted");
+    }
+
+    #[test]
+    fn toggle_pause_flips_flag() {
+        let mut state = state_with_runs(vec![]);
+        assert!(!state.paused);
+        state.toggle_pause();
+        assert!(state.paused);
+        state.toggle_pause();
+        assert!(!state.paused);
+    }
+
+    #[test]
+    fn rate_limit_retry_in_counts_down() {
+        let mut state = state_with_runs(vec![]);
+        assert_eq!(state.rate_limit_retry_in(), None);
+        state.set_rate_limited(120);
+        let secs = state.rate_limit_retry_in().unwrap();
+        assert!((119..=120).contains(&secs));
+    }
+
+    // --- Quick select ---
+
+    #[test]
+    fn quick_select_first() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        state.quick_select(1);
+        assert_eq!(state.cursor, 0);
+    }
+
+    #[test]
+    fn quick_select_second() {
+        let mut state = state_with_runs(vec![
             make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
             make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
             make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
@@ -1394,187 +5092,1306 @@ mod tests {
             job_idx: None,
             step_idx: None,
             expanded: false,
+            grouped: false,
+            branch: None,
         };
         assert!(state.resolve_item(&item).is_none());
     }
 
     #[test]
-    fn cursor_clamped_on_tree_shrink() {
+    fn cursor_clamped_on_tree_shrink() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::InProgress, None),
+            make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        state.cursor = 2;
+        // Switch to active-only filter: only 1 item
+        state.filter = FilterMode::ActiveOnly;
+        state.rebuild_tree();
+        assert_eq!(state.cursor, 0);
+    }
+
+    #[test]
+    fn spinner_wraps() {
+        let mut state = state_with_runs(vec![]);
+        for _ in 0..SPINNER_FRAME_COUNT {
+            state.advance_spinner();
+        }
+        assert_eq!(state.spinner_frame, 0); // wrapped back
+    }
+
+    #[test]
+    fn current_run_url_returns_url() {
+        let state = state_with_runs(vec![make_run(
+            42,
+            RunStatus::Completed,
+            Some(Conclusion::Success),
+        )]);
+        assert_eq!(
+            state.current_run_url(),
+            Some("https://github.com/test/repo/actions/runs/42")
+        );
+    }
+
+    #[test]
+    fn current_run_id_returns_id() {
+        let state = state_with_runs(vec![make_run(
+            42,
+            RunStatus::Completed,
+            Some(Conclusion::Success),
+        )]);
+        assert_eq!(state.current_run_id(), Some(42));
+    }
+
+    #[test]
+    fn current_run_url_empty() {
+        let state = state_with_runs(vec![]);
+        assert_eq!(state.current_run_url(), None);
+    }
+
+    // --- Log overlay tests ---
+
+    #[test]
+    fn current_item_is_failed_on_failed_run() {
+        let state = state_with_runs(vec![make_run(
+            1,
+            RunStatus::Completed,
+            Some(Conclusion::Failure),
+        )]);
+        assert!(state.current_item_is_failed());
+    }
+
+    #[test]
+    fn current_item_is_failed_on_success_run() {
+        let state = state_with_runs(vec![make_run(
+            1,
+            RunStatus::Completed,
+            Some(Conclusion::Success),
+        )]);
+        assert!(!state.current_item_is_failed());
+    }
+
+    #[test]
+    fn current_item_is_failed_on_failed_job() {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Failure));
+        run.jobs = Some(vec![make_job(
+            "build",
+            RunStatus::Completed,
+            Some(Conclusion::Failure),
+        )]);
+        let mut state = state_with_runs(vec![run]);
+        state.expanded_runs.insert(1);
+        state.rebuild_tree();
+        state.cursor = 1; // on the job
+        assert!(state.current_item_is_failed());
+    }
+
+    #[test]
+    fn current_item_is_failed_empty() {
+        let state = state_with_runs(vec![]);
+        assert!(!state.current_item_is_failed());
+    }
+
+    #[test]
+    fn current_item_ids_run() {
+        let state = state_with_runs(vec![make_run(
+            42,
+            RunStatus::Completed,
+            Some(Conclusion::Failure),
+        )]);
+        assert_eq!(state.current_item_ids(), Some((42, None)));
+    }
+
+    #[test]
+    fn current_item_ids_job() {
+        let mut run = make_run(42, RunStatus::Completed, Some(Conclusion::Failure));
+        let mut job = make_job("build", RunStatus::Completed, Some(Conclusion::Failure));
+        job.database_id = Some(99);
+        run.jobs = Some(vec![job]);
+        let mut state = state_with_runs(vec![run]);
+        state.expanded_runs.insert(42);
+        state.rebuild_tree();
+        state.cursor = 1; // on the job
+        assert_eq!(state.current_item_ids(), Some((42, Some(99))));
+    }
+
+    fn state_with_expanded_job() -> AppState {
+        let mut run = make_run(42, RunStatus::Completed, Some(Conclusion::Failure));
+        let mut job = make_job("build", RunStatus::Completed, Some(Conclusion::Failure));
+        job.database_id = Some(99);
+        job.url = "https://github.com/test/repo/actions/runs/42/job/99".to_string();
+        run.jobs = Some(vec![job]);
+        let mut state = state_with_runs(vec![run]);
+        state.expanded_runs.insert(42);
+        state.expanded_jobs.insert((42, 99));
+        state.rebuild_tree();
+        state
+    }
+
+    #[test]
+    fn current_item_url_on_run() {
+        let state = state_with_expanded_job();
+        assert_eq!(
+            state.current_item_url().as_deref(),
+            Some("https://github.com/test/repo/actions/runs/42")
+        );
+    }
+
+    #[test]
+    fn current_item_url_on_job() {
+        let mut state = state_with_expanded_job();
+        state.cursor = 1;
+        assert_eq!(
+            state.current_item_url().as_deref(),
+            Some("https://github.com/test/repo/actions/runs/42/job/99")
+        );
+    }
+
+    #[test]
+    fn current_item_url_on_step_uses_job_url() {
+        let mut state = state_with_expanded_job();
+        state.cursor = 2;
+        assert_eq!(state.tree_items[2].level, TreeLevel::Step);
+        assert_eq!(
+            state.current_item_url().as_deref(),
+            Some("https://github.com/test/repo/actions/runs/42/job/99")
+        );
+    }
+
+    #[test]
+    fn current_item_url_empty() {
+        assert_eq!(state_with_runs(vec![]).current_item_url(), None);
+    }
+
+    #[test]
+    fn retry_clears_error_and_requests_fetch() {
+        let mut state = state_with_runs(vec![make_run(7, RunStatus::Completed, None)]);
+        state.expanded_runs.insert(7);
+        state.run_errors.insert(7, "boom".to_string());
+        state.rebuild_tree();
+        state.cursor = 1;
+        assert_eq!(state.tree_items[1].level, TreeLevel::Loading);
+        assert_eq!(state.current_run_error(), Some(7));
+        assert_eq!(state.retry_current(), Some(7));
+        assert!(state.run_errors.is_empty());
+        assert!(state.expanded_runs.contains(&7));
+        assert_eq!(state.current_run_error(), None);
+        assert_eq!(state.retry_current(), None);
+    }
+
+    fn skipped_fixture() -> AppState {
+        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Success));
+        let mut build = make_job("build", RunStatus::Completed, Some(Conclusion::Success));
+        build.steps[1].conclusion = Some(Conclusion::Skipped);
+        let mut deploy = make_job("deploy", RunStatus::Completed, Some(Conclusion::Skipped));
+        deploy.database_id = Some(2);
+        let mut notify = make_job("notify", RunStatus::Completed, Some(Conclusion::Neutral));
+        notify.database_id = Some(3);
+        run.jobs = Some(vec![build, deploy, notify]);
+        let mut state = state_with_runs(vec![run]);
+        state.expanded_runs.insert(1);
+        state.expanded_jobs.insert((1, 1));
+        state.rebuild_tree();
+        state
+    }
+
+    fn tree_names(state: &AppState) -> Vec<String> {
+        state
+            .tree_items
+            .iter()
+            .filter_map(|item| match state.resolve_item(item)? {
+                ResolvedItem::Job(j) => Some(j.name.clone()),
+                ResolvedItem::Step(s) => Some(s.name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hide_skipped_drops_skipped_and_neutral_jobs() {
+        let mut state = skipped_fixture();
+        assert_eq!(
+            tree_names(&state),
+            vec!["build", "Checkout", "Build", "deploy", "notify"]
+        );
+        state.toggle_hide_skipped();
+        assert!(!tree_names(&state).contains(&"deploy".to_string()));
+        assert!(!tree_names(&state).contains(&"notify".to_string()));
+        state.toggle_hide_skipped();
+        assert_eq!(tree_names(&state).len(), 5);
+    }
+
+    #[test]
+    fn hide_skipped_drops_skipped_steps() {
+        let mut state = skipped_fixture();
+        state.toggle_hide_skipped();
+        assert_eq!(tree_names(&state), vec!["build", "Checkout"]);
+    }
+
+    #[test]
+    fn hide_skipped_leaves_no_loading_row_when_every_job_is_hidden() {
+        let mut state = skipped_fixture();
+        if let Some(jobs) = state.runs[0].jobs.as_mut() {
+            jobs.remove(0);
+        }
+        state.toggle_hide_skipped();
+        assert_eq!(state.tree_items.len(), 1);
+        assert_eq!(state.tree_items[0].level, TreeLevel::Run);
+    }
+
+    #[test]
+    fn refresh_jobs_clears_cached_jobs_for_refetch() {
+        let mut run = make_run(7, RunStatus::InProgress, None);
+        run.jobs = Some(vec![
+            make_job("build", RunStatus::Completed, Some(Conclusion::Success)),
+            make_job("test", RunStatus::InProgress, None),
+        ]);
+        let mut state = state_with_runs(vec![run, make_run(8, RunStatus::Completed, None)]);
+        state.expanded_runs.insert(7);
+        state.rebuild_tree();
+        state.cursor = 2; // second job
+        assert_eq!(state.refresh_current_jobs(), Some(7));
+        assert!(state.runs[0].jobs.is_none());
+        assert!(state.runs[1].jobs.is_none());
+        assert_eq!(state.tree_items[1].level, TreeLevel::Loading);
+        // Cursor returns to the run row while its jobs reload
+        assert_eq!(state.cursor, 0);
+
+        // Collapsed runs get expanded so the reload is visible
+        state.cursor = 2;
+        assert_eq!(state.refresh_current_jobs(), Some(8));
+        assert!(state.expanded_runs.contains(&8));
+
+        let mut empty = state_with_runs(vec![]);
+        assert_eq!(empty.refresh_current_jobs(), None);
+    }
+
+    #[test]
+    fn current_item_ids_empty() {
+        let state = state_with_runs(vec![]);
+        assert_eq!(state.current_item_ids(), None);
+    }
+
+    fn unwrap_log_overlay(state: &AppState) -> &LogOverlay {
+        match &state.overlay {
+            ActiveOverlay::Log(o) => o,
+            _ => panic!("Expected Log overlay"),
+        }
+    }
+
+    #[test]
+    fn log_result_wanted_only_by_its_open_overlay() {
+        let mut state = state_with_runs(vec![]);
+        assert!(!state.log_result_wanted(1, Some(2)));
+
+        state.open_log_overlay_loading("Test".to_string(), 1, Some(2));
+        assert!(state.log_result_wanted(1, Some(2)));
+        // Same run, but the whole-run log or another job is a different fetch
+        assert!(!state.log_result_wanted(1, None));
+        assert!(!state.log_result_wanted(1, Some(3)));
+        assert!(!state.log_result_wanted(2, Some(2)));
+
+        // Still wanted once loaded: follow mode refetches into it
+        state.refresh_log_overlay(1, Some(2), "line", 10);
+        assert!(state.log_result_wanted(1, Some(2)));
+
+        state.close_log_overlay();
+        assert!(!state.log_result_wanted(1, Some(2)));
+
+        // Navigated to a different overlay
+        state.open_log_overlay_loading("Test".to_string(), 1, Some(2));
+        state.open_detail_overlay("Details".to_string(), vec![]);
+        assert!(!state.log_result_wanted(1, Some(2)));
+    }
+
+    #[test]
+    fn open_close_log_overlay() {
+        let mut state = state_with_runs(vec![]);
+        assert!(!state.has_log_overlay());
+
+        state.open_log_overlay("Test".to_string(), "line1\nline2", 1, None);
+        assert!(state.has_log_overlay());
+        assert_eq!(unwrap_log_overlay(&state).lines.len(), 2);
+        assert_eq!(state.log_overlay_text(), Some("line1\nline2".to_string()));
+
+        state.close_log_overlay();
+        assert!(!state.has_log_overlay());
+        assert_eq!(state.log_overlay_text(), None);
+    }
+
+    #[test]
+    fn log_overlay_truncates_long_content() {
+        let mut state = state_with_runs(vec![]);
+        let content: String = (0..600)
+            .map(|i| format!("line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        state.open_log_overlay("Test".to_string(), &content, 1, None);
+        assert_eq!(unwrap_log_overlay(&state).lines.len(), LOG_MAX_LINES);
+        assert_eq!(unwrap_log_overlay(&state).dropped_lines, 100);
+        // Should keep last 500 lines (100..599)
+        assert!(unwrap_log_overlay(&state).lines[0].contains("100"));
+    }
+
+    #[test]
+    fn full_log_mode_keeps_every_line_and_tail_mode_truncates() {
+        let mut state = state_with_runs(vec![]);
+        state.config.log_max_lines = 50;
+        let content: String = (0..600)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        state.open_log_overlay("Test".to_string(), &content, 1, Some(2));
+        assert_eq!(unwrap_log_overlay(&state).lines.len(), 50);
+        assert_eq!(state.log_fetch_max_lines(), 50);
+
+        assert_eq!(state.toggle_full_log(10), Some((1, Some(2))));
+        assert_eq!(state.log_fetch_max_lines(), usize::MAX);
+        // Tail in view by default once the full log arrives
+        state.scroll_log_to_bottom(10);
+        assert!(state.refresh_log_overlay(1, Some(2), &content, 10));
+        let overlay = unwrap_log_overlay(&state);
+        assert_eq!(overlay.lines.len(), 600);
+        assert_eq!(overlay.dropped_lines, 0);
+        assert_eq!(overlay.scroll, 590);
+        state.scroll_log_to_top();
+        assert_eq!(
+            unwrap_log_overlay(&state).lines[unwrap_log_overlay(&state).scroll],
+            "line 0"
+        );
+
+        assert_eq!(state.toggle_full_log(10), None);
+        let overlay = unwrap_log_overlay(&state);
+        assert_eq!(overlay.lines.len(), 50);
+        assert_eq!(overlay.dropped_lines, 550);
+        assert_eq!(overlay.lines[0], "line 550");
+        assert_eq!(overlay.scroll, 0);
+    }
+
+    #[test]
+    fn log_overlay_honors_custom_max_lines() {
+        let mut state = state_with_runs(vec![]);
+        state.config.log_max_lines = 50;
+        let content: String = (0..600)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        state.open_log_overlay("Test".to_string(), &content, 1, None);
+        let overlay = unwrap_log_overlay(&state);
+        assert_eq!(overlay.lines.len(), 50);
+        assert_eq!(overlay.dropped_lines, 550);
+        assert_eq!(overlay.lines[0], "line 550");
+
+        state.config.log_max_lines = 1000;
+        state.open_log_overlay("Test".to_string(), &content, 1, None);
+        assert_eq!(unwrap_log_overlay(&state).lines.len(), 600);
+        assert_eq!(unwrap_log_overlay(&state).dropped_lines, 0);
+    }
+
+    #[test]
+    fn loading_log_overlay_tracks_progress_then_fills() {
+        let mut state = state_with_runs(vec![]);
+        state.open_log_overlay_loading("log".to_string(), 1, Some(2));
+        assert_eq!(unwrap_log_overlay(&state).loading, Some(0));
+        state.set_log_progress(1, Some(2), 4096);
+        state.set_log_progress(1, None, 9999); // other fetch: ignored
+        assert_eq!(unwrap_log_overlay(&state).loading, Some(4096));
+        assert!(state.refresh_log_overlay(1, Some(2), "a\nb\nc", 1));
+        let overlay = unwrap_log_overlay(&state);
+        assert_eq!(overlay.loading, None);
+        assert_eq!(overlay.lines.len(), 3);
+        assert_eq!(overlay.scroll, 0, "first content starts at the top");
+        state.set_log_progress(1, Some(2), 10);
+        assert_eq!(unwrap_log_overlay(&state).loading, None);
+    }
+
+    #[test]
+    fn abort_log_loading_only_closes_matching_loading_overlay() {
+        let mut state = state_with_runs(vec![]);
+        state.open_log_overlay("log".to_string(), "done", 1, None);
+        state.abort_log_loading(1, None);
+        assert!(state.has_log_overlay());
+        state.open_log_overlay_loading("log".to_string(), 1, None);
+        state.abort_log_loading(2, None);
+        assert!(state.has_log_overlay());
+        state.abort_log_loading(1, None);
+        assert!(!state.has_log_overlay());
+    }
+
+    #[test]
+    fn format_bytes_units() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(120 * 1024 + 5), "120 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 + 400 * 1024), "3.4 MB");
+    }
+
+    fn numbered_lines(n: usize) -> String {
+        (0..n)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn toggle_log_follow_sets_followed_log() {
+        let mut state = state_with_runs(vec![]);
+        assert_eq!(state.followed_log(), None);
+        state.toggle_log_follow(); // no overlay — no-op
+        state.open_log_overlay("Test".to_string(), "log", 1, Some(5));
+        assert_eq!(state.followed_log(), None);
+        state.toggle_log_follow();
+        assert_eq!(state.followed_log(), Some((1, Some(5))));
+        state.close_overlay();
+        assert_eq!(state.followed_log(), None);
+    }
+
+    #[test]
+    fn refresh_log_overlay_pins_to_bottom() {
+        let mut state = state_with_runs(vec![]);
+        state.open_log_overlay("Test".to_string(), &numbered_lines(30), 1, None);
+        state.scroll_log_to_bottom(10);
+        assert!(state.refresh_log_overlay(1, None, &numbered_lines(40), 10));
+        let overlay = unwrap_log_overlay(&state);
+        assert_eq!(overlay.lines.len(), 40);
+        assert_eq!(overlay.scroll, 30);
+    }
+
+    #[test]
+    fn refresh_log_overlay_keeps_scroll_when_not_at_bottom() {
+        let mut state = state_with_runs(vec![]);
+        state.open_log_overlay("Test".to_string(), &numbered_lines(30), 1, None);
+        state.scroll_log_down(5, 10);
+        assert!(state.refresh_log_overlay(1, None, &numbered_lines(40), 10));
+        assert_eq!(unwrap_log_overlay(&state).scroll, 5);
+    }
+
+    const STEP_LOG: &str = "build\tCheckout\t2024-01-01T00:00:00Z cloning\n\
+                            build\tRun tests\t2024-01-01T00:00:01Z test a ... FAILED\n\
+                            build\tRun tests\t2024-01-01T00:00:02Z error: 1 failed";
+
+    #[test]
+    fn step_log_section_keeps_matching_step() {
+        assert_eq!(
+            step_log_section(STEP_LOG, "Run tests").as_deref(),
+            Some(
+                "build\tRun tests\t2024-01-01T00:00:01Z test a ... FAILED\n\
+                 build\tRun tests\t2024-01-01T00:00:02Z error: 1 failed"
+            )
+        );
+    }
+
+    #[test]
+    fn step_log_section_none_without_match_or_columns() {
+        assert_eq!(step_log_section(STEP_LOG, "Deploy"), None);
+        assert_eq!(
+            step_log_section("plain job log\nno columns", "Run tests"),
+            None
+        );
+    }
+
+    #[test]
+    fn group_log_by_job_puts_headers_between_jobs() {
+        let log = "build\tCompile\terror: oops\nbuild\tCompile\texit 1\ntest\tRun\tFAILED\n";
+        assert_eq!(
+            group_log_by_job(log),
+            "=== Job: build ===\nCompile\terror: oops\nCompile\texit 1\n\n\
+             === Job: test ===\nRun\tFAILED\n"
+        );
+    }
+
+    #[test]
+    fn group_log_by_job_keeps_lines_without_job_column() {
+        assert_eq!(group_log_by_job("plain\ntrace"), "plain\ntrace\n");
+        assert_eq!(group_log_by_job(""), "");
+    }
+
+    #[test]
+    fn current_failed_run_id_only_for_failed_runs() {
+        let state = state_with_runs(vec![make_run(
+            7,
+            RunStatus::Completed,
+            Some(Conclusion::Failure),
+        )]);
+        assert_eq!(state.current_failed_run_id(), Some(7));
+        let state = state_with_runs(vec![make_run(
+            7,
+            RunStatus::Completed,
+            Some(Conclusion::Success),
+        )]);
+        assert_eq!(state.current_failed_run_id(), None);
+    }
+
+    #[test]
+    fn refresh_log_overlay_narrows_to_step_or_falls_back() {
+        let mut state = state_with_runs(vec![]);
+        state.open_log_overlay_loading("Test".to_string(), 1, Some(5));
+        state.set_log_step(Some("Run tests".to_string()));
+        assert!(state.refresh_log_overlay(1, Some(5), STEP_LOG, 10));
+        assert_eq!(unwrap_log_overlay(&state).lines.len(), 2);
+
+        state.set_log_step(Some("Deploy".to_string()));
+        assert!(state.refresh_log_overlay(1, Some(5), STEP_LOG, 10));
+        assert_eq!(unwrap_log_overlay(&state).lines.len(), 3);
+    }
+
+    #[test]
+    fn step_log_is_narrowed_before_the_tail_is_cut() {
+        let mut state = state_with_runs(vec![]);
+        state.config.log_max_lines = 3;
+        state.open_log_overlay_loading("Test".to_string(), 1, Some(5));
+        assert_eq!(state.log_fetch_max_lines(), 3);
+        state.set_log_step(Some("Build".to_string()));
+        assert_eq!(state.log_fetch_max_lines(), usize::MAX);
+
+        let mut log: Vec<String> = (0..4).map(|i| format!("job\tBuild\tb{i}")).collect();
+        log.extend((0..10).map(|i| format!("job\tTest\tt{i}")));
+        assert!(state.refresh_log_overlay(1, Some(5), &log.join("\n"), 10));
+        let overlay = unwrap_log_overlay(&state);
+        assert_eq!(overlay.lines.len(), 3);
+        assert!(overlay.lines[2].ends_with("b3"), "{:?}", overlay.lines);
+    }
+
+    #[test]
+    fn reopening_log_restores_scroll() {
+        let mut state = state_with_runs(vec![]);
+        state.open_log_overlay("Test".to_string(), &numbered_lines(400), 1, Some(5));
+        state.scroll_log_down(300, 10);
+        state.close_overlay();
+        assert_eq!(state.log_scroll.get(&(1, Some(5))), Some(&300));
+
+        state.open_log_overlay("Test".to_string(), &numbered_lines(400), 1, Some(5));
+        assert_eq!(unwrap_log_overlay(&state).scroll, 300);
+        state.close_overlay();
+
+        // Loading path restores once content arrives
+        state.open_log_overlay_loading("Test".to_string(), 1, Some(5));
+        assert!(state.refresh_log_overlay(1, Some(5), &numbered_lines(400), 10));
+        assert_eq!(unwrap_log_overlay(&state).scroll, 300);
+        state.close_overlay();
+
+        // Shorter content than the saved offset starts at the top
+        state.open_log_overlay("Test".to_string(), &numbered_lines(50), 1, Some(5));
+        assert_eq!(unwrap_log_overlay(&state).scroll, 0);
+        state.close_overlay();
+
+        state.open_log_overlay("Other".to_string(), &numbered_lines(400), 1, Some(6));
+        assert_eq!(unwrap_log_overlay(&state).scroll, 0);
+    }
+
+    #[test]
+    fn refresh_log_overlay_ignores_other_target() {
+        let mut state = state_with_runs(vec![]);
+        assert!(!state.refresh_log_overlay(1, None, "new", 10));
+        state.open_log_overlay("Test".to_string(), "old", 1, Some(5));
+        assert!(!state.refresh_log_overlay(1, Some(6), "new", 10));
+        assert_eq!(unwrap_log_overlay(&state).lines, vec!["old".to_string()]);
+    }
+
+    #[test]
+    fn needs_approval_waiting_or_action_required() {
+        assert!(make_run(1, RunStatus::Waiting, None).needs_approval());
+        assert!(
+            make_run(2, RunStatus::Completed, Some(Conclusion::ActionRequired)).needs_approval()
+        );
+        assert!(!make_run(3, RunStatus::Queued, None).needs_approval());
+        assert!(!make_run(4, RunStatus::Completed, Some(Conclusion::Failure)).needs_approval());
+    }
+
+    #[test]
+    fn remove_run_closes_approve_confirm() {
+        let mut state = state_with_runs(vec![make_run(1, RunStatus::Waiting, None)]);
+        state.open_confirm_overlay(
+            "Confirm Approve".to_string(),
+            "Approve?".to_string(),
+            ConfirmAction::ApproveRun(1),
+        );
+        state.remove_run(1);
+        assert!(!state.has_confirm_overlay());
+    }
+
+    // --- Input overlay tests ---
+
+    fn dispatch_purpose() -> InputPurpose {
+        InputPurpose::DispatchBranch {
+            workflow: "CI".to_string(),
+        }
+    }
+
+    #[test]
+    fn input_overlay_edit_and_take() {
+        let mut state = state_with_runs(vec![]);
+        state.open_input_overlay(
+            "Dispatch".to_string(),
+            "Branch".to_string(),
+            "main".to_string(),
+            dispatch_purpose(),
+        );
+        assert!(state.has_input_overlay());
+        state.input_pop();
+        state.input_pop();
+        state.input_push('s');
+        state.input_push('x');
+        assert_eq!(
+            state.take_input(),
+            Some((dispatch_purpose(), "masx".to_string()))
+        );
+        assert!(!state.has_input_overlay());
+    }
+
+    fn make_named_run(id: u64, title: &str, branch: &str) -> WorkflowRun {
+        let mut run = make_run_on_branch(id, branch);
+        run.display_title = title.to_string();
+        run
+    }
+
+    #[test]
+    fn text_filter_narrows_tree_as_you_type() {
+        let mut state = state_with_runs(vec![
+            make_named_run(1, "Fix login bug", "main"),
+            make_named_run(2, "Bump deps", "main"),
+            make_named_run(3, "Login page redesign", "main"),
+        ]);
+        state.open_text_filter();
+        for c in "LOGIN".chars() {
+            state.input_push(c);
+        }
+        assert_eq!(state.text_filter.as_deref(), Some("LOGIN"));
+        let ids: Vec<u64> = state
+            .filtered_runs_indices()
+            .iter()
+            .map(|(_, r)| r.database_id)
+            .collect();
+        assert_eq!(ids, vec![1, 3]);
+        assert_eq!(state.tree_items.len(), 2);
+    }
+
+    #[test]
+    fn text_filter_matches_workflow_name() {
+        let mut run = make_named_run(1, "Bump deps", "main");
+        run.name = "Release".to_string();
+        let mut state = state_with_runs(vec![run, make_named_run(2, "Bump deps", "main")]);
+        state.text_filter = Some("release".to_string());
+        state.rebuild_tree();
+        assert_eq!(state.tree_items.len(), 1);
+    }
+
+    #[test]
+    fn text_filter_composes_with_current_branch() {
+        let mut state = state_with_runs(vec![
+            make_named_run(1, "Fix login", "main"),
+            make_named_run(2, "Fix login", "feature"),
+            make_named_run(3, "Bump deps", "main"),
+        ]);
+        state.filter = FilterMode::CurrentBranch;
+        state.text_filter = Some("login".to_string());
+        state.rebuild_tree();
+        let ids: Vec<u64> = state
+            .filtered_runs_indices()
+            .iter()
+            .map(|(_, r)| r.database_id)
+            .collect();
+        assert_eq!(ids, vec![1]);
+    }
+
+    #[test]
+    fn text_filter_submit_keeps_and_esc_clears() {
+        let mut state = state_with_runs(vec![
+            make_named_run(1, "Fix login", "main"),
+            make_named_run(2, "Bump deps", "main"),
+        ]);
+        state.open_text_filter();
+        state.input_push('d');
+        state.input_push('e');
+        assert_eq!(
+            state.take_input(),
+            Some((InputPurpose::TextFilter, "de".to_string()))
+        );
+        assert_eq!(state.tree_items.len(), 1);
+
+        state.open_text_filter();
+        assert!(matches!(&state.overlay, ActiveOverlay::Input(o) if o.value == "de"));
+        state.close_overlay();
+        assert_eq!(state.text_filter, None);
+        assert_eq!(state.tree_items.len(), 2);
+    }
+
+    // --- Workflow picker ---
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| (*s).to_string()).collect()
+    }
+
+    #[test]
+    fn fuzzy_score_requires_subsequence() {
+        assert!(fuzzy_score("ci", "CI").is_some());
+        assert!(fuzzy_score("dpl", "Deploy").is_some());
+        assert!(fuzzy_score("xyz", "Deploy").is_none());
+        assert!(fuzzy_score("yolped", "Deploy").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn fuzzy_rank_prefers_contiguous_and_word_starts() {
+        let candidates = names(&["Code Inspection", "CI", "Docs Check", "Nightly CI"]);
+        assert_eq!(
+            fuzzy_rank("ci", &candidates),
+            vec!["CI", "Nightly CI", "Code Inspection"]
+        );
+        assert_eq!(
+            fuzzy_rank("dc", &candidates),
+            vec!["Docs Check", "Code Inspection"]
+        );
+    }
+
+    #[test]
+    fn fuzzy_rank_drops_non_matches() {
+        let candidates = names(&["Lint", "Release"]);
+        assert_eq!(fuzzy_rank("rel", &candidates), vec!["Release"]);
+        assert!(fuzzy_rank("zzz", &candidates).is_empty());
+    }
+
+    fn state_with_workflows(list: &[&str]) -> AppState {
+        let runs = list
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let mut run = make_run(i as u64 + 1, RunStatus::Completed, None);
+                run.name = (*name).to_string();
+                run
+            })
+            .collect();
+        state_with_runs(runs)
+    }
+
+    #[test]
+    fn workflow_picker_lists_distinct_names_with_all_first() {
+        let mut state = state_with_workflows(&["Lint", "CI", "Lint"]);
+        state.open_workflow_picker();
+        let ActiveOverlay::WorkflowPicker(ref picker) = state.overlay else {
+            panic!("expected picker");
+        };
+        assert_eq!(picker.entries(), vec![None, Some("CI"), Some("Lint")]);
+        assert_eq!(state.take_workflow_pick(), Some(None));
+    }
+
+    #[test]
+    fn workflow_picker_typing_filters_and_picks() {
+        let mut state = state_with_workflows(&["Lint", "CI", "Release"]);
+        state.open_workflow_picker();
+        state.picker_move_down();
+        for c in "rel".chars() {
+            state.input_push(c);
+        }
+        state.picker_move_down(); // clamped to the only match
+        assert_eq!(
+            state.take_workflow_pick(),
+            Some(Some("Release".to_string()))
+        );
+        assert!(!state.has_workflow_picker());
+    }
+
+    #[test]
+    fn workflow_picker_keeps_workflows_filtered_out_of_later_polls() {
+        let mut state = state_with_workflows(&["Lint", "CI", "Release"]);
+        // A `--workflow CI` poll: only that workflow's runs come back
+        state.runs.retain(|r| r.name == "CI");
+        state.rebuild_tree();
+        state.open_workflow_picker();
+        let ActiveOverlay::WorkflowPicker(ref picker) = state.overlay else {
+            panic!("expected picker");
+        };
+        assert_eq!(
+            picker.entries(),
+            vec![None, Some("CI"), Some("Lint"), Some("Release")]
+        );
+    }
+
+    #[test]
+    fn workflow_picker_no_match_picks_nothing() {
+        let mut state = state_with_workflows(&["CI"]);
+        state.open_workflow_picker();
+        state.input_push('z');
+        assert_eq!(state.take_workflow_pick(), None);
+        assert_eq!(state.take_workflow_pick(), None);
+    }
+
+    #[test]
+    fn take_input_trims_and_requires_overlay() {
+        let mut state = state_with_runs(vec![]);
+        assert_eq!(state.take_input(), None);
+        state.open_input_overlay(
+            "Dispatch".to_string(),
+            "Branch".to_string(),
+            "  dev ".to_string(),
+            dispatch_purpose(),
+        );
+        assert_eq!(state.take_input().map(|(_, v)| v), Some("dev".to_string()));
+    }
+
+    #[test]
+    fn remove_run_keeps_dispatch_confirm() {
+        let mut state = state_with_runs(vec![make_run(1, RunStatus::Completed, None)]);
+        state.open_confirm_overlay(
+            "Confirm Dispatch".to_string(),
+            "Run CI?".to_string(),
+            ConfirmAction::DispatchWorkflow {
+                workflow: "CI".to_string(),
+                branch: "main".to_string(),
+            },
+        );
+        state.remove_run(1);
+        assert!(state.has_confirm_overlay());
+    }
+
+    // --- Confirm overlay tests ---
+
+    #[test]
+    fn open_close_confirm_overlay() {
+        let mut state = state_with_runs(vec![make_run(1, RunStatus::InProgress, None)]);
+        assert!(!state.has_confirm_overlay());
+
+        state.open_confirm_overlay(
+            "Confirm".to_string(),
+            "Cancel run?".to_string(),
+            ConfirmAction::CancelRun(1),
+        );
+        assert!(state.has_confirm_overlay());
+        assert_eq!(state.confirm_action(), Some(ConfirmAction::CancelRun(1)));
+
+        state.close_confirm_overlay();
+        assert!(!state.has_confirm_overlay());
+        assert_eq!(state.confirm_action(), None);
+    }
+
+    #[test]
+    fn rerun_confirm_yes_yields_action_and_no_discards_it() {
+        let mut state = state_with_runs(vec![make_run(
+            1,
+            RunStatus::Completed,
+            Some(Conclusion::Failure),
+        )]);
+        state.open_confirm_overlay(
+            "Confirm Rerun".to_string(),
+            "Rerun?".to_string(),
+            ConfirmAction::RerunRun(1),
+        );
+        // ConfirmYes reads the pending action before closing the overlay.
+        assert_eq!(state.confirm_action(), Some(ConfirmAction::RerunRun(1)));
+
+        // Cancelling (n/Esc) closes the overlay, leaving nothing to execute.
+        state.close_confirm_overlay();
+        assert_eq!(state.confirm_action(), None);
+    }
+
+    // --- Unknown status tests ---
+
+    #[test]
+    fn active_filter_includes_unknown_only_when_enabled() {
         let mut state = state_with_runs(vec![
-            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(1, RunStatus::Unknown, None),
             make_run(2, RunStatus::InProgress, None),
-            make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
         ]);
-        state.cursor = 2;
-        // Switch to active-only filter: only 1 item
         state.filter = FilterMode::ActiveOnly;
         state.rebuild_tree();
-        assert_eq!(state.cursor, 0);
+        assert_eq!(state.tree_items.len(), 1);
+
+        state.config.unknown_is_active = true;
+        state.rebuild_tree();
+        assert_eq!(state.tree_items.len(), 2);
     }
 
     #[test]
-    fn spinner_wraps() {
-        let mut state = state_with_runs(vec![]);
-        for _ in 0..SPINNER_FRAME_COUNT {
-            state.advance_spinner();
+    fn machine_names_match_serde() {
+        for status in [
+            RunStatus::Completed,
+            RunStatus::InProgress,
+            RunStatus::Waiting,
+        ] {
+            let json = serde_json::to_string(&status).unwrap();
+            assert_eq!(json, format!("\"{}\"", status.as_str()));
+        }
+        for conclusion in [Conclusion::TimedOut, Conclusion::ActionRequired] {
+            let json = serde_json::to_string(&conclusion).unwrap();
+            assert_eq!(json, format!("\"{}\"", conclusion.as_str()));
         }
-        assert_eq!(state.spinner_frame, 0); // wrapped back
     }
 
     #[test]
-    fn current_run_url_returns_url() {
-        let state = state_with_runs(vec![make_run(
-            42,
-            RunStatus::Completed,
-            Some(Conclusion::Success),
-        )]);
-        assert_eq!(
-            state.current_run_url(),
-            Some("https://github.com/test/repo/actions/runs/42")
-        );
+    fn status_label_flags_unknown() {
+        assert_eq!(status_label(RunStatus::Queued), "Queued");
+        assert!(status_label(RunStatus::Unknown).contains("unrecognized"));
     }
 
+    // --- Cursor anchoring tests ---
+
     #[test]
-    fn current_run_id_returns_id() {
-        let state = state_with_runs(vec![make_run(
-            42,
-            RunStatus::Completed,
-            Some(Conclusion::Success),
-        )]);
-        assert_eq!(state.current_run_id(), Some(42));
+    fn cursor_follows_run_when_poll_reorders() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(3, RunStatus::InProgress, None),
+        ]);
+        state.cursor = 1;
+        assert_eq!(state.current_run_id(), Some(2));
+
+        // A new run arrives on top and the rest shift down
+        state.update_runs(vec![
+            make_run(4, RunStatus::Queued, None),
+            make_run(3, RunStatus::InProgress, None),
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+        ]);
+        assert_eq!(state.current_run_id(), Some(2));
+        assert_eq!(state.cursor, 3);
     }
 
     #[test]
-    fn current_run_url_empty() {
-        let state = state_with_runs(vec![]);
-        assert_eq!(state.current_run_url(), None);
-    }
+    fn cursor_keeps_row_offset_within_run() {
+        let mut run = make_run(2, RunStatus::Completed, Some(Conclusion::Failure));
+        let mut build = make_job("build", RunStatus::Completed, Some(Conclusion::Success));
+        build.database_id = Some(20);
+        let mut test = make_job("test", RunStatus::Completed, Some(Conclusion::Failure));
+        test.database_id = Some(21);
+        run.jobs = Some(vec![build, test]);
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            run.clone(),
+        ]);
+        state.expanded_runs.insert(2);
+        state.rebuild_tree();
+        state.cursor = 3; // second job of run 2
 
-    // --- Log overlay tests ---
+        state.update_runs(vec![
+            run,
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        assert_eq!(state.cursor, 2);
+        assert_eq!(state.current_item_ids(), Some((2, Some(21))));
+    }
 
     #[test]
-    fn current_item_is_failed_on_failed_run() {
-        let state = state_with_runs(vec![make_run(
+    fn cursor_clamps_when_run_disappears() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+        ]);
+        state.cursor = 1;
+        state.update_runs(vec![make_run(
             1,
             RunStatus::Completed,
-            Some(Conclusion::Failure),
+            Some(Conclusion::Success),
         )]);
-        assert!(state.current_item_is_failed());
+        assert_eq!(state.cursor, 0);
     }
 
+    // --- Letter jump tests ---
+
     #[test]
-    fn current_item_is_failed_on_success_run() {
-        let state = state_with_runs(vec![make_run(
-            1,
-            RunStatus::Completed,
-            Some(Conclusion::Success),
-        )]);
-        assert!(!state.current_item_is_failed());
+    fn quick_select_label_digits_then_letters() {
+        assert_eq!(quick_select_label(0), Some('1'));
+        assert_eq!(quick_select_label(8), Some('9'));
+        assert_eq!(quick_select_label(9), Some('a'));
+        assert_eq!(quick_select_label(14), Some('f'));
+        assert_eq!(quick_select_label(15), Some('h'), "g is the gg prefix");
+        assert_eq!(quick_select_label(33), Some('z'));
+        assert_eq!(quick_select_label(34), None);
     }
 
     #[test]
-    fn current_item_is_failed_on_failed_job() {
-        let mut run = make_run(1, RunStatus::Completed, Some(Conclusion::Failure));
-        run.jobs = Some(vec![make_job(
-            "build",
-            RunStatus::Completed,
-            Some(Conclusion::Failure),
-        )]);
-        let mut state = state_with_runs(vec![run]);
+    fn vim_jumps_move_to_first_last_and_nth_row() {
+        let mut runs: Vec<WorkflowRun> = (1..=4)
+            .map(|id| make_run(id, RunStatus::Completed, Some(Conclusion::Success)))
+            .collect();
+        runs[0].jobs = Some(vec![
+            make_job("build", RunStatus::Completed, Some(Conclusion::Success)),
+            make_job("test", RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        let mut state = state_with_runs(runs);
         state.expanded_runs.insert(1);
         state.rebuild_tree();
-        state.cursor = 1; // on the job
-        assert!(state.current_item_is_failed());
-    }
+        let rows = state.tree_items.len();
+        assert_eq!(rows, 6);
 
-    #[test]
-    fn current_item_is_failed_empty() {
-        let state = state_with_runs(vec![]);
-        assert!(!state.current_item_is_failed());
+        state.jump_to_last();
+        assert_eq!(state.cursor, rows - 1);
+        assert_eq!(state.current_run_id(), Some(4));
+        state.jump_to_first();
+        assert_eq!(state.cursor, 0);
+
+        // `3G`: digits accumulate, then G jumps to that row
+        state.push_count_digit(3);
+        let count = state.pending_count.take().unwrap();
+        state.jump_to_row(count);
+        assert_eq!(state.cursor, 2);
+        assert_eq!(state.tree_items[2].level, TreeLevel::Job, "rows, not runs");
+
+        state.push_count_digit(1);
+        state.push_count_digit(2);
+        assert_eq!(state.pending_count, Some(12));
+        state.jump_to_row(12);
+        assert_eq!(state.cursor, rows - 1, "clamped");
+        state.jump_to_row(0);
+        assert_eq!(state.cursor, 0);
     }
 
     #[test]
-    fn current_item_ids_run() {
-        let state = state_with_runs(vec![make_run(
-            42,
-            RunStatus::Completed,
-            Some(Conclusion::Failure),
-        )]);
-        assert_eq!(state.current_item_ids(), Some((42, None)));
+    fn count_prefix_keeps_every_digit_across_key_presses() {
+        use crate::input::{self, Action, InputContext};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let runs = (1..=15)
+            .map(|id| make_run(id, RunStatus::Completed, Some(Conclusion::Success)))
+            .collect();
+        let mut state = state_with_runs(runs);
+        // Same steps as the event loop: context from state, then apply the action
+        for c in ['1', '2', 'G'] {
+            let ctx = InputContext {
+                pending_count: state.pending_count,
+                ..InputContext::default()
+            };
+            let action = input::map_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE), &ctx);
+            if !action.extends_count() {
+                state.pending_count = None;
+            }
+            match action {
+                Action::QuickSelect(n) => {
+                    state.quick_select(n);
+                    state.push_count_digit(n);
+                }
+                Action::CountDigit(d) => state.push_count_digit(d),
+                Action::JumpToRow(n) => state.jump_to_row(n),
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+        assert_eq!(state.cursor, 11, "row 12");
+        assert_eq!(state.pending_count, None);
     }
 
     #[test]
-    fn current_item_ids_job() {
-        let mut run = make_run(42, RunStatus::Completed, Some(Conclusion::Failure));
-        let mut job = make_job("build", RunStatus::Completed, Some(Conclusion::Failure));
-        job.database_id = Some(99);
-        run.jobs = Some(vec![job]);
-        let mut state = state_with_runs(vec![run]);
-        state.expanded_runs.insert(42);
+    fn jump_label_resolves_to_tree_index() {
+        let runs = (1..=12)
+            .map(|id| make_run(id, RunStatus::Completed, Some(Conclusion::Success)))
+            .collect();
+        let mut state = state_with_runs(runs);
+        // An expanded run adds rows, so labels must count runs, not rows
+        state.expanded_runs.insert(1);
         state.rebuild_tree();
-        state.cursor = 1; // on the job
-        assert_eq!(state.current_item_ids(), Some((42, Some(99))));
+        let extra_rows = state.tree_items.len() - 12;
+
+        assert_eq!(state.jump_label_row('a'), Some(9 + extra_rows));
+        assert_eq!(state.jump_label_row('c'), Some(11 + extra_rows));
+        assert_eq!(state.jump_label_row('d'), None);
+        assert_eq!(state.jump_label_row('?'), None);
+
+        state.jump_to_label('b');
+        assert_eq!(state.current_run_id(), Some(11));
+    }
+
+    // --- Long-run warning tests ---
+
+    fn running_for(id: u64, mins: i64, now: DateTime<Utc>) -> WorkflowRun {
+        let mut run = make_run(id, RunStatus::InProgress, None);
+        run.created_at = now - chrono::Duration::minutes(mins);
+        run
     }
 
     #[test]
-    fn current_item_ids_empty() {
-        let state = state_with_runs(vec![]);
-        assert_eq!(state.current_item_ids(), None);
+    fn should_warn_long_run_checks_elapsed_status_and_warned() {
+        let now = Utc::now();
+        let mut warned = std::collections::HashSet::new();
+        assert!(!should_warn_long_run(
+            &running_for(1, 29, now),
+            now,
+            30,
+            &warned
+        ));
+        assert!(should_warn_long_run(
+            &running_for(1, 30, now),
+            now,
+            30,
+            &warned
+        ));
+
+        let mut done = running_for(1, 45, now);
+        done.status = RunStatus::Completed;
+        assert!(!should_warn_long_run(&done, now, 30, &warned));
+
+        warned.insert(1);
+        assert!(!should_warn_long_run(
+            &running_for(1, 45, now),
+            now,
+            30,
+            &warned
+        ));
     }
 
-    fn unwrap_log_overlay(state: &AppState) -> &LogOverlay {
-        match &state.overlay {
-            ActiveOverlay::Log(o) => o,
-            _ => panic!("Expected Log overlay"),
-        }
+    #[test]
+    fn check_long_runs_notifies_once() {
+        let now = Utc::now();
+        let mut state = state_with_runs(vec![running_for(1, 40, now), running_for(2, 5, now)]);
+        state.check_long_runs(now);
+        assert!(state.notifications.is_empty());
+
+        state.config.long_run_threshold = Some(30);
+        state.check_long_runs(now);
+        assert_eq!(state.notifications.len(), 1);
+        assert_eq!(state.notifications[0].run_id, 1);
+        state.check_long_runs(now);
+        assert_eq!(state.notifications.len(), 1);
     }
 
+    // --- Batch selection tests ---
+
     #[test]
-    fn open_close_log_overlay() {
-        let mut state = state_with_runs(vec![]);
-        assert!(!state.has_log_overlay());
+    fn toggle_selected_marks_and_unmarks_current_run() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+        ]);
+        state.toggle_selected();
+        state.move_cursor_down();
+        state.toggle_selected();
+        assert_eq!(state.selected_runs.len(), 2);
 
-        state.open_log_overlay("Test".to_string(), "line1\nline2", 1, None);
-        assert!(state.has_log_overlay());
-        assert_eq!(unwrap_log_overlay(&state).lines.len(), 2);
-        assert_eq!(state.log_overlay_text(), Some("line1\nline2".to_string()));
+        state.toggle_selected();
+        assert!(state.selected_runs.contains(&1));
+        assert!(!state.selected_runs.contains(&2));
 
-        state.close_log_overlay();
-        assert!(!state.has_log_overlay());
-        assert_eq!(state.log_overlay_text(), None);
+        state.clear_selection();
+        assert!(state.selected_runs.is_empty());
     }
 
     #[test]
-    fn log_overlay_truncates_long_content() {
-        let mut state = state_with_runs(vec![]);
-        let content: String = (0..600)
-            .map(|i| format!("line {}", i))
-            .collect::<Vec<_>>()
-            .join("\n");
-        state.open_log_overlay("Test".to_string(), &content, 1, None);
-        assert_eq!(unwrap_log_overlay(&state).lines.len(), LOG_MAX_LINES);
-        // Should keep last 500 lines (100..599)
-        assert!(unwrap_log_overlay(&state).lines[0].contains("100"));
+    fn batch_targets_respect_per_run_guards() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::InProgress, None),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
+        ]);
+        state.selected_runs.extend([1, 2, 3, 99]);
+
+        assert_eq!(state.batch_targets(BatchOp::Cancel), (vec![1], 3));
+        assert_eq!(state.batch_targets(BatchOp::Delete), (vec![2, 3], 2));
     }
 
-    // --- Confirm overlay tests ---
+    #[test]
+    fn open_batch_confirm_carries_only_eligible_ids() {
+        let mut state = state_with_runs(vec![
+            make_run(1, RunStatus::InProgress, None),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+        ]);
+        state.selected_runs.extend([1, 2]);
+        state.open_batch_confirm(BatchOp::Delete);
+        assert_eq!(
+            state.confirm_action(),
+            Some(ConfirmAction::Batch {
+                op: BatchOp::Delete,
+                run_ids: vec![2],
+                from_marks: true,
+            })
+        );
+    }
 
     #[test]
-    fn open_close_confirm_overlay() {
-        let mut state = state_with_runs(vec![make_run(1, RunStatus::InProgress, None)]);
+    fn open_batch_confirm_errors_when_nothing_eligible() {
+        let mut state = state_with_runs(vec![make_run(
+            1,
+            RunStatus::Completed,
+            Some(Conclusion::Failure),
+        )]);
+        state.open_batch_confirm(BatchOp::Cancel);
         assert!(!state.has_confirm_overlay());
+        assert!(state.error.is_some());
 
-        state.open_confirm_overlay(
-            "Confirm".to_string(),
-            "Cancel run?".to_string(),
-            ConfirmAction::CancelRun(1),
+        state.selected_runs.insert(1);
+        state.open_batch_confirm(BatchOp::Cancel);
+        assert!(!state.has_confirm_overlay());
+    }
+
+    #[test]
+    fn active_run_ids_ignore_marks_and_finished_runs() {
+        let mut state = state_with_runs(vec![
+            make_run(3, RunStatus::InProgress, None),
+            make_run(1, RunStatus::InProgress, None),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(4, RunStatus::Queued, None),
+        ]);
+        state.selected_runs.insert(2);
+        assert_eq!(state.active_run_ids(), vec![1, 3]);
+
+        state.open_cancel_all_confirm();
+        assert_eq!(
+            state.confirm_action(),
+            Some(ConfirmAction::Batch {
+                op: BatchOp::Cancel,
+                run_ids: vec![1, 3],
+                from_marks: false,
+            })
         );
-        assert!(state.has_confirm_overlay());
-        assert_eq!(state.confirm_action(), Some(ConfirmAction::CancelRun(1)));
+    }
 
-        state.close_confirm_overlay();
+    #[test]
+    fn cancel_all_errors_when_nothing_in_progress() {
+        let mut state = state_with_runs(vec![make_run(
+            1,
+            RunStatus::Completed,
+            Some(Conclusion::Success),
+        )]);
+        assert!(state.active_run_ids().is_empty());
+        state.open_cancel_all_confirm();
         assert!(!state.has_confirm_overlay());
-        assert_eq!(state.confirm_action(), None);
+        assert!(state.error.is_some());
+    }
+
+    #[test]
+    fn report_batch_result_notifies_each_run() {
+        let mut state = state_with_runs(vec![]);
+        state.report_batch_result(BatchOp::Cancel, &[1, 2], &[(3, "HTTP 409".to_string())]);
+        let notes: Vec<(u64, &str)> = state
+            .notifications
+            .iter()
+            .map(|n| (n.run_id, n.message.as_str()))
+            .collect();
+        assert_eq!(
+            notes,
+            vec![
+                (1, "Run cancelled"),
+                (2, "Run cancelled"),
+                (3, "Cancel failed: HTTP 409"),
+            ]
+        );
+        assert_eq!(
+            state.error.as_ref().map(|(m, _)| m.as_str()),
+            Some("Cancelled 2 of 3 runs; 1 failed (run 3: HTTP 409)")
+        );
+
+        let mut state = state_with_runs(vec![]);
+        state.report_batch_result(BatchOp::Delete, &[1], &[]);
+        assert_eq!(state.notifications.len(), 1);
+        assert_eq!(state.notifications[0].message, "Deleted 1 run");
+    }
+
+    #[test]
+    fn remove_run_drops_selection() {
+        let mut state = state_with_runs(vec![make_run(
+            1,
+            RunStatus::Completed,
+            Some(Conclusion::Failure),
+        )]);
+        state.selected_runs.insert(1);
+        state.remove_run(1);
+        assert!(state.selected_runs.is_empty());
+    }
+
+    #[test]
+    fn batch_summary_reports_partial_failures() {
+        assert_eq!(BatchOp::Delete.summary(3, &[]), "Deleted 3 runs");
+        assert_eq!(
+            BatchOp::Cancel.summary(1, &[(42, "HTTP 409".to_string())]),
+            "Cancelled 1 of 2 runs; 1 failed (run 42: HTTP 409)"
+        );
     }
 
     #[test]
@@ -1648,6 +6465,42 @@ mod tests {
         assert_eq!(state.cursor, 0); // clamped
     }
 
+    #[test]
+    fn pr_url_from_run_url() {
+        let mut run = make_run(5, RunStatus::Completed, Some(Conclusion::Success));
+        assert_eq!(run.pr_url(), None);
+        run.pr_number = Some(17);
+        assert_eq!(
+            run.pr_url().as_deref(),
+            Some("https://github.com/test/repo/pull/17")
+        );
+        let state = state_with_runs(vec![run]);
+        assert_eq!(
+            state.current_run_pr_url().as_deref(),
+            Some("https://github.com/test/repo/pull/17")
+        );
+    }
+
+    #[test]
+    fn log_page_step_follows_visible_height() {
+        assert_eq!(log_page_step(40, false), 40);
+        assert_eq!(log_page_step(40, true), 20);
+        assert_eq!(log_page_step(7, true), 3);
+        assert_eq!(log_page_step(1, true), 1);
+        assert_eq!(log_page_step(0, false), 1);
+
+        let mut state = state_with_runs(vec![]);
+        let content = (0..100)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        state.open_log_overlay("Test".to_string(), &content, 1, None);
+        state.scroll_log_down(log_page_step(30, false), 30);
+        assert_eq!(unwrap_log_overlay(&state).scroll, 30);
+        state.scroll_log_up(log_page_step(30, true));
+        assert_eq!(unwrap_log_overlay(&state).scroll, 15);
+    }
+
     #[test]
     fn scroll_log_bounds() {
         let mut state = state_with_runs(vec![]);
@@ -0,0 +1,108 @@
+//! Opt-in (`--cache`) snapshot of the last successful poll, so startup can render
+//! immediately instead of blocking on the first fetch. One file per repo under
+//! the state dir; the first live poll replaces whatever was loaded.
+
+use crate::app::WorkflowRun;
+use color_eyre::eyre::{eyre, Result};
+use std::path::{Path, PathBuf};
+
+/// `owner/repo` and nested GitLab groups flatten to one file name.
+pub fn cache_file(state_dir: &Path, repo: &str) -> PathBuf {
+    state_dir
+        .join("cache")
+        .join(format!("{}.json", repo.replace('/', "__")))
+}
+
+/// Writes via a temp file + rename so a crash mid-write never leaves a torn cache.
+pub fn save(path: &Path, runs: &[WorkflowRun]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| eyre!("Failed to create cache directory {dir:?}: {e}"))?;
+    }
+    let json = serde_json::to_string(runs)?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json).map_err(|e| eyre!("Failed to write cache {tmp:?}: {e}"))?;
+    std::fs::rename(&tmp, path).map_err(|e| eyre!("Failed to replace cache {path:?}: {e}"))
+}
+
+pub fn load(path: &Path) -> Result<Vec<WorkflowRun>> {
+    let json =
+        std::fs::read_to_string(path).map_err(|e| eyre!("Failed to read cache {path:?}: {e}"))?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{Conclusion, Job, RunStatus, Step};
+    use chrono::{TimeZone, Utc};
+
+    fn temp_state_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ciw-cache-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn make_run() -> WorkflowRun {
+        let at = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        WorkflowRun {
+            display_title: "Fix build".to_string(),
+            created_at: at,
+            updated_at: at,
+            event: "pull_request".to_string(),
+            number: 3,
+            url: "https://github.com/o/r/actions/runs/7".to_string(),
+            head_sha: "abc1234def".to_string(),
+            pr_number: Some(12),
+            attempt: 2,
+            jobs: Some(vec![Job {
+                database_id: Some(70),
+                started_at: Some(at),
+                url: "https://github.com/o/r/actions/runs/7/job/70".to_string(),
+                steps: vec![Step {
+                    name: "cargo test".to_string(),
+                    status: RunStatus::Completed,
+                    conclusion: Some(Conclusion::Failure),
+                    number: 2,
+                    started_at: None,
+                    completed_at: None,
+                }],
+                ..crate::mock::job("test", RunStatus::Completed, Some(Conclusion::Failure))
+            }]),
+            ..crate::mock::run(7, RunStatus::Completed, Some(Conclusion::Failure))
+        }
+    }
+
+    #[test]
+    fn cache_file_flattens_repo_path() {
+        let path = cache_file(Path::new("/state"), "group/sub/project");
+        assert_eq!(path, PathBuf::from("/state/cache/group__sub__project.json"));
+    }
+
+    #[test]
+    fn round_trip_preserves_runs_and_jobs() {
+        let dir = temp_state_dir("roundtrip");
+        let path = cache_file(&dir, "o/r");
+        save(&path, &[make_run()]).unwrap();
+        let loaded = load(&path).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(loaded.len(), 1);
+        let run = &loaded[0];
+        assert_eq!(run.database_id, 7);
+        assert_eq!(run.conclusion, Some(Conclusion::Failure));
+        assert_eq!(run.head_sha, "abc1234def");
+        assert_eq!(run.pr_number, Some(12));
+        assert_eq!(run.attempt, 2);
+        assert_eq!(run.created_at, make_run().created_at);
+        let jobs = run.jobs.as_ref().unwrap();
+        assert_eq!(jobs[0].database_id, Some(70));
+        assert_eq!(jobs[0].steps[0].name, "cargo test");
+    }
+
+    #[test]
+    fn load_missing_file_errors() {
+        let dir = temp_state_dir("missing");
+        assert!(load(&cache_file(&dir, "o/r")).is_err());
+    }
+}
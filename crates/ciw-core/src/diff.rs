@@ -3,55 +3,153 @@
 //! Snapshot entries persist after a run disappears from the API — prevents false
 //! "started" notifications when runs scroll out of `--limit` and reappear later.
 
-use crate::app::{AppState, Notification, RunStatus, SnapshotEntry, WorkflowRun};
+use crate::app::{AppState, Conclusion, Notification, RunStatus, SnapshotEntry, WorkflowRun};
+use std::collections::HashSet;
 
 /// Polls absent before eviction. ~30s at 3s interval, ~5min at 30s idle interval.
 const SNAPSHOT_EVICTION_POLLS: u64 = 10;
 
-pub fn detect_changes(state: &mut AppState, new_runs: &[WorkflowRun]) {
+/// A run that reached `Completed` since the last poll; drives desktop notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Completion {
+    pub run_id: u64,
+    pub failed: bool,
+}
+
+/// Transition counts for one poll, e.g. "2 started, 1 succeeded, 1 failed".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChangeSummary {
+    pub started: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub cancelled: usize,
+    /// Finished some other way: skipped, neutral, stale, …
+    pub finished: usize,
+}
+
+impl ChangeSummary {
+    fn count_completion(&mut self, conclusion: Option<Conclusion>) {
+        match conclusion {
+            Some(Conclusion::Success) => self.succeeded += 1,
+            Some(c) if c.is_failure() => self.failed += 1,
+            Some(Conclusion::Cancelled) => self.cancelled += 1,
+            _ => self.finished += 1,
+        }
+    }
+
+    /// `None` when nothing changed, so quiet polls stay quiet.
+    pub fn label(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            (self.started, "started"),
+            (self.succeeded, "succeeded"),
+            (self.failed, "failed"),
+            (self.cancelled, "cancelled"),
+            (self.finished, "finished"),
+        ]
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, what)| format!("{n} {what}"))
+        .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
+
+/// What [`detect_changes`] found this poll.
+#[derive(Debug, Clone, Default)]
+pub struct PollChanges {
+    /// Runs that finished; drives desktop notifications.
+    pub completions: Vec<Completion>,
+    pub summary: ChangeSummary,
+}
+
+/// Updates the snapshot, pushes in-app notifications, and returns the runs that
+/// finished this poll along with a summary of every transition.
+///
+/// A run first seen already failed still counts — a short run can start and fail
+/// between two polls — and one first seen queued or running counts as started.
+/// Only ids above every snapshotted id qualify, so a run scrolling back into
+/// `--limit` after eviction isn't mistaken for a new one.
+pub fn detect_changes(state: &mut AppState, new_runs: &[WorkflowRun]) -> PollChanges {
     let now = std::time::Instant::now();
     state.poll_count += 1;
     let current_poll = state.poll_count;
+    let newest_known = state.previous_snapshot.keys().max().copied();
+    let mut completions = Vec::new();
+    let mut summary = ChangeSummary::default();
+    let mut started = HashSet::new();
 
     for run in new_runs {
-        if let Some(entry) = state.previous_snapshot.get(&run.database_id) {
-            let (old_status, old_conclusion) = (entry.status, entry.conclusion);
-            if old_status != run.status || old_conclusion != run.conclusion {
-                let msg = match (run.status, run.conclusion) {
-                    (RunStatus::Completed, Some(crate::app::Conclusion::Success)) => {
-                        format!("{} completed successfully", run.display_title)
-                    }
-                    (RunStatus::Completed, Some(crate::app::Conclusion::Failure)) => {
-                        format!("{} failed", run.display_title)
-                    }
-                    (RunStatus::Completed, Some(c)) => {
-                        format!("{} completed ({:?})", run.display_title, c)
-                    }
-                    (RunStatus::InProgress, _) => {
-                        format!("{} started", run.display_title)
-                    }
-                    _ => {
-                        format!("{} changed to {:?}", run.display_title, run.status)
-                    }
-                };
-                state.notifications.push(Notification {
+        let failed = run.conclusion.is_some_and(Conclusion::is_failure);
+        let Some(entry) = state.previous_snapshot.get(&run.database_id) else {
+            let is_new = newest_known.is_some_and(|id| run.database_id > id);
+            if run.status == RunStatus::Completed && failed && is_new {
+                completions.push(Completion {
                     run_id: run.database_id,
-                    message: msg,
-                    timestamp: now,
+                    failed,
                 });
+                summary.count_completion(run.conclusion);
+            } else if run.status != RunStatus::Completed && is_new {
+                summary.started += 1;
+                started.insert(run.database_id);
             }
+            continue;
+        };
+        if run.status == RunStatus::Completed && entry.status != RunStatus::Completed {
+            completions.push(Completion {
+                run_id: run.database_id,
+                failed,
+            });
+            summary.count_completion(run.conclusion);
+        } else if run.status == RunStatus::InProgress
+            && entry.status != RunStatus::InProgress
+            && !entry.counted_start
+        {
+            summary.started += 1;
+            started.insert(run.database_id);
+        }
+        let (old_status, old_conclusion) = (entry.status, entry.conclusion);
+        if old_status != run.status || old_conclusion != run.conclusion {
+            let msg = match (run.status, run.conclusion) {
+                (RunStatus::Completed, Some(Conclusion::Success)) => {
+                    format!("{} completed successfully", run.display_title)
+                }
+                (RunStatus::Completed, Some(Conclusion::Failure)) => {
+                    format!("{} failed", run.display_title)
+                }
+                (RunStatus::Completed, Some(c)) => {
+                    format!("{} completed ({:?})", run.display_title, c)
+                }
+                (RunStatus::InProgress, _) => {
+                    format!("{} started", run.display_title)
+                }
+                _ => {
+                    format!("{} changed to {:?}", run.display_title, run.status)
+                }
+            };
+            state.notifications.push(Notification {
+                run_id: run.database_id,
+                message: msg,
+                timestamp: now,
+            });
         }
     }
 
     // Merge new runs into existing snapshot — prevents false-positive notifications
     // when runs scroll out of the `--limit` window and reappear later.
     for run in new_runs {
+        let counted_start = run.status != RunStatus::Completed
+            && (started.contains(&run.database_id)
+                || state
+                    .previous_snapshot
+                    .get(&run.database_id)
+                    .is_some_and(|e| e.counted_start));
         state.previous_snapshot.insert(
             run.database_id,
             SnapshotEntry {
                 status: run.status,
                 conclusion: run.conclusion,
                 last_seen_poll: current_poll,
+                counted_start,
             },
         );
     }
@@ -60,35 +158,118 @@ pub fn detect_changes(state: &mut AppState, new_runs: &[WorkflowRun]) {
     state.previous_snapshot.retain(|_, entry| {
         current_poll.saturating_sub(entry.last_seen_poll) < SNAPSHOT_EVICTION_POLLS
     });
+
+    if completions.iter().any(|c| c.failed) {
+        state.alert_failure(now);
+    }
+
+    PollChanges {
+        completions,
+        summary,
+    }
+}
+
+/// Runs to fetch jobs for without waiting for an expand (`--prefetch-failed`):
+/// those that just failed and have no jobs loaded in `runs`.
+pub fn prefetch_targets(completions: &[Completion], runs: &[WorkflowRun]) -> Vec<u64> {
+    completions
+        .iter()
+        .filter(|c| c.failed)
+        .filter(|c| {
+            runs.iter()
+                .any(|r| r.database_id == c.run_id && r.jobs.is_none())
+        })
+        .map(|c| c.run_id)
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::app::{Conclusion, RunStatus};
-    use chrono::Utc;
 
     fn make_run(id: u64, status: RunStatus, conclusion: Option<Conclusion>) -> WorkflowRun {
-        WorkflowRun {
-            database_id: id,
-            display_title: format!("Run {}", id),
-            name: "CI".to_string(),
-            head_branch: "main".to_string(),
-            status,
-            conclusion,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            event: "push".to_string(),
-            number: id,
-            url: format!("https://github.com/test/repo/actions/runs/{}", id),
-            jobs: None,
-        }
+        crate::mock::run(id, status, conclusion)
     }
 
     fn make_state() -> AppState {
         AppState::new("test/repo".to_string(), Some("main".to_string()), 20, None)
     }
 
+    #[test]
+    fn prefetch_targets_only_newly_failed_without_jobs() {
+        let mut state = make_state();
+        detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::InProgress, None),
+                make_run(2, RunStatus::InProgress, None),
+                make_run(3, RunStatus::InProgress, None),
+                make_run(4, RunStatus::Completed, Some(Conclusion::Failure)),
+            ],
+        );
+        let mut with_jobs = make_run(3, RunStatus::Completed, Some(Conclusion::Failure));
+        with_jobs.jobs = Some(Vec::new());
+        let after = vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
+            with_jobs,
+            make_run(4, RunStatus::Completed, Some(Conclusion::Failure)),
+        ];
+        let completions = detect_changes(&mut state, &after).completions;
+        // 2 succeeded, 3 already has jobs, 4 was failed before this poll
+        assert_eq!(prefetch_targets(&completions, &after), vec![1]);
+    }
+
+    #[test]
+    fn new_failure_sets_flash_deadline_once_per_cooldown() {
+        let mut state = make_state();
+        state.config.flash_on_failure = true;
+        state.config.bell_on_failure = true;
+        detect_changes(&mut state, &[make_run(1, RunStatus::InProgress, None)]);
+        assert!(state.flash_until.is_none());
+
+        detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::Completed, Some(Conclusion::Failure)),
+                make_run(2, RunStatus::InProgress, None),
+            ],
+        );
+        let first = state.flash_until.expect("flash armed");
+        assert!(state.is_flashing(std::time::Instant::now()));
+        assert!(std::mem::take(&mut state.bell_pending));
+
+        // A second failure inside the cooldown doesn't re-alert
+        detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::Completed, Some(Conclusion::Failure)),
+                make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+            ],
+        );
+        assert_eq!(state.flash_until, Some(first));
+        assert!(!state.bell_pending);
+    }
+
+    #[test]
+    fn success_or_disabled_alert_never_flashes() {
+        let mut state = make_state();
+        detect_changes(&mut state, &[make_run(1, RunStatus::InProgress, None)]);
+        detect_changes(
+            &mut state,
+            &[make_run(1, RunStatus::Completed, Some(Conclusion::Failure))],
+        );
+        assert!(state.flash_until.is_none());
+
+        state.config.flash_on_failure = true;
+        detect_changes(&mut state, &[make_run(2, RunStatus::InProgress, None)]);
+        detect_changes(
+            &mut state,
+            &[make_run(2, RunStatus::Completed, Some(Conclusion::Success))],
+        );
+        assert!(state.flash_until.is_none());
+    }
+
     #[test]
     fn first_poll_no_notifications() {
         let mut state = make_state();
@@ -243,6 +424,223 @@ mod tests {
         assert!(!state.previous_snapshot.contains_key(&1));
     }
 
+    #[test]
+    fn snapshot_keeps_recently_seen_while_evicting_stale() {
+        let mut state = make_state();
+        detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::InProgress, None),
+                make_run(2, RunStatus::InProgress, None),
+            ],
+        );
+        // Run 2 stays visible; run 1 scrolls out of --limit for the whole window.
+        for _ in 0..SNAPSHOT_EVICTION_POLLS {
+            detect_changes(&mut state, &[make_run(2, RunStatus::InProgress, None)]);
+        }
+        assert!(!state.previous_snapshot.contains_key(&1));
+        assert!(state.previous_snapshot.contains_key(&2));
+
+        // A run that reappears after eviction is treated as new, not "completed".
+        detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+                make_run(2, RunStatus::InProgress, None),
+            ],
+        );
+        assert!(state.notifications.is_empty());
+    }
+
+    #[test]
+    fn completion_classifies_failure_and_success() {
+        let mut state = make_state();
+        detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::InProgress, None),
+                make_run(2, RunStatus::InProgress, None),
+                make_run(3, RunStatus::InProgress, None),
+            ],
+        );
+        let completions = detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::Completed, Some(Conclusion::Failure)),
+                make_run(2, RunStatus::Completed, Some(Conclusion::Success)),
+                make_run(3, RunStatus::InProgress, None),
+            ],
+        )
+        .completions;
+        assert_eq!(
+            completions,
+            vec![
+                Completion {
+                    run_id: 1,
+                    failed: true
+                },
+                Completion {
+                    run_id: 2,
+                    failed: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn completion_timed_out_counts_as_failure() {
+        let mut state = make_state();
+        detect_changes(&mut state, &[make_run(1, RunStatus::InProgress, None)]);
+        let completions = detect_changes(
+            &mut state,
+            &[make_run(
+                1,
+                RunStatus::Completed,
+                Some(Conclusion::TimedOut),
+            )],
+        )
+        .completions;
+        assert!(completions[0].failed);
+    }
+
+    #[test]
+    fn completion_for_new_run_that_appeared_failed() {
+        let mut state = make_state();
+        detect_changes(&mut state, &[make_run(1, RunStatus::InProgress, None)]);
+        let completions = detect_changes(
+            &mut state,
+            &[
+                make_run(2, RunStatus::Completed, Some(Conclusion::Failure)),
+                make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
+                make_run(1, RunStatus::InProgress, None),
+            ],
+        )
+        .completions;
+        assert_eq!(
+            completions,
+            vec![Completion {
+                run_id: 2,
+                failed: true
+            }]
+        );
+    }
+
+    #[test]
+    fn summary_counts_new_queued_or_running_run_as_started_once() {
+        let mut state = make_state();
+        detect_changes(
+            &mut state,
+            &[make_run(1, RunStatus::Completed, Some(Conclusion::Success))],
+        );
+
+        let changes = detect_changes(
+            &mut state,
+            &[
+                make_run(3, RunStatus::Queued, None),
+                make_run(2, RunStatus::InProgress, None),
+                make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            ],
+        );
+        assert_eq!(changes.summary.started, 2);
+        assert_eq!(changes.summary.label().as_deref(), Some("2 started"));
+
+        // Queued → in progress on a later poll doesn't count it again
+        let changes = detect_changes(
+            &mut state,
+            &[
+                make_run(3, RunStatus::InProgress, None),
+                make_run(2, RunStatus::InProgress, None),
+                make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            ],
+        );
+        assert_eq!(changes.summary.started, 0);
+
+        // A rerun after completion starts again
+        detect_changes(
+            &mut state,
+            &[make_run(3, RunStatus::Completed, Some(Conclusion::Success))],
+        );
+        let changes = detect_changes(&mut state, &[make_run(3, RunStatus::InProgress, None)]);
+        assert_eq!(changes.summary.started, 1);
+    }
+
+    #[test]
+    fn summary_counts_each_transition() {
+        let mut state = make_state();
+        let first = detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::Queued, None),
+                make_run(2, RunStatus::Queued, None),
+                make_run(3, RunStatus::InProgress, None),
+                make_run(4, RunStatus::InProgress, None),
+                make_run(5, RunStatus::InProgress, None),
+            ],
+        );
+        // Everything is new on the first poll; nothing transitioned
+        assert_eq!(first.summary, ChangeSummary::default());
+        assert_eq!(first.summary.label(), None);
+
+        let changes = detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::InProgress, None),
+                make_run(2, RunStatus::InProgress, None),
+                make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
+                make_run(4, RunStatus::Completed, Some(Conclusion::Failure)),
+                make_run(5, RunStatus::InProgress, None),
+                make_run(6, RunStatus::Completed, Some(Conclusion::Cancelled)),
+            ],
+        );
+        assert_eq!(
+            changes.summary,
+            ChangeSummary {
+                started: 2,
+                succeeded: 1,
+                failed: 1,
+                ..ChangeSummary::default()
+            }
+        );
+        assert_eq!(
+            changes.summary.label().as_deref(),
+            Some("2 started, 1 succeeded, 1 failed")
+        );
+        // Per-run notifications are still pushed alongside the summary
+        assert_eq!(state.notifications.len(), 4);
+
+        let skipped = detect_changes(
+            &mut state,
+            &[
+                make_run(1, RunStatus::Completed, Some(Conclusion::Cancelled)),
+                make_run(2, RunStatus::Completed, Some(Conclusion::Skipped)),
+            ],
+        );
+        assert_eq!(
+            skipped.summary.label().as_deref(),
+            Some("1 cancelled, 1 finished")
+        );
+    }
+
+    #[test]
+    fn no_completion_on_first_poll_or_already_completed() {
+        let mut state = make_state();
+        let runs = vec![make_run(1, RunStatus::Completed, Some(Conclusion::Failure))];
+        assert!(detect_changes(&mut state, &runs).completions.is_empty());
+        assert!(detect_changes(&mut state, &runs).completions.is_empty());
+    }
+
+    #[test]
+    fn no_completion_for_old_run_reappearing_after_eviction() {
+        let mut state = make_state();
+        detect_changes(&mut state, &[make_run(5, RunStatus::InProgress, None)]);
+        let completions = detect_changes(
+            &mut state,
+            &[make_run(4, RunStatus::Completed, Some(Conclusion::Failure))],
+        )
+        .completions;
+        assert!(completions.is_empty());
+    }
+
     #[test]
     fn notification_contains_correct_run_id() {
         let mut state = make_state();
@@ -4,8 +4,11 @@
 //! blocks and would starve the async runtime. Drop signals shutdown without joining
 //! to avoid deadlocking if `poll` blocks during panic unwinding.
 
+use crate::app::BatchOp;
 use crate::app::Job;
+use crate::app::RateLimit;
 use crate::app::WorkflowRun;
+use crate::notify::Fallback;
 use crossterm::event::{self, Event as CrosstermEvent, KeyEvent};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -26,29 +29,71 @@ pub enum AppEvent {
         run_id: u64,
         jobs: Vec<Job>,
     },
+    /// Cumulative bytes of a log still streaming in; throttled by the executor.
+    LogFetchProgress {
+        run_id: u64,
+        job_id: Option<u64>,
+        bytes: usize,
+    },
     FailedLogResult {
         run_id: u64,
         job_id: Option<u64>,
-        title: String,
         content: String,
     },
     ClipboardResult(Result<(), String>),
     RerunSuccess(u64),
     CancelSuccess(u64),
     DeleteSuccess(u64),
-    /// Per-run ⚠ icon. Persists until run is refreshed. Use for job-fetch failures etc.
+    /// Outcome of a confirmed batch; `failed` carries each run's error message.
+    BatchResult {
+        op: BatchOp,
+        succeeded: Vec<u64>,
+        failed: Vec<(u64, String)>,
+    },
+    ApproveSuccess(u64),
+    DispatchSuccess {
+        workflow: String,
+        branch: String,
+    },
+    /// Desktop notification failed in `spawn_blocking`; the event loop rings the
+    /// bell or adds an in-app notification so the TUI isn't written to off-thread.
+    NotifyFallback {
+        run_id: u64,
+        fallback: Fallback,
+        reason: String,
+    },
+    /// Poll hit a rate limit; the poller waits `retry_in` seconds before the next try.
+    RateLimited {
+        retry_in: u64,
+    },
+    /// Periodic quota reading for the header.
+    RateLimitStatus(RateLimit),
+    /// Startup lookup of the authenticated login, for `M`.
+    CurrentUser(String),
+    /// Per-run ⚠ icon. Persists until retried, a later fetch succeeds, or the run
+    /// leaves the list. Use for job-fetch failures etc.
     RunError {
         run_id: u64,
         error: String,
     },
+    /// Log fetch failed; closes the overlay if it's still waiting, then toasts.
+    LogFetchFailed {
+        run_id: u64,
+        job_id: Option<u64>,
+        error: String,
+    },
     /// Global toast, auto-dismisses after `ERROR_TTL_SECS`. Use `RunError` for per-run.
     Error(String),
+    /// A manual refresh failed: ends the debounce, unlike other `Error` sources.
+    RefreshFailed(String),
 }
 
 pub struct EventHandler {
     rx: mpsc::UnboundedReceiver<AppEvent>,
     tx: mpsc::UnboundedSender<AppEvent>,
     shutdown: Arc<AtomicBool>,
+    /// Set while an external pager owns the terminal.
+    suspended: Arc<AtomicBool>,
     thread: Option<JoinHandle<()>>,
 }
 
@@ -58,9 +103,15 @@ impl EventHandler {
         let eventtx = tx.clone();
         let shutdown = Arc::new(AtomicBool::new(false));
         let shutdown_flag = shutdown.clone();
+        let suspended = Arc::new(AtomicBool::new(false));
+        let suspended_flag = suspended.clone();
 
         let thread = std::thread::spawn(move || {
             while !shutdown_flag.load(Ordering::Relaxed) {
+                if suspended_flag.load(Ordering::Relaxed) {
+                    std::thread::sleep(tick_rate);
+                    continue;
+                }
                 match event::poll(tick_rate) {
                     Err(e) => {
                         let _ = eventtx.send(AppEvent::Error(format!("Terminal poll error: {e}")));
@@ -75,10 +126,8 @@ impl EventHandler {
                     Ok(true) => {}
                 }
                 match event::read() {
-                    Ok(CrosstermEvent::Key(key)) => {
-                        if eventtx.send(AppEvent::Key(key)).is_err() {
-                            break;
-                        }
+                    Ok(CrosstermEvent::Key(key)) if eventtx.send(AppEvent::Key(key)).is_err() => {
+                        break;
                     }
                     Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {
                         // EINTR — retry silently
@@ -96,6 +145,7 @@ impl EventHandler {
             rx,
             tx,
             shutdown,
+            suspended,
             thread: Some(thread),
         }
     }
@@ -108,6 +158,17 @@ impl EventHandler {
         self.rx.recv().await
     }
 
+    /// Stop reading the terminal so a child process gets the keys. Waits out one
+    /// `tick_rate` so an in-flight `poll` can't grab the first key press.
+    pub fn suspend(&self, tick_rate: Duration) {
+        self.suspended.store(true, Ordering::Relaxed);
+        std::thread::sleep(tick_rate);
+    }
+
+    pub fn resume(&self) {
+        self.suspended.store(false, Ordering::Relaxed);
+    }
+
     pub fn stop(&mut self) {
         self.shutdown.store(true, Ordering::Relaxed);
         if let Some(handle) = self.thread.take() {
@@ -0,0 +1,401 @@
+//! Non-interactive output for scripts (`--once`, `--watch-until-complete`):
+//! plain-text run table and an exit code summarizing the outcome. No raw mode,
+//! no alternate screen.
+
+use crate::app::{self, AppState, Conclusion, RunStatus, Since, WorkflowRun};
+use crate::events::AppEvent;
+use crate::poller::{self, Poller};
+use crate::traits::{CiExecutor, CiParser};
+use color_eyre::eyre::{eyre, Result};
+use std::io::IsTerminal;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+
+pub const EXIT_SUCCESS: i32 = 0;
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_ERROR: i32 = 2;
+
+/// Off for `--no-color` or `NO_COLOR`. Piped output is never colored so
+/// `grep`/`awk` see plain text.
+pub fn color_enabled(no_color_flag: bool) -> bool {
+    !no_color_flag && !app::no_color_env() && std::io::stdout().is_terminal()
+}
+
+/// Where tracing output goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogTarget {
+    Off,
+    /// `--verbose`: the debug log under the state dir.
+    File,
+    /// `--log-stderr`: pipeable diagnostics next to `--once` output.
+    Stderr,
+}
+
+/// `--log-stderr` beats `--verbose`, but only without the TUI — anything
+/// written to stderr there would corrupt the screen, so it falls back to the file.
+pub fn log_target(verbose: bool, log_stderr: bool, once: bool) -> LogTarget {
+    if log_stderr && once {
+        LogTarget::Stderr
+    } else if verbose || log_stderr {
+        LogTarget::File
+    } else {
+        LogTarget::Off
+    }
+}
+
+fn is_failure(conclusion: Option<Conclusion>) -> bool {
+    conclusion.is_some_and(Conclusion::is_failure)
+}
+
+/// `EXIT_FAILURE` if any run failed, otherwise `EXIT_SUCCESS`. Runs still in
+/// progress don't count either way.
+pub fn exit_code(runs: &[WorkflowRun]) -> i32 {
+    if runs.iter().any(|r| is_failure(r.conclusion)) {
+        EXIT_FAILURE
+    } else {
+        EXIT_SUCCESS
+    }
+}
+
+fn conclusion_ansi(conclusion: Option<Conclusion>) -> &'static str {
+    match conclusion {
+        Some(Conclusion::Success) => "\x1b[32m",
+        c if is_failure(c) => "\x1b[31m",
+        None => "\x1b[33m",
+        Some(_) => "\x1b[90m",
+    }
+}
+
+/// One-line progress summary, e.g. `2 active, 3 succeeded, 1 failed`. Failures
+/// of known required checks are called out: `2 failed (1 required)`.
+pub fn format_summary(runs: &[WorkflowRun]) -> String {
+    let active = runs
+        .iter()
+        .filter(|r| r.status != RunStatus::Completed)
+        .count();
+    let succeeded = runs
+        .iter()
+        .filter(|r| r.conclusion == Some(Conclusion::Success))
+        .count();
+    let failed: Vec<&WorkflowRun> = runs.iter().filter(|r| is_failure(r.conclusion)).collect();
+    let required = failed.iter().filter(|r| r.required == Some(true)).count();
+    let required_note = if required > 0 {
+        format!(" ({required} required)")
+    } else {
+        String::new()
+    };
+    format!(
+        "{active} active, {succeeded} succeeded, {} failed{required_note}",
+        failed.len()
+    )
+}
+
+/// Column-aligned table with a header row. Colors only the conclusion column so
+/// alignment isn't thrown off by escape codes.
+pub fn format_table(runs: &[WorkflowRun], color: bool) -> String {
+    const HEADERS: [&str; 5] = ["#", "WORKFLOW", "BRANCH", "STATUS", "CONCLUSION"];
+    let rows: Vec<[String; 5]> = runs
+        .iter()
+        .map(|r| {
+            [
+                r.number.to_string(),
+                r.name.clone(),
+                r.head_branch.clone(),
+                r.status.as_str().to_string(),
+                r.conclusion.map_or("-", Conclusion::as_str).to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(unicode_width::UnicodeWidthStr::width(cell.as_str()));
+        }
+    }
+
+    let mut out = String::new();
+    let header: Vec<String> = HEADERS.map(str::to_string).to_vec();
+    push_row(&mut out, &header, &widths, None);
+    for (row, run) in rows.iter().zip(runs) {
+        let ansi = color.then(|| conclusion_ansi(run.conclusion));
+        push_row(&mut out, row, &widths, ansi);
+    }
+    out
+}
+
+fn push_row(out: &mut String, cells: &[String], widths: &[usize; 5], last_ansi: Option<&str>) {
+    let last = cells.len() - 1;
+    for (i, cell) in cells.iter().enumerate() {
+        if i == last {
+            match last_ansi {
+                Some(ansi) => {
+                    out.push_str(ansi);
+                    out.push_str(cell);
+                    out.push_str("\x1b[0m");
+                }
+                None => out.push_str(cell),
+            }
+        } else {
+            let pad =
+                widths[i].saturating_sub(unicode_width::UnicodeWidthStr::width(cell.as_str()));
+            out.push_str(cell);
+            out.push_str(&" ".repeat(pad + 2));
+        }
+    }
+    out.push('\n');
+}
+
+/// Headless counterpart of the startup screen's CLI check + repo detection.
+#[allow(clippy::type_complexity)]
+pub async fn resolve_repo(
+    executor: &dyn CiExecutor,
+    repo_arg: Option<&str>,
+    validate_repo: Option<fn(&str) -> Result<(), String>>,
+) -> Result<String> {
+    executor.check_available().await?;
+    let repo = match repo_arg {
+        Some(r) => r.to_string(),
+        None => executor.detect_repo().await?,
+    };
+    if let Some(validate) = validate_repo {
+        validate(&repo).map_err(|e| eyre!("{e}"))?;
+    }
+    Ok(repo)
+}
+
+/// Fetch once (just `watch_run` when given), asking for only `branch` runs when
+/// one was given explicitly and keeping only runs created after `since`.
+pub async fn fetch_runs(
+    executor: &dyn CiExecutor,
+    parser: &dyn CiParser,
+    limit: usize,
+    filter: Option<&str>,
+    branch: Option<&str>,
+    since: Option<Since>,
+    watch_run: Option<u64>,
+) -> Result<Vec<WorkflowRun>> {
+    let mut runs = poller::fetch_state(executor, parser, limit, filter, branch, watch_run).await?;
+    retain_since(&mut runs, since);
+    Ok(runs)
+}
+
+fn retain_since(runs: &mut Vec<WorkflowRun>, since: Option<Since>) {
+    if let Some(since) = since {
+        let cutoff = since.cutoff(chrono::Utc::now());
+        runs.retain(|r| r.created_at >= cutoff);
+    }
+}
+
+/// Prints the run table to stdout (errors to stderr) and returns the exit code.
+pub async fn run_once(
+    executor: &dyn CiExecutor,
+    parser: &dyn CiParser,
+    limit: usize,
+    filter: Option<&str>,
+    branch: Option<&str>,
+    since: Option<Since>,
+    watch_run: Option<u64>,
+    color: bool,
+) -> i32 {
+    match fetch_runs(executor, parser, limit, filter, branch, since, watch_run).await {
+        Ok(runs) => {
+            print!("{}", format_table(&runs, color));
+            exit_code(&runs)
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            EXIT_ERROR
+        }
+    }
+}
+
+/// Polls until no run is active, printing a summary line per poll, then prints
+/// the final table. Poll errors are reported but non-fatal — the poller backs off
+/// and retries — so only `timeout` bounds the wait.
+pub async fn watch_until_complete(
+    executor: Arc<dyn CiExecutor>,
+    parser: Arc<dyn CiParser>,
+    limit: usize,
+    filter: Option<String>,
+    branch: Option<String>,
+    since: Option<Since>,
+    watch_run: Option<u64>,
+    interval: u64,
+    intervals: app::Intervals,
+    timeout: Duration,
+    color: bool,
+) -> i32 {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let (interval_tx, interval_rx) = watch::channel(interval);
+    // Sender dropped right away: the filter never changes, `borrow` still works
+    let (_, filter_rx) = watch::channel(filter.clone());
+    let poller = Poller::new(executor, parser, limit, filter_rx, tx, interval_rx)
+        .with_watch_run(watch_run)
+        .with_branch(branch.clone());
+    let poller_handle = tokio::spawn(poller.run());
+
+    let mut state = AppState::new(String::new(), branch, limit, filter);
+    state.poll_interval = interval;
+    state.intervals = intervals;
+    let deadline = tokio::time::sleep(timeout);
+    tokio::pin!(deadline);
+
+    let code = loop {
+        tokio::select! {
+            () = &mut deadline => {
+                eprintln!(
+                    "Error: timed out after {}s waiting for runs to complete",
+                    timeout.as_secs()
+                );
+                break EXIT_ERROR;
+            }
+            event = rx.recv() => match event {
+                Some(AppEvent::PollResult { mut runs, .. }) => {
+                    retain_since(&mut runs, since);
+                    state.runs = runs;
+                    state.last_poll = Some(std::time::Instant::now());
+                    println!(
+                        "[{}] {}",
+                        chrono::Local::now().format("%H:%M:%S"),
+                        format_summary(&state.runs)
+                    );
+                    if !state.has_active_runs() {
+                        print!("{}", format_table(&state.runs, color));
+                        break exit_code(&state.runs);
+                    }
+                    // Same adaptive choice as the TUI, so `--*-interval` apply here too
+                    let new_interval = app::select_poll_interval(
+                        state.has_active_runs(),
+                        state
+                            .last_poll
+                            .is_some_and(|t| app::is_recent(t.elapsed().as_secs())),
+                        &state.intervals,
+                    );
+                    if new_interval != state.poll_interval {
+                        state.poll_interval = new_interval;
+                        if interval_tx.send(new_interval).is_err() {
+                            tracing::warn!("interval: poller channel closed");
+                        }
+                    }
+                }
+                Some(AppEvent::Error(msg)) => eprintln!("Error: {msg}"),
+                Some(AppEvent::RateLimited { retry_in }) => {
+                    eprintln!("Rate limited, retrying in {retry_in}s");
+                }
+                Some(_) => {}
+                None => {
+                    eprintln!("Error: poller stopped unexpectedly");
+                    break EXIT_ERROR;
+                }
+            },
+        }
+    };
+    poller_handle.abort();
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_target_prefers_stderr_only_without_tui() {
+        assert_eq!(log_target(false, false, false), LogTarget::Off);
+        assert_eq!(log_target(false, false, true), LogTarget::Off);
+        assert_eq!(log_target(true, false, true), LogTarget::File);
+        assert_eq!(log_target(false, true, true), LogTarget::Stderr);
+        assert_eq!(log_target(true, true, true), LogTarget::Stderr);
+        // Never stderr under the TUI
+        assert_eq!(log_target(false, true, false), LogTarget::File);
+        assert_eq!(log_target(true, true, false), LogTarget::File);
+    }
+
+    fn make_run(id: u64, status: RunStatus, conclusion: Option<Conclusion>) -> WorkflowRun {
+        crate::mock::run(id, status, conclusion)
+    }
+
+    #[test]
+    fn exit_code_all_success() {
+        let runs = vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::InProgress, None),
+        ];
+        assert_eq!(exit_code(&runs), EXIT_SUCCESS);
+    }
+
+    #[test]
+    fn exit_code_any_failure() {
+        let runs = vec![
+            make_run(1, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(2, RunStatus::Completed, Some(Conclusion::TimedOut)),
+        ];
+        assert_eq!(exit_code(&runs), EXIT_FAILURE);
+    }
+
+    #[test]
+    fn exit_code_empty_is_success() {
+        assert_eq!(exit_code(&[]), EXIT_SUCCESS);
+    }
+
+    #[test]
+    fn cancelled_is_not_failure() {
+        assert!(!is_failure(Some(Conclusion::Cancelled)));
+        assert!(is_failure(Some(Conclusion::StartupFailure)));
+    }
+
+    #[test]
+    fn summary_counts_active_succeeded_failed() {
+        let runs = vec![
+            make_run(1, RunStatus::InProgress, None),
+            make_run(2, RunStatus::Queued, None),
+            make_run(3, RunStatus::Completed, Some(Conclusion::Success)),
+            make_run(4, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(5, RunStatus::Completed, Some(Conclusion::Cancelled)),
+        ];
+        assert_eq!(format_summary(&runs), "2 active, 1 succeeded, 1 failed");
+    }
+
+    #[test]
+    fn summary_splits_out_required_failures() {
+        let mut required_fail = make_run(1, RunStatus::Completed, Some(Conclusion::Failure));
+        required_fail.required = Some(true);
+        let mut optional_fail = make_run(2, RunStatus::Completed, Some(Conclusion::Failure));
+        optional_fail.required = Some(false);
+        let mut required_pass = make_run(3, RunStatus::Completed, Some(Conclusion::Success));
+        required_pass.required = Some(true);
+        let runs = vec![required_fail, optional_fail, required_pass];
+        assert_eq!(
+            format_summary(&runs),
+            "0 active, 1 succeeded, 2 failed (1 required)"
+        );
+        assert_eq!(
+            format_summary(&runs[1..]),
+            "0 active, 1 succeeded, 1 failed"
+        );
+    }
+
+    #[test]
+    fn table_has_header_and_aligned_rows() {
+        let runs = vec![
+            make_run(7, RunStatus::Completed, Some(Conclusion::Failure)),
+            make_run(123, RunStatus::InProgress, None),
+        ];
+        let out = format_table(&runs, false);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("#    WORKFLOW"));
+        assert!(lines[1].starts_with("7    CI"));
+        assert!(lines[1].ends_with("failure"));
+        assert!(lines[2].contains("in_progress"));
+        assert!(lines[2].ends_with('-'));
+    }
+
+    #[test]
+    fn table_without_color_has_no_escapes() {
+        let runs = vec![make_run(1, RunStatus::Completed, Some(Conclusion::Success))];
+        assert!(!format_table(&runs, false).contains('\x1b'));
+        assert!(format_table(&runs, true).contains("\x1b[32msuccess\x1b[0m"));
+    }
+}
@@ -10,27 +10,95 @@ pub enum Action {
     Collapse,
     Toggle,
     Refresh,
+    /// Re-fetch jobs for the cursor run after a failed fetch.
+    Retry,
+    /// Re-fetch jobs for the cursor run, discarding the ones already loaded.
+    RefreshJobs,
     CancelRun,
     DeleteRun,
+    ToggleSelect,
+    ClearSelection,
+    CancelSelected,
+    DeleteSelected,
+    /// Cancel every in-progress run, marked or not (confirmed first).
+    CancelAllActive,
     ConfirmYes,
     RerunFailed,
     OpenBrowser,
+    OpenPr,
+    ToggleMatrixGroups,
+    ToggleCompact,
     CycleFilter,
+    CycleEventFilter,
     FilterBranch,
     QuickSelect(usize),
+    /// `g` followed by a run's letter label.
+    JumpToLabel(char),
+    /// `gg`.
+    JumpToFirst,
+    /// `G` without a count.
+    JumpToLast,
+    /// `<n>G`.
+    JumpToRow(usize),
+    /// A digit typed after another, extending the `<n>G` count.
+    CountDigit(usize),
+    NextFailure,
+    PrevFailure,
+    TogglePause,
+    ToggleNotify,
+    CollapseAll,
+    ExpandAll,
+    /// First key of a two-key sequence (e.g. `z` in `zc`); caller stores it in
+    /// `InputContext::pending_key` for the next press.
+    Prefix(char),
     ViewLogs,
     CopyToClipboard,
+    /// Log overlay: whole log instead of the `--log-lines` tail.
+    ToggleFullLog,
+    CopyUrl,
+    /// Every failed job's log for the run, straight to the clipboard.
+    CopyFailedLogs,
+    OpenLogExternal,
     CloseOverlay,
     ScrollUp,
     ScrollDown,
     PageUp,
     PageDown,
+    HalfPageUp,
+    HalfPageDown,
     ScrollToTop,
     ScrollToBottom,
+    ToggleFollow,
+    ToggleLineNumbers,
     ShowDetails,
+    ShowNotificationHistory,
+    DispatchWorkflow,
+    Approve,
+    OpenFilterInput,
+    OpenWorkflowPicker,
+    CycleSort,
+    ToggleReverse,
+    /// Nest runs under per-branch headers, or back to the flat list.
+    ToggleBranchGroups,
+    /// Hide skipped/neutral jobs and steps, or show them again.
+    ToggleHideSkipped,
+    /// Filter to runs triggered by the authenticated user, or clear it.
+    ToggleMyRuns,
+    PickerUp,
+    PickerDown,
+    InputChar(char),
+    InputBackspace,
+    InputSubmit,
     None,
 }
 
+impl Action {
+    /// Digits keep the `<n>G` count going; any other key consumes or drops it.
+    pub fn extends_count(&self) -> bool {
+        matches!(self, Action::CountDigit(_) | Action::QuickSelect(_))
+    }
+}
+
 /// Which overlay (if any) is currently displayed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum OverlayMode {
@@ -39,6 +107,8 @@ pub enum OverlayMode {
     Log,
     Detail,
     Confirm,
+    Input,
+    WorkflowPicker,
 }
 
 /// Captures the UI state needed to interpret a key press.
@@ -47,6 +117,12 @@ pub struct InputContext {
     pub has_error: bool,
     pub is_loading: bool,
     pub overlay: OverlayMode,
+    /// Prefix key from the previous press, if it started a sequence.
+    pub pending_key: Option<char>,
+    /// Digits typed so far; a `G` now jumps to that row.
+    pub pending_count: Option<usize>,
+    /// Cursor is on a run whose job fetch failed; `r` retries it instead of refreshing.
+    pub on_run_error: bool,
 }
 
 /// Priority: Ctrl+C → overlay keys → error dismissal → default keys.
@@ -65,6 +141,17 @@ pub fn map_key(key: KeyEvent, ctx: &InputContext) -> Action {
 
     // Log overlay mode
     if has_log_overlay {
+        // Pager-style paging; the plain letters have their own bindings and
+        // other chords fall through to them
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('d') => return Action::HalfPageDown,
+                KeyCode::Char('u') => return Action::HalfPageUp,
+                KeyCode::Char('f') => return Action::PageDown,
+                KeyCode::Char('b') => return Action::PageUp,
+                _ => {}
+            }
+        }
         return match key.code {
             KeyCode::Char('j') | KeyCode::Down => Action::ScrollDown,
             KeyCode::Char('k') | KeyCode::Up => Action::ScrollUp,
@@ -73,6 +160,10 @@ pub fn map_key(key: KeyEvent, ctx: &InputContext) -> Action {
             KeyCode::Char('g') => Action::ScrollToTop,
             KeyCode::Char('G') => Action::ScrollToBottom,
             KeyCode::Char('y') => Action::CopyToClipboard,
+            KeyCode::Char('E') => Action::OpenLogExternal,
+            KeyCode::Char('F') => Action::ToggleFollow,
+            KeyCode::Char('a') => Action::ToggleFullLog,
+            KeyCode::Char('n') => Action::ToggleLineNumbers,
             KeyCode::Char('q' | 'e') | KeyCode::Esc => Action::CloseOverlay,
             _ => Action::None,
         };
@@ -95,6 +186,40 @@ pub fn map_key(key: KeyEvent, ctx: &InputContext) -> Action {
         };
     }
 
+    if let Some(prefix) = ctx.pending_key {
+        return match (prefix, key.code) {
+            ('z', KeyCode::Char('c')) => Action::CollapseAll,
+            ('z', KeyCode::Char('o')) => Action::ExpandAll,
+            ('g', KeyCode::Char('g')) => Action::JumpToFirst,
+            ('g', KeyCode::Char(c)) if c.is_ascii_lowercase() => Action::JumpToLabel(c),
+            _ => Action::None,
+        };
+    }
+
+    // Workflow picker — typing filters the list, arrows move the selection
+    if ctx.overlay == OverlayMode::WorkflowPicker {
+        return match key.code {
+            KeyCode::Enter => Action::InputSubmit,
+            KeyCode::Backspace => Action::InputBackspace,
+            KeyCode::Esc => Action::CloseOverlay,
+            KeyCode::Up => Action::PickerUp,
+            KeyCode::Down => Action::PickerDown,
+            KeyCode::Char(c) => Action::InputChar(c),
+            _ => Action::None,
+        };
+    }
+
+    // Text input overlay mode — every printable key is text, not a command
+    if ctx.overlay == OverlayMode::Input {
+        return match key.code {
+            KeyCode::Enter => Action::InputSubmit,
+            KeyCode::Backspace => Action::InputBackspace,
+            KeyCode::Esc => Action::CloseOverlay,
+            KeyCode::Char(c) => Action::InputChar(c),
+            _ => Action::None,
+        };
+    }
+
     match key.code {
         KeyCode::Char('q') => Action::Quit,
         KeyCode::Esc => {
@@ -109,15 +234,52 @@ pub fn map_key(key: KeyEvent, ctx: &InputContext) -> Action {
         KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter => Action::Expand,
         KeyCode::Left | KeyCode::Char('h') => Action::Collapse,
         KeyCode::Char(' ') => Action::Toggle,
+        KeyCode::Char('r') if ctx.on_run_error => Action::Retry,
         KeyCode::Char('r') if !ctx.is_loading => Action::Refresh,
         KeyCode::Char('c') => Action::CancelRun,
         KeyCode::Char('x') => Action::DeleteRun,
+        KeyCode::Char('v') => Action::ToggleSelect,
+        KeyCode::Char('V') => Action::ClearSelection,
+        KeyCode::Char('C') => Action::CancelSelected,
+        KeyCode::Char('X') => Action::DeleteSelected,
+        KeyCode::Char('K') => Action::CancelAllActive,
         KeyCode::Char('R') => Action::RerunFailed,
         KeyCode::Char('o') => Action::OpenBrowser,
+        KeyCode::Char('P') => Action::OpenPr,
+        KeyCode::Char('Y') => Action::CopyUrl,
+        KeyCode::Char('y') => Action::CopyFailedLogs,
+        KeyCode::Char('m') => Action::ToggleMatrixGroups,
+        KeyCode::Char('L') => Action::ToggleCompact,
         KeyCode::Char('e') => Action::ViewLogs,
         KeyCode::Char('f') => Action::CycleFilter,
+        KeyCode::Char('t') => Action::CycleEventFilter,
         KeyCode::Char('b') => Action::FilterBranch,
         KeyCode::Char('d') => Action::ShowDetails,
+        KeyCode::Char('H') => Action::ShowNotificationHistory,
+        KeyCode::Char(']') => Action::NextFailure,
+        KeyCode::Char('[') => Action::PrevFailure,
+        KeyCode::Char('z') => Action::Prefix('z'),
+        KeyCode::Char('g') => Action::Prefix('g'),
+        KeyCode::Char('p') => Action::TogglePause,
+        KeyCode::Char('N') => Action::ToggleNotify,
+        KeyCode::Char('D') => Action::DispatchWorkflow,
+        KeyCode::Char('A') => Action::Approve,
+        KeyCode::Char('/') => Action::OpenFilterInput,
+        KeyCode::Char('w') => Action::OpenWorkflowPicker,
+        KeyCode::Char('S') => Action::CycleSort,
+        KeyCode::Char('O') => Action::ToggleReverse,
+        KeyCode::Char('B') => Action::ToggleBranchGroups,
+        KeyCode::Char('J') => Action::RefreshJobs,
+        KeyCode::Char('s') => Action::ToggleHideSkipped,
+        KeyCode::Char('M') => Action::ToggleMyRuns,
+        KeyCode::Char('G') => match ctx.pending_count {
+            Some(n) => Action::JumpToRow(n),
+            None => Action::JumpToLast,
+        },
+        // Past the first digit: `12G`, or `10G` which a bare `0` can't start
+        KeyCode::Char(c) if c.is_ascii_digit() && ctx.pending_count.is_some() => {
+            Action::CountDigit((c as u8 - b'0') as usize)
+        }
         KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
             Action::QuickSelect((c as u8 - b'0') as usize)
         }
@@ -281,6 +443,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn r_retries_on_run_error_even_while_loading() {
+        let ctx = InputContext {
+            on_run_error: true,
+            is_loading: true,
+            ..Default::default()
+        };
+        assert_eq!(map_key(press(KeyCode::Char('r')), &ctx), Action::Retry);
+    }
+
     #[test]
     fn rerun_failed_capital_r() {
         assert_eq!(
@@ -297,6 +469,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn toggle_matrix_groups_m() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('m')), &ctx()),
+            Action::ToggleMatrixGroups
+        );
+    }
+
+    #[test]
+    fn open_pr_shift_p() {
+        assert_eq!(map_key(press(KeyCode::Char('P')), &ctx()), Action::OpenPr);
+    }
+
     #[test]
     fn cycle_filter_f() {
         assert_eq!(
@@ -305,6 +490,288 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cycle_event_filter_t() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('t')), &ctx()),
+            Action::CycleEventFilter
+        );
+    }
+
+    #[test]
+    fn failure_navigation_brackets() {
+        assert_eq!(
+            map_key(press(KeyCode::Char(']')), &ctx()),
+            Action::NextFailure
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Char('[')), &ctx()),
+            Action::PrevFailure
+        );
+    }
+
+    fn ctx_pending(c: char) -> InputContext {
+        InputContext {
+            pending_key: Some(c),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn z_starts_sequence() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('z')), &ctx()),
+            Action::Prefix('z')
+        );
+    }
+
+    #[test]
+    fn zc_collapses_all_zo_expands_all() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('c')), &ctx_pending('z')),
+            Action::CollapseAll
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Char('o')), &ctx_pending('z')),
+            Action::ExpandAll
+        );
+    }
+
+    #[test]
+    fn vim_gg_g_and_counts() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('g')), &ctx_pending('g')),
+            Action::JumpToFirst
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Char('G')), &ctx()),
+            Action::JumpToLast
+        );
+
+        // The first digit still quick-selects; the main loop starts the count
+        assert_eq!(
+            map_key(press(KeyCode::Char('3')), &ctx()),
+            Action::QuickSelect(3)
+        );
+        let counting = InputContext {
+            pending_count: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            map_key(press(KeyCode::Char('G')), &counting),
+            Action::JumpToRow(3)
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Char('0')), &counting),
+            Action::CountDigit(0)
+        );
+        assert_eq!(map_key(press(KeyCode::Char('0')), &ctx()), Action::None);
+        assert_eq!(
+            map_key(press(KeyCode::Char('j')), &counting),
+            Action::MoveDown
+        );
+    }
+
+    #[test]
+    fn g_then_letter_jumps_to_label() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('g')), &ctx()),
+            Action::Prefix('g')
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Char('c')), &ctx_pending('g')),
+            Action::JumpToLabel('c')
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Char('1')), &ctx_pending('g')),
+            Action::None
+        );
+    }
+
+    #[test]
+    fn unknown_sequence_is_swallowed() {
+        // Unrecognized second key must not fall through to the default bindings
+        assert_eq!(
+            map_key(press(KeyCode::Char('x')), &ctx_pending('z')),
+            Action::None
+        );
+    }
+
+    #[test]
+    fn toggle_pause_p() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('p')), &ctx()),
+            Action::TogglePause
+        );
+    }
+
+    #[test]
+    fn log_overlay_n_toggles_line_numbers() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('n')), &ctx_log()),
+            Action::ToggleLineNumbers
+        );
+    }
+
+    #[test]
+    fn log_overlay_shift_e_opens_pager() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('E')), &ctx_log()),
+            Action::OpenLogExternal
+        );
+    }
+
+    fn ctx_picker() -> InputContext {
+        InputContext {
+            overlay: OverlayMode::WorkflowPicker,
+            ..InputContext::default()
+        }
+    }
+
+    #[test]
+    fn shift_s_cycles_sort() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('S')), &ctx()),
+            Action::CycleSort
+        );
+    }
+
+    #[test]
+    fn shift_o_toggles_reverse() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('O')), &ctx()),
+            Action::ToggleReverse
+        );
+    }
+
+    #[test]
+    fn s_toggles_hide_skipped() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('s')), &ctx()),
+            Action::ToggleHideSkipped
+        );
+    }
+
+    #[test]
+    fn shift_j_refreshes_jobs() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('J')), &ctx()),
+            Action::RefreshJobs
+        );
+    }
+
+    #[test]
+    fn shift_b_toggles_branch_groups() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('B')), &ctx()),
+            Action::ToggleBranchGroups
+        );
+    }
+
+    #[test]
+    fn shift_m_toggles_my_runs() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('M')), &ctx()),
+            Action::ToggleMyRuns
+        );
+    }
+
+    #[test]
+    fn w_opens_workflow_picker() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('w')), &ctx()),
+            Action::OpenWorkflowPicker
+        );
+    }
+
+    #[test]
+    fn workflow_picker_keys() {
+        let ctx = ctx_picker();
+        assert_eq!(map_key(press(KeyCode::Up), &ctx), Action::PickerUp);
+        assert_eq!(map_key(press(KeyCode::Down), &ctx), Action::PickerDown);
+        assert_eq!(map_key(press(KeyCode::Enter), &ctx), Action::InputSubmit);
+        assert_eq!(map_key(press(KeyCode::Esc), &ctx), Action::CloseOverlay);
+        // Letters are query text, not tree commands
+        assert_eq!(
+            map_key(press(KeyCode::Char('q')), &ctx),
+            Action::InputChar('q')
+        );
+    }
+
+    #[test]
+    fn slash_opens_filter_input() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('/')), &ctx()),
+            Action::OpenFilterInput
+        );
+    }
+
+    #[test]
+    fn copy_url_shift_y() {
+        assert_eq!(map_key(press(KeyCode::Char('Y')), &ctx()), Action::CopyUrl);
+        assert_eq!(map_key(press(KeyCode::Char('Y')), &ctx_log()), Action::None);
+    }
+
+    #[test]
+    fn copy_failed_logs_y_outside_overlay() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('y')), &ctx()),
+            Action::CopyFailedLogs
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Char('y')), &ctx_log()),
+            Action::CopyToClipboard
+        );
+    }
+
+    #[test]
+    fn toggle_notify_shift_n() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('N')), &ctx()),
+            Action::ToggleNotify
+        );
+    }
+
+    fn ctx_input() -> InputContext {
+        InputContext {
+            overlay: OverlayMode::Input,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn dispatch_capital_d() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('D')), &ctx()),
+            Action::DispatchWorkflow
+        );
+    }
+
+    #[test]
+    fn approve_capital_a() {
+        assert_eq!(map_key(press(KeyCode::Char('A')), &ctx()), Action::Approve);
+    }
+
+    #[test]
+    fn input_overlay_captures_text() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('q')), &ctx_input()),
+            Action::InputChar('q')
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Backspace), &ctx_input()),
+            Action::InputBackspace
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Enter), &ctx_input()),
+            Action::InputSubmit
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Esc), &ctx_input()),
+            Action::CloseOverlay
+        );
+    }
+
     #[test]
     fn filter_branch_b() {
         assert_eq!(
@@ -331,7 +798,7 @@ mod tests {
 
     #[test]
     fn unbound_key_returns_none() {
-        assert_eq!(map_key(press(KeyCode::Char('z')), &ctx()), Action::None);
+        assert_eq!(map_key(press(KeyCode::Char('%')), &ctx()), Action::None);
     }
 
     #[test]
@@ -346,6 +813,22 @@ mod tests {
 
     // --- Overlay mode tests ---
 
+    #[test]
+    fn overlay_follow_capital_f() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('F')), &ctx_log()),
+            Action::ToggleFollow
+        );
+    }
+
+    #[test]
+    fn overlay_full_log_a() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('a')), &ctx_log()),
+            Action::ToggleFullLog
+        );
+    }
+
     #[test]
     fn overlay_scroll_down_j() {
         assert_eq!(
@@ -375,6 +858,37 @@ mod tests {
         assert_eq!(map_key(press(KeyCode::PageUp), &ctx_log()), Action::PageUp);
     }
 
+    #[test]
+    fn overlay_ctrl_paging() {
+        let cases = [
+            ('d', Action::HalfPageDown),
+            ('u', Action::HalfPageUp),
+            ('f', Action::PageDown),
+            ('b', Action::PageUp),
+        ];
+        for (c, action) in cases {
+            let key = press_with(KeyCode::Char(c), KeyModifiers::CONTROL);
+            assert_eq!(map_key(key, &ctx_log()), action);
+        }
+        // Without Ctrl these stay what they were
+        assert_eq!(map_key(press(KeyCode::Char('f')), &ctx_log()), Action::None);
+        // Other chords aren't swallowed
+        assert_eq!(
+            map_key(
+                press_with(KeyCode::Char('a'), KeyModifiers::CONTROL),
+                &ctx_log()
+            ),
+            Action::ToggleFullLog
+        );
+        assert_eq!(
+            map_key(
+                press_with(KeyCode::Char('c'), KeyModifiers::CONTROL),
+                &ctx_log()
+            ),
+            Action::Quit
+        );
+    }
+
     #[test]
     fn overlay_scroll_to_top_g() {
         assert_eq!(
@@ -437,6 +951,18 @@ mod tests {
 
     // --- Detail overlay mode tests ---
 
+    #[test]
+    fn notification_history_shift_h() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('H')), &ctx()),
+            Action::ShowNotificationHistory
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Char('H')), &ctx_detail()),
+            Action::None
+        );
+    }
+
     #[test]
     fn show_details_d() {
         assert_eq!(
@@ -494,6 +1020,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn selection_keys() {
+        let cases = [
+            ('v', Action::ToggleSelect),
+            ('V', Action::ClearSelection),
+            ('C', Action::CancelSelected),
+            ('X', Action::DeleteSelected),
+            ('K', Action::CancelAllActive),
+        ];
+        for (c, action) in cases {
+            assert_eq!(map_key(press(KeyCode::Char(c)), &ctx()), action);
+        }
+    }
+
+    #[test]
+    fn toggle_compact_shift_l() {
+        assert_eq!(
+            map_key(press(KeyCode::Char('L')), &ctx()),
+            Action::ToggleCompact
+        );
+    }
+
     #[test]
     fn confirm_yes_y() {
         assert_eq!(
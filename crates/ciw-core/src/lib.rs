@@ -17,11 +17,16 @@
 )]
 
 pub mod app;
+pub mod cache;
 pub mod diff;
 pub mod events;
+pub mod headless;
 pub mod input;
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock;
 pub mod notify;
 pub mod platform;
 pub mod poller;
+pub mod session;
 pub mod traits;
 pub mod tui;
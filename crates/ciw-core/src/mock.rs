@@ -0,0 +1,206 @@
+//! Canned [`CiExecutor`]/[`CiParser`] for driving the poller and event loop
+//! without a real CLI. Exposed under the `test-util` feature.
+//!
+//! Responses are keyed by trait method name (`"fetch_runs"`, `"cancel_run"`, …).
+//! Unconfigured fetches return an empty JSON list and actions succeed, so a test
+//! only sets up the calls it cares about.
+
+use crate::app::{Conclusion, Job, RunStatus, WorkflowRun};
+use crate::traits::{tail_log, CiExecutor, CiParser};
+use async_trait::async_trait;
+use chrono::Utc;
+use color_eyre::eyre::{eyre, Result};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Default)]
+pub struct MockExecutor {
+    responses: HashMap<&'static str, Result<String, String>>,
+    delays: HashMap<&'static str, Duration>,
+    calls: Mutex<Vec<&'static str>>,
+}
+
+impl MockExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Canned output for `method`: raw JSON for fetches, ignored for actions.
+    pub fn with_response(mut self, method: &'static str, output: impl Into<String>) -> Self {
+        self.responses.insert(method, Ok(output.into()));
+        self
+    }
+
+    /// Makes `method` fail with `message`, as a CLI error would read.
+    pub fn with_error(mut self, method: &'static str, message: impl Into<String>) -> Self {
+        self.responses.insert(method, Err(message.into()));
+        self
+    }
+
+    /// Holds `method` for `delay` before answering, to exercise in-flight paths.
+    pub fn with_delay(mut self, method: &'static str, delay: Duration) -> Self {
+        self.delays.insert(method, delay);
+        self
+    }
+
+    /// Methods called so far, in order.
+    pub fn calls(&self) -> Vec<&'static str> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, method: &'static str) -> Result<String> {
+        self.calls.lock().unwrap().push(method);
+        match self.responses.get(method) {
+            Some(Ok(output)) => Ok(output.clone()),
+            Some(Err(message)) => Err(eyre!("{message}")),
+            None if method.starts_with("fetch_") && !method.contains("logs") => {
+                Ok("[]".to_string())
+            }
+            None => Ok(String::new()),
+        }
+    }
+
+    async fn respond(&self, method: &'static str) -> Result<String> {
+        if let Some(delay) = self.delays.get(method) {
+            tokio::time::sleep(*delay).await;
+        }
+        self.record(method)
+    }
+}
+
+#[async_trait]
+impl CiExecutor for MockExecutor {
+    async fn check_available(&self) -> Result<()> {
+        self.respond("check_available").await.map(drop)
+    }
+
+    async fn detect_repo(&self) -> Result<String> {
+        self.respond("detect_repo").await
+    }
+
+    async fn detect_branch(&self) -> Result<String> {
+        self.respond("detect_branch").await
+    }
+
+    async fn fetch_runs(
+        &self,
+        _limit: usize,
+        _filter: Option<&str>,
+        _branch: Option<&str>,
+    ) -> Result<String> {
+        self.respond("fetch_runs").await
+    }
+
+    async fn fetch_run(&self, _run_id: u64) -> Result<String> {
+        self.respond("fetch_run").await
+    }
+
+    async fn fetch_jobs(&self, _run_id: u64) -> Result<String> {
+        self.respond("fetch_jobs").await
+    }
+
+    async fn cancel_run(&self, _run_id: u64) -> Result<()> {
+        self.respond("cancel_run").await.map(drop)
+    }
+
+    async fn delete_run(&self, _run_id: u64) -> Result<()> {
+        self.respond("delete_run").await.map(drop)
+    }
+
+    async fn rerun_failed(&self, _run_id: u64) -> Result<()> {
+        self.respond("rerun_failed").await.map(drop)
+    }
+
+    async fn approve(&self, _run_id: u64) -> Result<()> {
+        self.respond("approve").await.map(drop)
+    }
+
+    async fn dispatch_workflow(&self, _workflow: &str, _branch: &str) -> Result<()> {
+        self.respond("dispatch_workflow").await.map(drop)
+    }
+
+    async fn current_user(&self) -> Result<String> {
+        self.respond("current_user").await
+    }
+
+    async fn fetch_failed_logs(&self, _run_id: u64) -> Result<String> {
+        self.respond("fetch_failed_logs").await
+    }
+
+    async fn fetch_failed_logs_for_job(&self, _run_id: u64, _job_id: u64) -> Result<String> {
+        self.respond("fetch_failed_logs_for_job").await
+    }
+
+    fn open_in_browser(&self, _url: &str) -> Result<()> {
+        self.record("open_in_browser").map(drop)
+    }
+
+    async fn copy_to_clipboard(&self, _text: &str) -> Result<()> {
+        self.respond("copy_to_clipboard").await.map(drop)
+    }
+}
+
+/// Reads the shared model's own serde shape, so canned runs can be written
+/// with `serde_json::to_string` instead of a platform fixture.
+pub struct MockParser;
+
+impl CiParser for MockParser {
+    fn parse_runs(&self, json: &str) -> Result<Vec<WorkflowRun>> {
+        serde_json::from_str(json).map_err(|e| eyre!("Failed to parse runs: {e}"))
+    }
+
+    fn parse_jobs(&self, json: &str) -> Result<Vec<Job>> {
+        serde_json::from_str(json).map_err(|e| eyre!("Failed to parse jobs: {e}"))
+    }
+
+    fn process_log_output(&self, raw: &str, max_lines: usize) -> (String, bool) {
+        tail_log(raw, max_lines)
+    }
+}
+
+/// A test run with everything optional unset; override what a test cares
+/// about with struct update syntax (`WorkflowRun { actor, ..mock::run(..) }`),
+/// so a new field only has to be added here.
+pub fn run(id: u64, status: RunStatus, conclusion: Option<Conclusion>) -> WorkflowRun {
+    let now = Utc::now();
+    WorkflowRun {
+        database_id: id,
+        display_title: format!("Run {id}"),
+        name: "CI".to_string(),
+        head_branch: "main".to_string(),
+        status,
+        conclusion,
+        created_at: now,
+        updated_at: now,
+        event: "push".to_string(),
+        number: id,
+        url: format!("https://github.com/test/repo/actions/runs/{id}"),
+        head_sha: String::new(),
+        head_commit_message: None,
+        workflow_path: None,
+        pr_number: None,
+        attempt: 1,
+        required: None,
+        actor: None,
+        timestamps_unknown: false,
+        jobs: None,
+    }
+}
+
+/// A test job with no id, timestamps, or steps; see [`run`].
+pub fn job(name: &str, status: RunStatus, conclusion: Option<Conclusion>) -> Job {
+    Job {
+        name: name.to_string(),
+        status,
+        conclusion,
+        started_at: None,
+        completed_at: None,
+        url: String::new(),
+        steps: Vec::new(),
+        database_id: None,
+        stage: None,
+        runner_name: None,
+        labels: Vec::new(),
+    }
+}
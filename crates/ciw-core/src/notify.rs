@@ -1,16 +1,167 @@
-#[cfg(feature = "desktop-notify")]
-use crate::app::{Conclusion, WorkflowRun};
+use crate::app::{Conclusion, NotifyScope, WorkflowRun};
+
 #[cfg(feature = "desktop-notify")]
 use notify_rust::{Notification, Urgency};
 
-/// Attempt to send a desktop notification for a completed run.
-/// Returns `Some(error_message)` on failure, `None` on success.
+/// A slow endpoint shouldn't pile up tasks during a burst of completions.
+#[cfg(feature = "webhook")]
+const WEBHOOK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// How to surface a completion the desktop notifier couldn't show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fallback {
+    Bell,
+    InApp,
+}
+
+/// `None` when the desktop notification went through.
+pub fn fallback_for(desktop_error: Option<&str>, bell: bool) -> Option<Fallback> {
+    desktop_error.map(|_| {
+        if bell {
+            Fallback::Bell
+        } else {
+            Fallback::InApp
+        }
+    })
+}
+
+/// BEL has no glyph, so writing it between frames leaves the TUI intact. Call from
+/// the event loop, never from the `spawn_blocking` notifier.
+pub fn ring_bell() {
+    use std::io::Write;
+    let mut out = std::io::stdout();
+    if out.write_all(b"\x07").and_then(|()| out.flush()).is_err() {
+        tracing::warn!("bell: failed to write to stdout");
+    }
+}
+
+/// Desktop pings only; the in-app notification list always covers every run.
+/// With no known branch there's nothing to scope to, so everything passes.
+pub fn should_notify(run: &WorkflowRun, scope: NotifyScope, branch: Option<&str>) -> bool {
+    match (scope, branch) {
+        (NotifyScope::CurrentBranch, Some(b)) => run.head_branch == b,
+        _ => true,
+    }
+}
+
+/// Placeholders a `--notify-command` template may use.
+const PLACEHOLDERS: [&str; 3] = ["title", "status", "url"];
+
+/// `--notify-command`: a program plus arguments, split on whitespace, with
+/// `{title}`/`{status}`/`{url}` filled in per run. Substitution happens per
+/// argument and nothing goes through a shell, so a title with spaces or quotes
+/// stays one argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotifyCommand {
+    argv: Vec<String>,
+}
+
+impl NotifyCommand {
+    /// The argument list for `run`, program first.
+    pub fn args_for(&self, run: &WorkflowRun) -> Vec<String> {
+        let status = run
+            .conclusion
+            .map_or(run.status.as_str(), Conclusion::as_str);
+        self.argv
+            .iter()
+            .map(|arg| substitute(arg, &run.display_title, status, &run.url))
+            .collect()
+    }
+
+    /// Blocks until the command exits; call from `spawn_blocking`.
+    fn run(&self, run: &WorkflowRun) -> Option<String> {
+        let args = self.args_for(run);
+        match std::process::Command::new(&args[0])
+            .args(&args[1..])
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status()
+        {
+            Ok(status) if status.success() => None,
+            Ok(status) => Some(format!("Notify command {} exited with {status}", args[0])),
+            Err(e) => Some(format!("Notify command {} failed: {e}", args[0])),
+        }
+    }
+}
+
+impl std::str::FromStr for NotifyCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let argv: Vec<String> = s.split_whitespace().map(str::to_string).collect();
+        if argv.is_empty() {
+            return Err("notify command is empty".to_string());
+        }
+        for arg in &argv {
+            check_placeholders(arg)?;
+        }
+        Ok(NotifyCommand { argv })
+    }
+}
+
+/// Rejects unknown names and stray braces up front, so a typo fails at
+/// startup rather than reaching the notifier as literal text.
+fn check_placeholders(arg: &str) -> Result<(), String> {
+    let mut rest = arg;
+    while let Some(open) = rest.find(['{', '}']) {
+        if rest[open..].starts_with('}') {
+            return Err(format!("unmatched '}}' in notify command argument '{arg}'"));
+        }
+        let after = &rest[open + 1..];
+        let Some(close) = after.find('}') else {
+            return Err(format!("unmatched '{{' in notify command argument '{arg}'"));
+        };
+        let name = &after[..close];
+        if !PLACEHOLDERS.contains(&name) {
+            return Err(format!(
+                "unknown placeholder '{{{name}}}' in notify command (expected {{title}}, {{status}} or {{url}})"
+            ));
+        }
+        rest = &after[close + 1..];
+    }
+    Ok(())
+}
+
+/// Fills the placeholders in one template argument. Values are inserted
+/// verbatim; braces inside them are not expanded again.
+pub fn substitute(arg: &str, title: &str, status: &str, url: &str) -> String {
+    let mut out = String::with_capacity(arg.len());
+    let mut rest = arg;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open..];
+        let value = [("{title}", title), ("{status}", status), ("{url}", url)]
+            .into_iter()
+            .find(|(token, _)| after.starts_with(token));
+        if let Some((token, value)) = value {
+            out.push_str(value);
+            rest = &after[token.len()..];
+        } else {
+            out.push('{');
+            rest = &after[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Attempt to send a desktop notification for a completed run, through
+/// `command` when one is configured. Returns `Some(error_message)` on
+/// failure, `None` on success.
+pub fn send_desktop(run: &WorkflowRun, command: Option<&NotifyCommand>) -> Option<String> {
+    match command {
+        Some(command) => command.run(run),
+        None => send_builtin(run),
+    }
+}
+
 #[cfg(feature = "desktop-notify")]
-pub fn send_desktop(run: &WorkflowRun) -> Option<String> {
+fn send_builtin(run: &WorkflowRun) -> Option<String> {
     let (summary, icon, urgency) = match run.conclusion {
-        Some(Conclusion::Success) => ("CI Passed", "dialog-information", Urgency::Normal),
-        Some(Conclusion::Failure) => ("CI Failed", "dialog-error", Urgency::Critical),
-        _ => ("CI Finished", "dialog-information", Urgency::Normal),
+        Some(Conclusion::Success) => ("✅ CI passed", "dialog-information", Urgency::Normal),
+        Some(c) if c.is_failure() => ("❌ CI failed", "dialog-error", Urgency::Critical),
+        _ => ("CI finished", "dialog-information", Urgency::Normal),
     };
 
     let body = match run.conclusion {
@@ -31,7 +182,188 @@ pub fn send_desktop(run: &WorkflowRun) -> Option<String> {
 }
 
 #[cfg(not(feature = "desktop-notify"))]
-pub fn send_desktop(_run: &crate::app::WorkflowRun) -> Option<String> {
-    // Desktop notifications disabled at compile time
-    None
+fn send_builtin(_run: &WorkflowRun) -> Option<String> {
+    // Reported as a failure so the bell / in-app fallback still fires
+    Some("Desktop notifications not compiled in".to_string())
+}
+
+/// `--webhook` body for a finished run. `text` is what Slack incoming webhooks
+/// display; the other fields are for anything that parses the JSON.
+pub fn webhook_payload(run: &WorkflowRun, repo: &str) -> serde_json::Value {
+    let outcome = match run.conclusion {
+        Some(Conclusion::Success) => "passed",
+        Some(c) if c.is_failure() => "failed",
+        _ => "finished",
+    };
+    serde_json::json!({
+        "text": format!(
+            "CI {outcome} in {repo}: {} (#{}) {}",
+            run.display_title, run.number, run.url
+        ),
+        "repo": repo,
+        "title": run.display_title,
+        "workflow": run.name,
+        "branch": run.head_branch,
+        "number": run.number,
+        "status": run.status.as_str(),
+        "conclusion": run.conclusion.map_or("-", Conclusion::as_str),
+        "url": run.url,
+    })
+}
+
+/// POSTs `payload` as JSON; the error is the toast text.
+#[cfg(feature = "webhook")]
+pub async fn send_webhook(url: &str, payload: &serde_json::Value) -> Result<(), String> {
+    let response = reqwest::Client::new()
+        .post(url)
+        .timeout(WEBHOOK_TIMEOUT)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(payload.to_string())
+        .send()
+        .await
+        .map_err(|e| format!("Webhook failed: {e}"))?;
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(format!("Webhook failed: HTTP {}", status.as_u16()))
+    }
+}
+
+#[cfg(not(feature = "webhook"))]
+#[allow(clippy::unused_async)]
+pub async fn send_webhook(_url: &str, _payload: &serde_json::Value) -> Result<(), String> {
+    Err("Webhook support not compiled in".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::RunStatus;
+
+    fn run_on(branch: &str) -> WorkflowRun {
+        WorkflowRun {
+            display_title: "Run 1".to_string(),
+            head_branch: branch.to_string(),
+            url: String::new(),
+            ..crate::mock::run(1, RunStatus::Completed, None)
+        }
+    }
+
+    #[test]
+    fn scope_all_notifies_every_branch() {
+        assert!(should_notify(
+            &run_on("feature"),
+            NotifyScope::All,
+            Some("main")
+        ));
+    }
+
+    #[test]
+    fn scope_current_branch_skips_other_branches() {
+        let scope = NotifyScope::CurrentBranch;
+        assert!(should_notify(&run_on("main"), scope, Some("main")));
+        assert!(!should_notify(&run_on("feature"), scope, Some("main")));
+    }
+
+    #[test]
+    fn scope_current_branch_without_branch_notifies() {
+        assert!(should_notify(
+            &run_on("feature"),
+            NotifyScope::CurrentBranch,
+            None
+        ));
+    }
+
+    #[test]
+    fn webhook_payload_carries_title_status_and_url() {
+        let mut run = run_on("main");
+        run.display_title = "Fix flaky test".to_string();
+        run.number = 42;
+        run.url = "https://github.com/o/r/actions/runs/1".to_string();
+        run.conclusion = Some(Conclusion::Failure);
+        let payload = webhook_payload(&run, "o/r");
+        assert_eq!(
+            payload["text"],
+            "CI failed in o/r: Fix flaky test (#42) https://github.com/o/r/actions/runs/1"
+        );
+        assert_eq!(payload["title"], "Fix flaky test");
+        assert_eq!(payload["status"], "completed");
+        assert_eq!(payload["conclusion"], "failure");
+        assert_eq!(payload["url"], "https://github.com/o/r/actions/runs/1");
+        assert_eq!(payload["branch"], "main");
+
+        run.conclusion = Some(Conclusion::Success);
+        let text = webhook_payload(&run, "o/r")["text"].to_string();
+        assert!(text.contains("CI passed"), "{text}");
+        run.conclusion = Some(Conclusion::Cancelled);
+        let text = webhook_payload(&run, "o/r")["text"].to_string();
+        assert!(text.contains("CI finished"), "{text}");
+    }
+
+    #[test]
+    fn substitute_fills_every_placeholder() {
+        assert_eq!(
+            substitute(
+                "[{status}] {title}: {url}",
+                "Fix {x}",
+                "failure",
+                "https://u"
+            ),
+            "[failure] Fix {x}: https://u"
+        );
+        assert_eq!(substitute("-title", "t", "s", "u"), "-title");
+        assert_eq!(substitute("{title}{title}", "a", "s", "u"), "aa");
+    }
+
+    #[test]
+    fn notify_command_args_keep_title_as_one_argument() {
+        let command: NotifyCommand = "terminal-notifier -title {title} -message CI:{status}"
+            .parse()
+            .unwrap();
+        let mut run = run_on("main");
+        run.display_title = "Fix flaky test".to_string();
+        assert_eq!(
+            command.args_for(&run),
+            vec![
+                "terminal-notifier",
+                "-title",
+                "Fix flaky test",
+                "-message",
+                "CI:completed"
+            ]
+        );
+        run.conclusion = Some(Conclusion::Failure);
+        assert_eq!(command.args_for(&run)[4], "CI:failure");
+    }
+
+    #[test]
+    fn notify_command_rejects_bad_templates() {
+        assert!("".parse::<NotifyCommand>().is_err());
+        assert!("notify {branch}".parse::<NotifyCommand>().is_err());
+        assert!("notify {title".parse::<NotifyCommand>().is_err());
+        assert!("notify title}".parse::<NotifyCommand>().is_err());
+        assert!("notify {url}".parse::<NotifyCommand>().is_ok());
+    }
+
+    #[test]
+    fn fallback_none_when_desktop_succeeds() {
+        assert_eq!(fallback_for(None, true), None);
+        assert_eq!(fallback_for(None, false), None);
+    }
+
+    #[test]
+    fn fallback_bell_or_in_app_on_error() {
+        assert_eq!(fallback_for(Some("no dbus"), true), Some(Fallback::Bell));
+        assert_eq!(fallback_for(Some("no dbus"), false), Some(Fallback::InApp));
+    }
+
+    #[test]
+    fn notify_scope_parses() {
+        assert_eq!(
+            "current-branch".parse::<NotifyScope>(),
+            Ok(NotifyScope::CurrentBranch)
+        );
+        assert!("mine".parse::<NotifyScope>().is_err());
+    }
 }
@@ -2,10 +2,14 @@
 //!
 //! Interval is received via `watch::Receiver<u64>` — the main event loop adjusts it
 //! (3s/10s/30s) without restarting the poller. On consecutive failures, exponential
-//! backoff is applied up to `MAX_BACKOFF_SECS`.
+//! backoff is applied up to `MAX_BACKOFF_SECS`. Rate-limit errors back off from
+//! at least `RATE_LIMIT_MIN_SECS` — retrying at the active 3s interval only digs
+//! the hole deeper.
 
+use crate::app::WorkflowRun;
 use crate::events::AppEvent;
 use crate::traits::{CiExecutor, CiParser};
+use color_eyre::eyre::Result;
 use std::sync::Arc;
 use tokio::sync::{mpsc, watch};
 use tokio::time;
@@ -13,13 +17,85 @@ use tokio::time;
 /// 5 min — longest wait between retries before user should investigate manually.
 const MAX_BACKOFF_SECS: u64 = 300;
 
+/// Floor for the rate-limit backoff base; primary limits reset on the hour and
+/// secondary limits ask for a minute or more.
+const RATE_LIMIT_MIN_SECS: u64 = 60;
+
+/// Prefix executors put on rate-limit errors so the poller can tell them apart.
+pub const RATE_LIMITED: &str = "Rate limited";
+
+pub fn is_rate_limited(message: &str) -> bool {
+    message.starts_with(RATE_LIMITED)
+}
+
+/// Both CLIs pass the API's wording through: "API rate limit exceeded",
+/// "secondary rate limit", or an HTTP 429. Executors prefix such errors with
+/// [`RATE_LIMITED`].
+pub fn is_rate_limit_error(stderr: &str) -> bool {
+    let lower = stderr.to_ascii_lowercase();
+    lower.contains("rate limit")
+        || lower.contains("http 429")
+        || lower.contains("too many requests")
+}
+
+/// Interval sentinel: the poller idles until a non-zero interval is sent.
+pub const POLL_PAUSED: u64 = 0;
+
+/// `--jitter` spread: each sleep lands within ±10% of the interval.
+const JITTER_PERCENT: u64 = 10;
+
+/// Randomizes poll sleeps so instances started together drift apart instead of
+/// hitting the API in lockstep. SplitMix64 — no crypto needed, and a fixed
+/// seed makes it testable.
+#[derive(Debug, Clone)]
+pub struct Jitter {
+    state: u64,
+}
+
+impl Jitter {
+    pub fn seeded(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Seeded from the clock and pid, so concurrent instances differ.
+    pub fn from_clock() -> Self {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        Self::seeded(nanos ^ (u64::from(std::process::id()) << 32))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `secs` shifted by a uniform offset in ±`JITTER_PERCENT`%, at millisecond
+    /// resolution so short intervals still spread.
+    pub fn apply(&mut self, secs: u64) -> time::Duration {
+        let base_ms = secs.saturating_mul(1000);
+        let spread = base_ms * JITTER_PERCENT / 100;
+        let offset = self.next_u64() % (2 * spread + 1);
+        time::Duration::from_millis(base_ms - spread + offset)
+    }
+}
+
 pub struct Poller {
     executor: Arc<dyn CiExecutor>,
     parser: Arc<dyn CiParser>,
     limit: usize,
-    filter: Option<String>,
+    /// Workflow filter; the TUI's picker swaps it without restarting the poller.
+    filter_rx: watch::Receiver<Option<String>>,
     tx: mpsc::UnboundedSender<AppEvent>,
     interval_rx: watch::Receiver<u64>,
+    jitter: Option<Jitter>,
+    /// `--watch-run`: poll this one run instead of the list.
+    watch_run: Option<u64>,
+    /// Headless `--branch`: only that branch's runs count toward `limit`.
+    branch: Option<String>,
 }
 
 /// Compute backoff delay: `min(base_interval * 2^failures, MAX_BACKOFF_SECS)`.
@@ -30,12 +106,18 @@ pub fn backoff_delay(base_interval: u64, failures: u32) -> u64 {
         .clamp(1, MAX_BACKOFF_SECS)
 }
 
+/// Backoff after consecutive rate-limited polls, doubling from
+/// `max(base_interval, RATE_LIMIT_MIN_SECS)`.
+pub fn rate_limit_delay(base_interval: u64, failures: u32) -> u64 {
+    backoff_delay(base_interval.max(RATE_LIMIT_MIN_SECS), failures)
+}
+
 impl Poller {
     pub fn new(
         executor: Arc<dyn CiExecutor>,
         parser: Arc<dyn CiParser>,
         limit: usize,
-        filter: Option<String>,
+        filter_rx: watch::Receiver<Option<String>>,
         tx: mpsc::UnboundedSender<AppEvent>,
         interval_rx: watch::Receiver<u64>,
     ) -> Self {
@@ -43,39 +125,102 @@ impl Poller {
             executor,
             parser,
             limit,
-            filter,
+            filter_rx,
             tx,
             interval_rx,
+            jitter: None,
+            watch_run: None,
+            branch: None,
         }
     }
 
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = Some(jitter);
+        self
+    }
+
+    pub fn with_watch_run(mut self, watch_run: Option<u64>) -> Self {
+        self.watch_run = watch_run;
+        self
+    }
+
+    pub fn with_branch(mut self, branch: Option<String>) -> Self {
+        self.branch = branch;
+        self
+    }
+
     pub async fn run(mut self) {
         let mut failures: u32 = 0;
+        let mut rate_limited = false;
 
         // Initial fetch
         match self.poll_once().await {
             PollOutcome::Success => failures = 0,
             PollOutcome::Failure => failures = failures.saturating_add(1),
+            PollOutcome::RateLimited => {
+                failures = failures.saturating_add(1);
+                rate_limited = true;
+                let retry_in = rate_limit_delay(*self.interval_rx.borrow(), failures);
+                if self.tx.send(AppEvent::RateLimited { retry_in }).is_err() {
+                    return;
+                }
+            }
             PollOutcome::ChannelClosed => return,
         }
 
         loop {
-            let base_interval = *self.interval_rx.borrow();
-            let delay = if failures > 0 {
-                backoff_delay(base_interval, failures)
+            let current = *self.interval_rx.borrow();
+            if current == POLL_PAUSED {
+                if self.interval_rx.changed().await.is_err() {
+                    return; // Sender dropped
+                }
             } else {
-                base_interval
-            };
-            // Wake early if the polling interval changes (e.g. idle -> active)
-            tokio::select! {
-                () = time::sleep(time::Duration::from_secs(delay)) => {},
-                _ = self.interval_rx.changed() => {},
+                let delay = if rate_limited {
+                    rate_limit_delay(current, failures)
+                } else if failures > 0 {
+                    backoff_delay(current, failures)
+                } else {
+                    current
+                };
+                let sleep = match &mut self.jitter {
+                    Some(jitter) => jitter.apply(delay),
+                    None => time::Duration::from_secs(delay),
+                };
+                if rate_limited {
+                    // Sit out the whole backoff: an idle -> active switch
+                    // mustn't send us back to an API that just refused us
+                    time::sleep(sleep).await;
+                } else {
+                    // Wake early if the polling interval changes (e.g. idle -> active)
+                    tokio::select! {
+                        () = time::sleep(sleep) => {},
+                        _ = self.interval_rx.changed() => {},
+                    }
+                }
+            }
+            // Re-read: a resume polls right away so the view catches up
+            let base_interval = *self.interval_rx.borrow();
+            if base_interval == POLL_PAUSED {
+                continue;
             }
 
             match self.poll_once().await {
-                PollOutcome::Success => failures = 0,
+                PollOutcome::Success => {
+                    failures = 0;
+                    rate_limited = false;
+                }
+                PollOutcome::RateLimited => {
+                    // A generic failure streak before the limit still counts
+                    failures = failures.saturating_add(1);
+                    rate_limited = true;
+                    let retry_in = rate_limit_delay(base_interval, failures);
+                    if self.tx.send(AppEvent::RateLimited { retry_in }).is_err() {
+                        return;
+                    }
+                }
                 PollOutcome::Failure => {
                     failures = failures.saturating_add(1);
+                    rate_limited = false;
                     let next_delay = backoff_delay(base_interval, failures);
                     if self
                         .tx
@@ -94,10 +239,15 @@ impl Poller {
 
     /// Returns the outcome of a single poll attempt.
     async fn poll_once(&self) -> PollOutcome {
-        match self
-            .executor
-            .fetch_runs(self.limit, self.filter.as_deref())
-            .await
+        let filter = self.filter_rx.borrow().clone();
+        match fetch_run_list(
+            &*self.executor,
+            self.limit,
+            filter.as_deref(),
+            self.branch.as_deref(),
+            self.watch_run,
+        )
+        .await
         {
             Ok(json) => match self.parser.parse_runs(&json) {
                 Ok(runs) => {
@@ -125,7 +275,11 @@ impl Poller {
                 }
             },
             Err(e) => {
-                if self.tx.send(AppEvent::Error(format!("{e}"))).is_err() {
+                let message = format!("{e}");
+                if is_rate_limited(&message) {
+                    return PollOutcome::RateLimited;
+                }
+                if self.tx.send(AppEvent::Error(message)).is_err() {
                     return PollOutcome::ChannelClosed;
                 }
                 PollOutcome::Failure
@@ -137,9 +291,40 @@ impl Poller {
 enum PollOutcome {
     Success,
     Failure,
+    /// Reported via `AppEvent::RateLimited` by the caller, which knows the delay.
+    RateLimited,
     ChannelClosed,
 }
 
+/// A poll's raw runs JSON: the list, or only `watch_run` under `--watch-run`.
+pub async fn fetch_run_list(
+    executor: &dyn CiExecutor,
+    limit: usize,
+    filter: Option<&str>,
+    branch: Option<&str>,
+    watch_run: Option<u64>,
+) -> Result<String> {
+    match watch_run {
+        Some(run_id) => executor.fetch_run(run_id).await,
+        None => executor.fetch_runs(limit, filter, branch).await,
+    }
+}
+
+/// One fetch-and-parse of the run list, for callers outside the TUI (and the
+/// manual refresh). The poller keeps its own split so it can tell rate limits
+/// from parse errors.
+pub async fn fetch_state(
+    executor: &dyn CiExecutor,
+    parser: &dyn CiParser,
+    limit: usize,
+    workflow: Option<&str>,
+    branch: Option<&str>,
+    watch_run: Option<u64>,
+) -> Result<Vec<WorkflowRun>> {
+    let json = fetch_run_list(executor, limit, workflow, branch, watch_run).await?;
+    parser.parse_runs(&json)
+}
+
 pub async fn fetch_jobs_for_run(
     executor: &dyn CiExecutor,
     parser: &dyn CiParser,
@@ -206,6 +391,34 @@ pub async fn fetch_jobs_for_run(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock::{MockExecutor, MockParser};
+    use std::time::Duration;
+
+    #[test]
+    fn jitter_stays_within_ten_percent() {
+        let mut jitter = Jitter::seeded(42);
+        for base in [3, 10, 30, 300] {
+            let (lo, hi) = (base * 900, base * 1100);
+            let samples: Vec<u128> = (0..500).map(|_| jitter.apply(base).as_millis()).collect();
+            assert!(
+                samples
+                    .iter()
+                    .all(|&ms| (u128::from(lo)..=u128::from(hi)).contains(&ms)),
+                "{base}s out of band"
+            );
+            // Actually spreads rather than returning the base every time
+            assert!(samples.iter().any(|&ms| ms < u128::from(base) * 1000));
+            assert!(samples.iter().any(|&ms| ms > u128::from(base) * 1000));
+        }
+    }
+
+    #[test]
+    fn jitter_is_deterministic_per_seed() {
+        let (mut a, mut b) = (Jitter::seeded(7), Jitter::seeded(7));
+        for _ in 0..10 {
+            assert_eq!(a.apply(10), b.apply(10));
+        }
+    }
 
     #[test]
     fn backoff_zero_failures_returns_base() {
@@ -241,4 +454,124 @@ mod tests {
     fn backoff_base_zero_floors_to_one() {
         assert_eq!(backoff_delay(0, 5), 1);
     }
+
+    #[test]
+    fn rate_limit_delay_floors_base_and_doubles() {
+        assert_eq!(rate_limit_delay(3, 1), 120);
+        assert_eq!(rate_limit_delay(3, 2), 240);
+        assert_eq!(rate_limit_delay(90, 1), 180);
+        assert_eq!(rate_limit_delay(3, 3), MAX_BACKOFF_SECS);
+    }
+
+    #[test]
+    fn rate_limited_prefix_detection() {
+        assert!(is_rate_limited(&format!(
+            "{RATE_LIMITED}: API rate limit exceeded"
+        )));
+        assert!(!is_rate_limited("gh command failed: HTTP 500"));
+    }
+
+    #[test]
+    fn rate_limit_error_matches_cli_wording() {
+        assert!(is_rate_limit_error("API rate limit exceeded for user ID 1"));
+        assert!(is_rate_limit_error(
+            "You have exceeded a secondary rate limit"
+        ));
+        assert!(is_rate_limit_error("HTTP 429: Too Many Requests"));
+        assert!(!is_rate_limit_error("HTTP 404: Not Found"));
+    }
+
+    fn make_run(id: u64) -> WorkflowRun {
+        let now = chrono::Utc::now();
+        WorkflowRun {
+            created_at: now,
+            updated_at: now,
+            ..crate::mock::run(
+                id,
+                crate::app::RunStatus::Completed,
+                Some(crate::app::Conclusion::Success),
+            )
+        }
+    }
+
+    fn runs_json(ids: &[u64]) -> String {
+        let runs: Vec<WorkflowRun> = ids.iter().map(|&id| make_run(id)).collect();
+        serde_json::to_string(&runs).unwrap()
+    }
+
+    fn block_on<T>(fut: impl std::future::Future<Output = T>) -> T {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+            .block_on(fut)
+    }
+
+    #[test]
+    fn fetch_state_parses_the_run_list() {
+        let executor = MockExecutor::new().with_response("fetch_runs", runs_json(&[1, 2, 3]));
+        let runs = block_on(fetch_state(&executor, &MockParser, 3, None, None, None)).unwrap();
+        let ids: Vec<u64> = runs.iter().map(|r| r.database_id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn fetch_state_fetches_only_the_watched_run() {
+        let executor = MockExecutor::new().with_response("fetch_run", runs_json(&[42]));
+        let runs = block_on(fetch_state(&executor, &MockParser, 3, None, None, Some(42))).unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].database_id, 42);
+        assert_eq!(executor.calls(), vec!["fetch_run"]);
+    }
+
+    #[test]
+    fn fetch_state_surfaces_parse_errors() {
+        let executor = MockExecutor::new().with_response("fetch_runs", "not json");
+        assert!(block_on(fetch_state(&executor, &MockParser, 3, None, None, None)).is_err());
+    }
+
+    /// Runs a poller over `executor` until its first event.
+    fn first_poll_event(executor: MockExecutor) -> AppEvent {
+        block_on(async {
+            let (tx, mut rx) = mpsc::unbounded_channel();
+            let (_filter_tx, filter_rx) = watch::channel(None);
+            let (_interval_tx, interval_rx) = watch::channel(POLL_PAUSED);
+            let poller = Poller::new(
+                Arc::new(executor),
+                Arc::new(MockParser),
+                10,
+                filter_rx,
+                tx,
+                interval_rx,
+            );
+            let handle = tokio::spawn(poller.run());
+            let event = rx.recv().await.unwrap();
+            handle.abort();
+            event
+        })
+    }
+
+    #[test]
+    fn poller_emits_poll_result_from_executor() {
+        let executor = MockExecutor::new()
+            .with_response("fetch_runs", runs_json(&[7, 8]))
+            .with_delay("fetch_runs", Duration::from_millis(5));
+        match first_poll_event(executor) {
+            AppEvent::PollResult { runs, manual } => {
+                assert!(!manual);
+                assert_eq!(runs.len(), 2);
+                assert_eq!(runs[0].database_id, 7);
+            }
+            other => panic!("expected PollResult, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn poller_surfaces_executor_error() {
+        let executor = MockExecutor::new().with_error("fetch_runs", "gh command failed: HTTP 500");
+        match first_poll_event(executor) {
+            AppEvent::Error(message) => assert_eq!(message, "gh command failed: HTTP 500"),
+            other => panic!("expected Error, got {other:?}"),
+        }
+    }
 }
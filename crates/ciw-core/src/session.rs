@@ -0,0 +1,193 @@
+//! Opt-in (`--session`) UI state that survives restarts: filter, sort, text
+//! filter, expanded runs and the cursor's run. One file per repo under the state
+//! dir, written on quit and applied once the startup runs are in the tree.
+
+use crate::app::{AppState, FilterMode, SortMode, TreeLevel};
+use color_eyre::eyre::{eyre, Result};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SessionState {
+    pub filter: FilterMode,
+    pub sort_mode: SortMode,
+    #[serde(default)]
+    pub text_filter: Option<String>,
+    #[serde(default)]
+    pub expanded_runs: Vec<u64>,
+    #[serde(default)]
+    pub cursor_run_id: Option<u64>,
+}
+
+impl SessionState {
+    pub fn capture(state: &AppState) -> Self {
+        let mut expanded_runs: Vec<u64> = state.expanded_runs.iter().copied().collect();
+        expanded_runs.sort_unstable();
+        Self {
+            filter: state.filter,
+            sort_mode: state.sort_mode,
+            text_filter: state.text_filter.clone(),
+            expanded_runs,
+            cursor_run_id: state.current_run_id(),
+        }
+    }
+
+    /// Applies to `state` and rebuilds the tree. Expanded ids for runs no longer
+    /// listed are dropped; returns the expanded ones still missing jobs.
+    pub fn restore(self, state: &mut AppState) -> Vec<u64> {
+        state.filter = self.filter;
+        state.sort_mode = self.sort_mode;
+        state.text_filter = self.text_filter;
+        state.expanded_runs = self
+            .expanded_runs
+            .into_iter()
+            .filter(|id| state.runs.iter().any(|r| r.database_id == *id))
+            .collect();
+        state.rebuild_tree();
+
+        // After the rebuild, so a vanished or filtered-out run leaves the clamped cursor
+        if let Some(run_id) = self.cursor_run_id {
+            if let Some(i) = state.tree_items.iter().position(|item| {
+                item.level == TreeLevel::Run
+                    && state
+                        .runs
+                        .get(item.run_idx)
+                        .is_some_and(|r| r.database_id == run_id)
+            }) {
+                state.cursor = i;
+            }
+        }
+
+        let mut needs_jobs: Vec<u64> = state
+            .runs
+            .iter()
+            .filter(|r| state.expanded_runs.contains(&r.database_id) && r.jobs.is_none())
+            .map(|r| r.database_id)
+            .collect();
+        needs_jobs.sort_unstable();
+        needs_jobs
+    }
+}
+
+/// Same flattening as [`crate::cache::cache_file`], under `session/`.
+pub fn session_file(state_dir: &Path, repo: &str) -> PathBuf {
+    state_dir
+        .join("session")
+        .join(format!("{}.json", repo.replace('/', "__")))
+}
+
+pub fn save(path: &Path, session: &SessionState) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| eyre!("Failed to create session directory {dir:?}: {e}"))?;
+    }
+    let json = serde_json::to_string(session)?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json).map_err(|e| eyre!("Failed to write session {tmp:?}: {e}"))?;
+    std::fs::rename(&tmp, path).map_err(|e| eyre!("Failed to replace session {path:?}: {e}"))
+}
+
+pub fn load(path: &Path) -> Result<SessionState> {
+    let json =
+        std::fs::read_to_string(path).map_err(|e| eyre!("Failed to read session {path:?}: {e}"))?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{Conclusion, RunStatus, WorkflowRun};
+    use chrono::{TimeZone, Utc};
+
+    fn temp_state_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ciw-session-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn make_run(id: u64) -> WorkflowRun {
+        let at = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        WorkflowRun {
+            created_at: at,
+            updated_at: at,
+            url: String::new(),
+            ..crate::mock::run(id, RunStatus::Completed, Some(Conclusion::Failure))
+        }
+    }
+
+    fn sample() -> SessionState {
+        SessionState {
+            filter: FilterMode::FailuresOnly,
+            sort_mode: SortMode::Duration,
+            text_filter: Some("run".to_string()),
+            expanded_runs: vec![2, 5],
+            cursor_run_id: Some(2),
+        }
+    }
+
+    #[test]
+    fn round_trip_preserves_fields() {
+        let dir = temp_state_dir("roundtrip");
+        let path = session_file(&dir, "o/r");
+        save(&path, &sample()).unwrap();
+        let loaded = load(&path).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(loaded, sample());
+    }
+
+    #[test]
+    fn load_tolerates_missing_optional_fields() {
+        let json = r#"{"filter":"active_only","sort_mode":"status"}"#;
+        let loaded: SessionState = serde_json::from_str(json).unwrap();
+        assert_eq!(loaded.filter, FilterMode::ActiveOnly);
+        assert_eq!(loaded.sort_mode, SortMode::Status);
+        assert!(loaded.expanded_runs.is_empty());
+    }
+
+    #[test]
+    fn restore_drops_missing_runs_and_places_cursor() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.runs = vec![make_run(1), make_run(2), make_run(3)];
+        state.rebuild_tree();
+
+        let needs_jobs = sample().restore(&mut state);
+
+        assert_eq!(state.filter, FilterMode::FailuresOnly);
+        assert_eq!(state.sort_mode, SortMode::Duration);
+        assert_eq!(state.text_filter.as_deref(), Some("run"));
+        assert!(state.expanded_runs.contains(&2));
+        assert!(!state.expanded_runs.contains(&5));
+        assert_eq!(needs_jobs, vec![2]);
+        assert_eq!(state.current_run_id(), Some(2));
+    }
+
+    #[test]
+    fn restore_clamps_cursor_when_its_run_is_gone() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.runs = vec![make_run(1)];
+        state.rebuild_tree();
+
+        let session = SessionState {
+            filter: FilterMode::All,
+            sort_mode: SortMode::Recent,
+            text_filter: None,
+            expanded_runs: vec![9],
+            cursor_run_id: Some(9),
+        };
+        assert!(session.restore(&mut state).is_empty());
+        assert!(state.expanded_runs.is_empty());
+        assert_eq!(state.cursor, 0);
+        assert_eq!(state.current_run_id(), Some(1));
+    }
+
+    #[test]
+    fn capture_reflects_state() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.runs = vec![make_run(1), make_run(2)];
+        state.expanded_runs.extend([2, 1]);
+        state.filter = FilterMode::ActiveOnly;
+        state.rebuild_tree();
+        let session = SessionState::capture(&state);
+        assert_eq!(session.expanded_runs, vec![1, 2]);
+        assert_eq!(session.filter, FilterMode::ActiveOnly);
+    }
+}
@@ -3,9 +3,84 @@
 //! The library only sees `Arc<dyn CiExecutor>` / `Arc<dyn CiParser>` — concrete
 //! implementations live in each binary crate.
 
-use crate::app::{Job, WorkflowRun};
+use crate::app::{Job, RateLimit, WorkflowRun};
 use async_trait::async_trait;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// Default cap on simultaneous CLI subprocesses (`--max-concurrent`).
+pub const DEFAULT_MAX_CONCURRENT: usize = 4;
+/// Default for both `--fetch-timeout` and `--log-timeout`.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Per-command deadlines. Logs get their own: a big `--log-failed` on a slow
+/// link legitimately outlasts any sensible limit for a status poll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    /// Run/job lists, status checks and actions.
+    pub fetch: Duration,
+    /// Failed-log fetches.
+    pub log: Duration,
+}
+
+impl Timeouts {
+    pub fn from_secs(fetch: u64, log: u64) -> Self {
+        Self {
+            fetch: Duration::from_secs(fetch),
+            log: Duration::from_secs(log),
+        }
+    }
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self::from_secs(DEFAULT_TIMEOUT_SECS, DEFAULT_TIMEOUT_SECS)
+    }
+}
+
+/// Runs `fut` while holding one permit. Executors wrap each subprocess in this —
+/// never a whole multi-command flow, or nested calls could deadlock.
+pub async fn with_permit<T>(
+    permits: &Semaphore,
+    fut: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    let _permit = permits
+        .acquire()
+        .await
+        .map_err(|_| eyre!("Executor is shutting down"))?;
+    fut.await
+}
+
+/// Minimum growth between two progress reports, so a fast stream doesn't flood
+/// the event channel.
+pub const PROGRESS_STEP_BYTES: usize = 64 * 1024;
+
+/// Accumulates streamed chunk sizes and decides when a progress report is due.
+#[derive(Debug, Default)]
+pub struct ProgressThrottle {
+    total: usize,
+    reported: usize,
+}
+
+impl ProgressThrottle {
+    /// Adds `n` bytes; returns the new total when it has grown by at least
+    /// [`PROGRESS_STEP_BYTES`] since the last report.
+    pub fn add(&mut self, n: usize) -> Option<usize> {
+        self.total += n;
+        if self.total - self.reported >= PROGRESS_STEP_BYTES {
+            self.reported = self.total;
+            Some(self.total)
+        } else {
+            None
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
 
 /// Side-effecting operations against a CI platform's CLI tool.
 ///
@@ -18,22 +93,105 @@ pub trait CiExecutor: Send + Sync {
     async fn detect_repo(&self) -> Result<String>;
     async fn detect_branch(&self) -> Result<String>;
     /// Returns raw JSON passed to [`CiParser::parse_runs`]. `filter` is a workflow
-    /// name (GitHub) or pipeline source (GitLab).
-    async fn fetch_runs(&self, limit: usize, filter: Option<&str>) -> Result<String>;
+    /// name (GitHub) or pipeline source (GitLab); `branch` is applied server-side
+    /// so `limit` counts only that branch's runs.
+    async fn fetch_runs(
+        &self,
+        limit: usize,
+        filter: Option<&str>,
+        branch: Option<&str>,
+    ) -> Result<String>;
+    /// One run by id, as a one-element list for [`CiParser::parse_runs`];
+    /// what `--watch-run` polls instead of the list.
+    async fn fetch_run(&self, run_id: u64) -> Result<String>;
     /// Returns raw JSON passed to [`CiParser::parse_jobs`].
     async fn fetch_jobs(&self, run_id: u64) -> Result<String>;
     async fn cancel_run(&self, run_id: u64) -> Result<()>;
     async fn delete_run(&self, run_id: u64) -> Result<()>;
     async fn rerun_failed(&self, run_id: u64) -> Result<()>;
+    /// Approves a run blocked on a deployment review or contributor approval.
+    async fn approve(&self, _run_id: u64) -> Result<()> {
+        Err(eyre!("Approving runs is unsupported on this platform"))
+    }
+    /// Starts a new run of `workflow` on `branch` (GitHub `workflow_dispatch`).
+    async fn dispatch_workflow(&self, _workflow: &str, _branch: &str) -> Result<()> {
+        Err(eyre!("Workflow dispatch is unsupported on this platform"))
+    }
+    /// Login of the authenticated user, resolved once at startup for `M`.
+    async fn current_user(&self) -> Result<String> {
+        Err(eyre!(
+            "Looking up the current user is unsupported on this platform"
+        ))
+    }
+    /// Current API quota; checked every [`crate::app::RATE_LIMIT_CHECK_POLLS`] polls.
+    async fn rate_limit(&self) -> Result<RateLimit> {
+        Err(eyre!("Rate limit status is unsupported on this platform"))
+    }
+    /// Whether [`Self::rate_limit`] is implemented, so callers skip the probe
+    /// instead of spawning a request that can only fail.
+    fn supports_rate_limit(&self) -> bool {
+        false
+    }
     async fn fetch_failed_logs(&self, run_id: u64) -> Result<String>;
     /// Separate from `fetch_failed_logs` because GitLab can't filter logs server-side;
     /// it must fetch each job's trace individually by ID.
     async fn fetch_failed_logs_for_job(&self, run_id: u64, job_id: u64) -> Result<String>;
+    /// Failed logs for a run (`job_id: None`) or one job, calling `progress` with
+    /// the bytes received so far while output streams in. The default buffers
+    /// through the methods above and never reports.
+    async fn fetch_failed_logs_streaming(
+        &self,
+        run_id: u64,
+        job_id: Option<u64>,
+        _progress: &(dyn Fn(usize) + Send + Sync),
+    ) -> Result<String> {
+        match job_id {
+            Some(job_id) => self.fetch_failed_logs_for_job(run_id, job_id).await,
+            None => self.fetch_failed_logs(run_id).await,
+        }
+    }
     /// Sync (not async) because it only spawns a detached child process — no waiting.
     fn open_in_browser(&self, url: &str) -> Result<()>;
     async fn copy_to_clipboard(&self, text: &str) -> Result<()>;
 }
 
+/// Percent-encodes a query value (a branch name may hold `/`, `#`, `&`, ...);
+/// RFC 3986 unreserved characters pass through.
+pub fn query_escape(value: &str) -> String {
+    use std::fmt::Write as _;
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            out.push(char::from(byte));
+        } else {
+            let _ = write!(out, "%{byte:02X}");
+        }
+    }
+    out
+}
+
+/// First line of a log that came through a lossy UTF-8 decode, so the `�`s
+/// read as ours and not the job's.
+pub const INVALID_UTF8_NOTE: &str = "(log contained invalid UTF-8; bad bytes are shown as �)";
+
+/// The shared [`CiParser::process_log_output`]: keeps the last `max_lines`
+/// lines, returning whether any were cut. Executors decode output with
+/// `from_utf8_lossy`, which turns invalid bytes into U+FFFD; such a log gets
+/// [`INVALID_UTF8_NOTE`] on top.
+pub fn tail_log(raw: &str, max_lines: usize) -> (String, bool) {
+    let lines: Vec<&str> = raw.lines().collect();
+    let (text, truncated) = if lines.len() > max_lines {
+        (lines[lines.len() - max_lines..].join("\n"), true)
+    } else {
+        (raw.to_string(), false)
+    };
+    if text.contains(char::REPLACEMENT_CHARACTER) {
+        (format!("{INVALID_UTF8_NOTE}\n{text}"), truncated)
+    } else {
+        (text, truncated)
+    }
+}
+
 /// Deserializes platform-specific JSON into the shared data model.
 /// Sync — parsing is CPU-bound, no I/O.
 pub trait CiParser: Send + Sync {
@@ -43,3 +201,63 @@ pub trait CiParser: Send + Sync {
     /// relevant for debugging). Returns `(processed_text, was_truncated)`.
     fn process_log_output(&self, raw: &str, max_lines: usize) -> (String, bool);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn query_escape_keeps_unreserved_only() {
+        assert_eq!(query_escape("main"), "main");
+        assert_eq!(query_escape("feat/a-b_c.d~e"), "feat%2Fa-b_c.d~e");
+        assert_eq!(query_escape("fix#1&x=é"), "fix%231%26x%3D%C3%A9");
+    }
+
+    #[test]
+    fn progress_throttle_reports_each_step() {
+        let mut throttle = ProgressThrottle::default();
+        assert_eq!(throttle.add(1000), None);
+        assert_eq!(throttle.add(PROGRESS_STEP_BYTES - 1001), None);
+        assert_eq!(throttle.add(1), Some(PROGRESS_STEP_BYTES));
+        assert_eq!(throttle.add(10), None);
+        let big = throttle.add(3 * PROGRESS_STEP_BYTES);
+        assert_eq!(big, Some(4 * PROGRESS_STEP_BYTES + 10));
+        assert_eq!(throttle.total(), 4 * PROGRESS_STEP_BYTES + 10);
+    }
+
+    #[test]
+    fn with_permit_bounds_concurrency() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let permits = Arc::new(Semaphore::new(2));
+            let in_flight = Arc::new(AtomicUsize::new(0));
+            let peak = Arc::new(AtomicUsize::new(0));
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    let (permits, in_flight, peak) =
+                        (permits.clone(), in_flight.clone(), peak.clone());
+                    tokio::spawn(async move {
+                        with_permit(&permits, async {
+                            let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                            peak.fetch_max(now, Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(5)).await;
+                            in_flight.fetch_sub(1, Ordering::SeqCst);
+                            Ok(())
+                        })
+                        .await
+                    })
+                })
+                .collect();
+            for h in handles {
+                h.await.unwrap().unwrap();
+            }
+            assert_eq!(peak.load(Ordering::SeqCst), 2);
+        });
+    }
+}
@@ -10,11 +10,23 @@ pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
     let narrow = area.width < crate::app::NARROW_WIDTH_THRESHOLD;
 
     let hints: &[(&str, &str)] = if state.has_log_overlay() {
-        &[("j/k", "scroll"), ("y", "copy"), ("q", "close")]
+        &[
+            ("j/k", "scroll"),
+            ("n", "numbers"),
+            ("F", "follow"),
+            ("a", "full"),
+            ("y", "copy"),
+            ("E", "pager"),
+            ("q", "close"),
+        ]
     } else if state.has_detail_overlay() {
         &[("d/q", "close")]
     } else if state.has_confirm_overlay() {
         &[("y", "confirm"), ("n", "cancel")]
+    } else if state.has_input_overlay() {
+        &[("Enter", "submit"), ("Esc", "cancel")]
+    } else if state.has_workflow_picker() {
+        &[("↑↓", "select"), ("Enter", "apply"), ("Esc", "cancel")]
     } else if narrow {
         &[
             ("j/k", "nav"),
@@ -44,12 +56,25 @@ pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
         ]
     };
 
-    // Notification display
-    let line = if let Some(notif) = state.notifications.last() {
-        Line::from(vec![
-            Span::styled("★ ", Style::default().fg(Color::Yellow)),
-            Span::styled(&notif.message, Style::default().fg(Color::Yellow)),
-        ])
+    // Notification display: the latest run's, then the poll summary beside it
+    let summary = state.poll_summary.as_ref().map(|(s, _)| s.as_str());
+    let line = if state.notifications.last().is_some() || summary.is_some() {
+        let mut spans = Vec::new();
+        if let Some(notif) = state.notifications.last() {
+            spans.push(Span::styled("★ ", Style::default().fg(Color::Yellow)));
+            spans.push(Span::styled(
+                notif.message.as_str(),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        if let Some(summary) = summary {
+            let sep = if spans.is_empty() { "" } else { " · " };
+            spans.push(Span::styled(
+                format!("{sep}this poll: {summary}"),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        Line::from(spans)
     } else {
         let mut spans: Vec<Span> = Vec::new();
         for (i, (key, desc)) in hints.iter().enumerate() {
@@ -6,6 +6,21 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 use ratatui::Frame;
 
+/// Share of the poll interval already elapsed, while the countdown is what
+/// the header shows. Recomputed from `next_poll_in` each frame, so a manual
+/// refresh or an interval change simply re-fills from the new values.
+pub fn poll_progress(state: &AppState) -> Option<f64> {
+    let counting = !state.is_loading()
+        && state.rate_limit_retry_in().is_none()
+        && !state.paused
+        && state.next_poll_in > 0
+        && state.poll_interval > 0;
+    counting.then(|| {
+        let elapsed = state.poll_interval.saturating_sub(state.next_poll_in);
+        (elapsed as f64 / state.poll_interval as f64).min(1.0)
+    })
+}
+
 pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
     let mut spans = vec![
         Span::styled(
@@ -15,6 +30,14 @@ pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw("│ "),
+        Span::styled(
+            state
+                .config
+                .host
+                .as_ref()
+                .map_or_else(String::new, |host| format!("{host}/")),
+            Style::default().fg(Color::DarkGray),
+        ),
         Span::styled(
             &state.config.repo,
             Style::default()
@@ -36,6 +59,7 @@ pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
         crate::app::FilterMode::All => "",
         crate::app::FilterMode::ActiveOnly => " [active]",
         crate::app::FilterMode::CurrentBranch => " [branch]",
+        crate::app::FilterMode::FailuresOnly => " [failed]",
     };
     if !filter_text.is_empty() {
         spans.push(Span::styled(
@@ -43,14 +67,129 @@ pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
             Style::default().fg(Color::Magenta),
         ));
     }
+    if let Some(event) = &state.event_filter {
+        spans.push(Span::styled(
+            format!(" [event:{event}]"),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if let Some(actor) = &state.actor_filter {
+        spans.push(Span::styled(
+            format!(" [actor:{actor}]"),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
 
-    // Loading spinner or poll countdown
+    if !state.selected_runs.is_empty() {
+        spans.push(Span::styled(
+            format!(" [{} selected]", state.selected_runs.len()),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    if state.sort_mode != crate::app::SortMode::Recent {
+        spans.push(Span::styled(
+            format!(" [sort:{}]", state.sort_mode.label()),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    if state.reverse {
+        spans.push(Span::styled(
+            " [reversed]",
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    if state.hide_skipped {
+        spans.push(Span::styled(
+            " [no skipped]",
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    if state.group_by_branch {
+        spans.push(Span::styled(
+            " [by branch]",
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    if let Some(workflow) = &state.config.workflow_filter {
+        spans.push(Span::styled(
+            format!(" [wf:{workflow}]"),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if let Some(query) = &state.text_filter {
+        spans.push(Span::styled(
+            format!(" [/{query}]"),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+
+    if !state.desktop_notify {
+        spans.push(Span::styled(
+            " [muted]",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    if state.from_cache {
+        spans.push(Span::styled(
+            " [cached]",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    if let Some(quota) = &state.api_quota {
+        let (text, color) = if quota.is_low() {
+            let secs = quota
+                .reset
+                .signed_duration_since(chrono::Utc::now())
+                .num_seconds();
+            (
+                format!(
+                    " [api {}/{}, resets in {}]",
+                    quota.remaining,
+                    quota.limit,
+                    crate::app::format_duration(secs)
+                ),
+                Color::Yellow,
+            )
+        } else {
+            (
+                format!(" [api {}/{}]", quota.remaining, quota.limit),
+                Color::DarkGray,
+            )
+        };
+        spans.push(Span::styled(text, Style::default().fg(color)));
+    }
+
+    if let Some((text, stale)) = state.freshness(std::time::Instant::now()) {
+        let color = if stale {
+            Color::Yellow
+        } else {
+            Color::DarkGray
+        };
+        spans.push(Span::styled(format!(" {text}"), Style::default().fg(color)));
+    }
+
+    // Loading spinner, pause indicator, or poll countdown
     if state.is_loading() {
         spans.push(Span::raw(" "));
         spans.push(Span::styled(
             format!("{}", spinner::frame(state.spinner_frame)),
             Style::default().fg(Color::Yellow),
         ));
+    } else if let Some(secs) = state.rate_limit_retry_in() {
+        spans.push(Span::styled(
+            format!(" rate-limited, retrying in {secs}s"),
+            Style::default().fg(Color::Red),
+        ));
+    } else if state.paused {
+        spans.push(Span::styled(
+            " PAUSED",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
     } else if state.next_poll_in > 0 {
         // Append countdown timer
         let countdown = format!(" {}s", state.next_poll_in);
@@ -76,4 +215,68 @@ pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
     );
 
     f.render_widget(header, area);
+
+    // The bottom border doubles as a thin gauge filling toward the next poll
+    if let Some(ratio) = poll_progress(state) {
+        let filled = (f64::from(area.width) * ratio).round() as u16;
+        let y = area.bottom().saturating_sub(1);
+        for x in area.x..area.x + filled.min(area.width) {
+            f.buffer_mut()[(x, y)].set_fg(Color::Cyan);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::time::{Duration, Instant};
+
+    fn header_text(state: &AppState) -> (String, ratatui::buffer::Buffer) {
+        let mut terminal = Terminal::new(TestBackend::new(120, 2)).unwrap();
+        terminal.draw(|f| render(f, f.area(), state)).unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        let text = buffer.content()[..120]
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        (text, buffer)
+    }
+
+    #[test]
+    fn border_gauge_fills_with_the_countdown() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.poll_interval = 10;
+        state.next_poll_in = 4;
+        assert_eq!(poll_progress(&state), Some(0.6));
+        let (_, buffer) = header_text(&state);
+        let filled = (0..120)
+            .filter(|&x| buffer[(x, 1)].fg == Color::Cyan)
+            .count();
+        assert_eq!(filled, 72);
+
+        // Manual refresh resets the countdown to the full interval
+        state.next_poll_in = 10;
+        let (_, buffer) = header_text(&state);
+        assert!((0..120).all(|x| buffer[(x, 1)].fg != Color::Cyan));
+
+        state.paused = true;
+        assert_eq!(poll_progress(&state), None);
+    }
+
+    #[test]
+    fn shows_time_since_last_poll_and_flags_stale_data() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.poll_interval = 10;
+        state.last_poll = Instant::now().checked_sub(Duration::from_secs(4));
+        let (text, _) = header_text(&state);
+        assert!(text.contains("updated 4s ago"), "{text}");
+
+        state.last_poll = Instant::now().checked_sub(Duration::from_secs(45));
+        let (text, buffer) = header_text(&state);
+        let col = text.find("updated 45s ago").expect("indicator shown");
+        let x = u16::try_from(text[..col].chars().count()).unwrap();
+        assert_eq!(buffer[(x, 0)].fg, Color::Yellow);
+    }
 }
@@ -0,0 +1,53 @@
+use crate::app::InputOverlay;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+pub fn render(f: &mut Frame, overlay: &InputOverlay) {
+    let area = f.area();
+
+    let width = 50u16.min(area.width);
+    let height = 5u16.min(area.height);
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    let overlay_area = Rect::new(x, y, width, height);
+
+    f.render_widget(Clear, overlay_area);
+
+    let title = format!(" {} ", overlay.title);
+    let hints = Line::from(vec![
+        Span::styled(
+            "Enter",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" submit   ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            "Esc",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" cancel ", Style::default().fg(Color::DarkGray)),
+    ]);
+
+    let block = Block::default()
+        .title(title)
+        .title_bottom(hints.centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+
+    let line = Line::from(vec![
+        Span::styled(
+            format!(" {}: ", overlay.prompt),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled(&overlay.value, Style::default().fg(Color::White)),
+        Span::styled("█", Style::default().fg(Color::Yellow)),
+    ]);
+
+    let paragraph = Paragraph::new(vec![Line::from(""), line]).block(block);
+    f.render_widget(paragraph, overlay_area);
+}
@@ -1,21 +1,56 @@
 use crate::app::LogOverlay;
-use ratatui::layout::Rect;
+use ratatui::layout::{Margin, Rect};
 use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::widgets::{
+    Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+};
 use ratatui::Frame;
+use unicode_width::UnicodeWidthChar;
 
-pub fn render(f: &mut Frame, overlay: &LogOverlay) {
-    let area = f.area();
+/// Splits `line` into chunks at most `width` columns wide. Wrapping here rather
+/// than in `Paragraph` keeps continuation rows aligned past the gutter.
+fn wrap_columns(line: &str, width: usize) -> Vec<&str> {
+    if width == 0 || line.is_empty() {
+        return vec![line];
+    }
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut cols = 0;
+    for (i, c) in line.char_indices() {
+        let w = c.width().unwrap_or(0);
+        if cols + w > width && i > start {
+            chunks.push(&line[start..i]);
+            start = i;
+            cols = 0;
+        }
+        cols += w;
+    }
+    chunks.push(&line[start..]);
+    chunks
+}
 
-    // ~90% width, ~80% height, centered
+/// The overlay's box within `area`: ~90% width, ~80% height, centered.
+fn overlay_area(area: Rect) -> Rect {
     let width = (area.width * 9 / 10)
         .max(area.width.min(20))
         .min(area.width);
     let height = (area.height * 8 / 10).max(6).min(area.height);
     let x = area.x + (area.width.saturating_sub(width)) / 2;
     let y = area.y + (area.height.saturating_sub(height)) / 2;
-    let overlay_area = Rect::new(x, y, width, height);
+    Rect::new(x, y, width, height)
+}
+
+/// Log lines the overlay shows on a terminal `terminal_height` rows tall; the
+/// scroll handlers page by this so they match what `render` draws.
+pub fn visible_height(terminal_height: u16) -> usize {
+    let area = Rect::new(0, 0, 0, terminal_height);
+    overlay_area(area).height.saturating_sub(2) as usize
+}
+
+pub fn render(f: &mut Frame, overlay: &LogOverlay, line_numbers: bool) {
+    let overlay_area = overlay_area(f.area());
+    let (width, height) = (overlay_area.width, overlay_area.height);
 
     // Clear the area behind the overlay
     f.render_widget(Clear, overlay_area);
@@ -36,8 +71,28 @@ pub fn render(f: &mut Frame, overlay: &LogOverlay) {
         String::new()
     };
 
-    let title = format!(" {} {}", overlay.title, scroll_info);
-    let hints = " j/k scroll | y copy | q close ";
+    if let Some(bytes) = overlay.loading {
+        let block = Block::default()
+            .title(format!(" {} ", overlay.title))
+            .title_bottom(Line::from(" q close ").centered())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .style(Style::default().bg(Color::Black));
+        let text = Line::styled(
+            format!(" fetching… {}", crate::app::format_bytes(bytes)),
+            Style::default().fg(Color::DarkGray),
+        );
+        f.render_widget(Paragraph::new(text).block(block), overlay_area);
+        return;
+    }
+
+    let follow_tag = if overlay.following { "[follow] " } else { "" };
+    let full_tag = if overlay.full { "[full] " } else { "" };
+    let title = format!(
+        " {} {}{}{}",
+        overlay.title, follow_tag, full_tag, scroll_info
+    );
+    let hints = " j/k scroll | n numbers | F follow | a full | y copy | E pager | q close ";
 
     let block = Block::default()
         .title(title)
@@ -46,18 +101,161 @@ pub fn render(f: &mut Frame, overlay: &LogOverlay) {
         .border_style(Style::default().fg(Color::Yellow))
         .style(Style::default().bg(Color::Black));
 
-    // Build visible lines
-    let visible_lines: Vec<Line> = overlay
-        .lines
-        .iter()
-        .skip(overlay.scroll)
-        .take(inner_height)
-        .map(|l| Line::from(Span::raw(l.as_str())))
-        .collect();
+    // Gutter: widest original line number plus a separating space
+    let last_number = overlay.dropped_lines + total;
+    let gutter_width = if line_numbers {
+        last_number.to_string().len() + 1
+    } else {
+        0
+    };
+    let content_width = (width.saturating_sub(2) as usize).saturating_sub(gutter_width);
+
+    let mut visible_lines: Vec<Line> = Vec::with_capacity(inner_height);
+    'lines: for (i, l) in overlay.lines.iter().enumerate().skip(overlay.scroll) {
+        for (chunk_idx, chunk) in wrap_columns(l, content_width).into_iter().enumerate() {
+            if visible_lines.len() >= inner_height {
+                break 'lines;
+            }
+            let mut spans = Vec::with_capacity(2);
+            if line_numbers {
+                let number = if chunk_idx == 0 {
+                    format!(
+                        "{:>w$} ",
+                        overlay.dropped_lines + i + 1,
+                        w = gutter_width - 1
+                    )
+                } else {
+                    " ".repeat(gutter_width)
+                };
+                spans.push(Span::styled(number, Style::default().fg(Color::DarkGray)));
+            }
+            spans.push(Span::raw(chunk));
+            visible_lines.push(Line::from(spans));
+        }
+    }
+
+    f.render_widget(Paragraph::new(visible_lines).block(block), overlay_area);
+
+    if total > inner_height {
+        let mut scrollbar_state =
+            ScrollbarState::new(total.saturating_sub(inner_height)).position(overlay.scroll);
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None),
+            overlay_area.inner(Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut scrollbar_state,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn overlay(lines: usize, dropped_lines: usize) -> LogOverlay {
+        LogOverlay {
+            title: "log".to_string(),
+            lines: (1..=lines).map(|i| format!("line {i}")).collect(),
+            dropped_lines,
+            scroll: 0,
+            run_id: 1,
+            job_id: None,
+            following: false,
+            loading: None,
+            step: None,
+            full: false,
+        }
+    }
+
+    fn render_to_string(overlay: &LogOverlay, line_numbers: bool) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+        terminal.draw(|f| render(f, overlay, line_numbers)).unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| {
+                row.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn visible_height_matches_rendered_rows() {
+        let log = overlay(200, 0);
+        for height in 6..=60 {
+            let mut terminal = Terminal::new(TestBackend::new(80, height)).unwrap();
+            terminal.draw(|f| render(f, &log, false)).unwrap();
+            let buffer = terminal.backend().buffer();
+            let rendered = (0..height)
+                .filter(|&y| {
+                    (0..80)
+                        .map(|x| buffer[(x, y)].symbol())
+                        .collect::<String>()
+                        .contains("line ")
+                })
+                .count();
+            assert_eq!(rendered, visible_height(height), "height {height}");
+        }
+    }
+
+    #[test]
+    fn wrap_columns_splits_at_width() {
+        assert_eq!(wrap_columns("abcdef", 4), vec!["abcd", "ef"]);
+        assert_eq!(wrap_columns("abc", 4), vec!["abc"]);
+        assert_eq!(wrap_columns("", 4), vec![""]);
+    }
+
+    #[test]
+    fn wrap_columns_counts_wide_chars() {
+        assert_eq!(wrap_columns("日本語", 4), vec!["日本", "語"]);
+    }
+
+    #[test]
+    fn odd_code_points_render_without_panicking() {
+        let mut odd = overlay(0, 0);
+        odd.lines = vec![
+            "bad \u{FFFD}\u{FFFD} bytes".to_string(),
+            "ctrl \u{0}\u{7}\u{1b}[31m\r end".to_string(),
+            "e\u{301}\u{200d}\u{feff} zero-width".to_string(),
+            "日本語".repeat(20),
+            "\u{10FFFF}\u{E000}".to_string(),
+        ];
+        for line_numbers in [true, false] {
+            let text = render_to_string(&odd, line_numbers);
+            assert!(text.contains('\u{FFFD}'), "{text}");
+        }
+        // Narrower than a wide char: the chunk still advances
+        assert_eq!(wrap_columns("日本", 1), vec!["日", "本"]);
+    }
+
+    #[test]
+    fn loading_overlay_shows_bytes_fetched() {
+        let mut loading = overlay(0, 0);
+        loading.loading = Some(120 * 1024);
+        let text = render_to_string(&loading, true);
+        assert!(text.contains("fetching… 120 KB"), "{text}");
+    }
 
-    let paragraph = Paragraph::new(visible_lines)
-        .block(block)
-        .wrap(Wrap { trim: false });
+    #[test]
+    fn gutter_shows_original_line_numbers() {
+        let text = render_to_string(&overlay(20, 100), true);
+        assert!(text.contains("101 line 1"), "{text}");
+    }
 
-    f.render_widget(paragraph, overlay_area);
+    #[test]
+    fn gutter_hidden_when_toggled_off() {
+        let text = render_to_string(&overlay(20, 100), false);
+        assert!(!text.contains("101 "), "{text}");
+        assert!(text.contains("line 1"), "{text}");
+    }
 }
@@ -2,8 +2,11 @@ pub mod confirm_overlay;
 pub mod detail_overlay;
 pub mod footer;
 pub mod header;
+pub mod input_overlay;
 pub mod log_overlay;
+pub mod pager;
 pub mod render;
 pub mod spinner;
 pub mod startup;
 pub mod tree;
+pub mod workflow_picker;
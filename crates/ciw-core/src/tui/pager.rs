@@ -0,0 +1,143 @@
+//! Hand the log overlay to `$PAGER` with the TUI suspended.
+//!
+//! The caller must also suspend the input thread (`EventHandler::suspend`), or it
+//! keeps reading keys meant for the pager.
+
+use color_eyre::eyre::Result;
+use crossterm::execute;
+use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const DEFAULT_PAGER: &str = "less -R";
+
+/// `$PAGER` split on whitespace; unset or blank falls back to `less -R`.
+pub fn pager_command(pager: Option<&str>) -> (String, Vec<String>) {
+    let cmd = pager
+        .filter(|p| !p.trim().is_empty())
+        .unwrap_or(DEFAULT_PAGER);
+    let mut parts = cmd.split_whitespace().map(str::to_string);
+    let program = parts.next().unwrap_or_default();
+    (program, parts.collect())
+}
+
+/// Logs routinely carry secrets, so the file is created fresh (never through a
+/// pre-planted name or symlink) and readable only by the owner.
+fn create_private(dir: &Path, text: &str) -> io::Result<PathBuf> {
+    let pid = std::process::id();
+    for n in 0..100 {
+        let path = dir.join(format!("ciw-log-{pid}-{n}.txt"));
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = match options.open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        };
+        file.write_all(text.as_bytes())?;
+        return Ok(path);
+    }
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "no free temp file name for the log",
+    ))
+}
+
+/// Writes `text` to a private temp file and shows it in the pager. Returns the
+/// file path when the pager couldn't be run, so the user can open it
+/// themselves; otherwise the file is removed. The terminal is restored before
+/// returning, whatever failed on the way.
+pub fn open(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    text: &str,
+) -> Result<Option<PathBuf>> {
+    let path = create_private(&std::env::temp_dir(), text)?;
+    let (program, args) = pager_command(std::env::var("PAGER").ok().as_deref());
+
+    let suspended =
+        terminal::disable_raw_mode().and_then(|()| execute!(io::stdout(), LeaveAlternateScreen));
+    let status = suspended.as_ref().ok().map(|()| {
+        std::process::Command::new(&program)
+            .args(&args)
+            .arg(&path)
+            .status()
+    });
+    // Every step runs even if an earlier one failed; the first error wins.
+    let restored = [
+        terminal::enable_raw_mode(),
+        execute!(io::stdout(), EnterAlternateScreen),
+        terminal.clear(),
+    ]
+    .into_iter()
+    .collect::<io::Result<Vec<()>>>();
+
+    let keep = match status {
+        Some(Ok(s)) if s.success() => false,
+        Some(Ok(s)) => {
+            tracing::warn!("pager {program} exited with {s}");
+            true
+        }
+        Some(Err(e)) => {
+            tracing::warn!("pager {program} failed to start: {e}");
+            true
+        }
+        None => false,
+    };
+    let outcome = suspended.and(restored);
+    // Kept only when its path is about to be shown to the user.
+    if outcome.is_err() || !keep {
+        if let Err(e) = std::fs::remove_file(&path) {
+            tracing::warn!("pager: failed to remove {}: {e}", path.display());
+        }
+    }
+    outcome?;
+    Ok(keep.then_some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pager_defaults_to_less() {
+        assert_eq!(
+            pager_command(None),
+            ("less".to_string(), vec!["-R".to_string()])
+        );
+        assert_eq!(pager_command(Some("  ")).0, "less");
+    }
+
+    #[test]
+    fn log_file_is_private_and_never_reuses_a_name() {
+        let dir = std::env::temp_dir().join(format!("ciw-pager-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let planted = dir.join(format!("ciw-log-{}-0.txt", std::process::id()));
+        std::fs::write(&planted, "planted").unwrap();
+
+        let path = create_private(&dir, "secret log").unwrap();
+        assert_ne!(path, planted);
+        assert_eq!(std::fs::read_to_string(&planted).unwrap(), "planted");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "secret log");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn pager_splits_arguments() {
+        assert_eq!(
+            pager_command(Some("bat --paging=always")),
+            ("bat".to_string(), vec!["--paging=always".to_string()])
+        );
+        assert_eq!(pager_command(Some("more")), ("more".to_string(), vec![]));
+    }
+}
@@ -2,8 +2,22 @@ use crate::app::AppState;
 use crate::tui::{footer, header, tree};
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::Frame;
+use unicode_width::UnicodeWidthStr;
+
+/// Tallest the error banner grows before the rest of the message is cut.
+const ERROR_MAX_LINES: u16 = 4;
+/// Below this the layout math (header, footer, borders) leaves nothing for
+/// the tree, so only a notice is drawn until the terminal grows again.
+const MIN_WIDTH: u16 = 20;
+const MIN_HEIGHT: u16 = 6;
 
 pub fn render(f: &mut Frame, state: &AppState) {
+    let area = f.area();
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        render_too_small(f);
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -24,11 +38,17 @@ pub fn render(f: &mut Frame, state: &AppState) {
             use ratatui::layout::Rect;
             use ratatui::style::{Color, Style};
             use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+            let width = area.width.saturating_sub(2);
+            // Grows with the message but stays between header and footer
+            let lines = wrapped_line_count(err, usize::from(width.saturating_sub(2)))
+                .min(ERROR_MAX_LINES)
+                .min(area.height - 6);
+            let height = lines + 2;
             let err_area = Rect {
                 x: area.x + 1,
-                y: area.y + area.height.saturating_sub(5),
-                width: area.width.saturating_sub(2),
-                height: 3,
+                y: area.y + area.height.saturating_sub(2 + height),
+                width,
+                height,
             };
             let err_widget = Paragraph::new(err.to_owned())
                 .style(Style::default().fg(Color::Red))
@@ -46,7 +66,7 @@ pub fn render(f: &mut Frame, state: &AppState) {
     // Overlay (drawn on top of everything)
     match &state.overlay {
         crate::app::ActiveOverlay::Log(overlay) => {
-            crate::tui::log_overlay::render(f, overlay);
+            crate::tui::log_overlay::render(f, overlay, state.log_line_numbers);
         }
         crate::app::ActiveOverlay::Detail(overlay) => {
             crate::tui::detail_overlay::render(f, overlay);
@@ -54,6 +74,203 @@ pub fn render(f: &mut Frame, state: &AppState) {
         crate::app::ActiveOverlay::Confirm(ref overlay) => {
             crate::tui::confirm_overlay::render(f, overlay);
         }
+        crate::app::ActiveOverlay::Input(ref overlay) => {
+            crate::tui::input_overlay::render(f, overlay);
+        }
+        crate::app::ActiveOverlay::WorkflowPicker(ref picker) => {
+            crate::tui::workflow_picker::render(f, picker);
+        }
         crate::app::ActiveOverlay::None => {}
     }
+
+    if !state.config.color {
+        strip_styles(f.buffer_mut());
+    }
+
+    // `--flash`: invert everything already drawn, overlays included
+    if state.is_flashing(std::time::Instant::now()) {
+        use ratatui::style::{Modifier, Style};
+        let area = f.area();
+        f.buffer_mut()
+            .set_style(area, Style::default().add_modifier(Modifier::REVERSED));
+    }
+}
+
+fn render_too_small(f: &mut Frame) {
+    use ratatui::layout::Alignment;
+    use ratatui::widgets::{Paragraph, Wrap};
+    let area = f.area();
+    let notice = format!("Terminal too small (need {MIN_WIDTH}×{MIN_HEIGHT})");
+    // Vertically centred once it has wrapped
+    let lines = wrapped_line_count(&notice, usize::from(area.width)).min(area.height);
+    let mut notice_area = area;
+    notice_area.y += (area.height - lines) / 2;
+    notice_area.height = lines;
+    f.render_widget(
+        Paragraph::new(notice)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        notice_area,
+    );
+}
+
+/// Rows `text` takes when word-wrapped to `width`, like `Wrap { trim: true }`.
+fn wrapped_line_count(text: &str, width: usize) -> u16 {
+    let width = width.max(1);
+    let mut rows = 0usize;
+    for line in text.lines() {
+        let mut current = 0usize;
+        rows += 1;
+        for word in line.split_whitespace() {
+            let w = UnicodeWidthStr::width(word);
+            let needed = if current == 0 { w } else { current + 1 + w };
+            if needed <= width {
+                current = needed;
+                continue;
+            }
+            // Too long for what's left: starts a new row, or several when the
+            // word alone is wider than one
+            if current > 0 {
+                rows += 1;
+            }
+            rows += (w - 1) / width;
+            current = match w % width {
+                0 => width,
+                rest => rest,
+            };
+        }
+    }
+    u16::try_from(rows.max(1)).unwrap_or(u16::MAX)
+}
+
+/// `--no-color`: one pass over the finished frame instead of a check in every
+/// widget. Keeps `REVERSED` so the cursor row stays visible.
+fn strip_styles(buf: &mut ratatui::buffer::Buffer) {
+    use ratatui::style::{Color, Modifier};
+    for cell in &mut buf.content {
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+        cell.modifier &= Modifier::REVERSED;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{Conclusion, RunStatus, WorkflowRun};
+    use ratatui::backend::TestBackend;
+    use ratatui::style::{Color, Modifier};
+    use ratatui::Terminal;
+
+    fn run(id: u64, conclusion: Conclusion) -> WorkflowRun {
+        WorkflowRun {
+            url: String::new(),
+            head_sha: "abc1234def".to_string(),
+            ..crate::mock::run(id, RunStatus::Completed, Some(conclusion))
+        }
+    }
+
+    fn draw(color: bool) -> ratatui::buffer::Buffer {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.runs = vec![run(1, Conclusion::Success), run(2, Conclusion::Failure)];
+        state.config.color = color;
+        state.rebuild_tree();
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        terminal.draw(|f| render(f, &state)).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    fn rows(buffer: &ratatui::buffer::Buffer) -> Vec<String> {
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(ratatui::buffer::Cell::symbol).collect())
+            .collect()
+    }
+
+    #[test]
+    fn wrapped_line_count_breaks_on_words() {
+        assert_eq!(wrapped_line_count("short", 20), 1);
+        assert_eq!(wrapped_line_count("aaaa bbbb cccc", 9), 2);
+        assert_eq!(wrapped_line_count("one\ntwo", 20), 2);
+        assert_eq!(wrapped_line_count(&"x".repeat(25), 10), 3);
+    }
+
+    #[test]
+    fn long_error_wraps_over_several_banner_lines() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.set_error(format!(
+            "HTTP 403: {} - rate limit exceeded",
+            "blocked ".repeat(12)
+        ));
+        let mut terminal = Terminal::new(TestBackend::new(40, 20)).unwrap();
+        terminal.draw(|f| render(f, &state)).unwrap();
+        let rows = rows(terminal.backend().buffer());
+        let banner: Vec<&String> = rows.iter().filter(|r| r.contains("blocked")).collect();
+        assert!(banner.len() > 1, "{rows:#?}");
+        assert!(
+            rows.iter().any(|r| r.contains("exceeded")),
+            "end of message kept"
+        );
+    }
+
+    #[test]
+    fn error_banner_is_capped_above_the_footer() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.set_error("word ".repeat(200));
+        let mut terminal = Terminal::new(TestBackend::new(30, 8)).unwrap();
+        terminal.draw(|f| render(f, &state)).unwrap();
+        let rows = rows(terminal.backend().buffer());
+        // 8 rows: header 2, banner at most 4 (2 lines), footer 2
+        let banner = rows.iter().filter(|r| r.contains("word")).count();
+        assert_eq!(banner, 2, "{rows:#?}");
+        assert!(!rows[6].contains("word") && !rows[7].contains("word"));
+    }
+
+    #[test]
+    fn tiny_terminal_shows_notice_instead_of_layout() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.runs = vec![run(1, Conclusion::Failure)];
+        state.rebuild_tree();
+        state.set_error("boom".to_string());
+        state.open_detail_overlay("Details".to_string(), vec![]);
+
+        let mut empty = Terminal::new(TestBackend::new(0, 0)).unwrap();
+        empty.draw(|f| render(f, &state)).unwrap();
+        for (w, h) in [(20, 5), (19, 6), (12, 3), (1, 1)] {
+            let mut terminal = Terminal::new(TestBackend::new(w, h)).unwrap();
+            terminal.draw(|f| render(f, &state)).unwrap();
+            let text = rows(terminal.backend().buffer()).concat();
+            assert!(!text.contains("o/r"), "{w}x{h}: {text}");
+            if w >= 12 && h >= 2 {
+                assert!(text.contains("small"), "{w}x{h}: {text}");
+            }
+        }
+
+        // Resizing back restores the normal layout
+        let mut terminal = Terminal::new(TestBackend::new(20, 6)).unwrap();
+        terminal.draw(|f| render(f, &state)).unwrap();
+        let text = rows(terminal.backend().buffer()).concat();
+        assert!(!text.contains("too small"), "{text}");
+    }
+
+    #[test]
+    fn no_color_renders_unstyled_except_cursor() {
+        let colored = draw(true);
+        assert!(colored.content.iter().any(|c| c.fg == Color::Green));
+
+        let plain = draw(false);
+        assert!(plain
+            .content
+            .iter()
+            .all(|c| c.fg == Color::Reset && c.bg == Color::Reset));
+        assert!(plain
+            .content
+            .iter()
+            .all(|c| (c.modifier - Modifier::REVERSED).is_empty()));
+        assert!(plain
+            .content
+            .iter()
+            .any(|c| c.modifier.contains(Modifier::REVERSED)));
+    }
 }
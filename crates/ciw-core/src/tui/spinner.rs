@@ -5,6 +5,8 @@ pub fn frame(idx: usize) -> char {
 }
 
 #[cfg(test)]
+// Positional format args predate the lint; left as written.
+#[allow(clippy::uninlined_format_args)]
 mod tests {
     use super::*;
 
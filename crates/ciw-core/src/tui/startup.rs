@@ -58,7 +58,19 @@ struct StartupPhase {
 pub struct StartupResult {
     pub repo: String,
     pub branch: Option<String>,
+    /// Detection ran but found no branch (detached HEAD); worth telling the
+    /// user why the branch filter is unavailable.
+    pub branch_undetected: bool,
     pub runs: Vec<WorkflowRun>,
+    /// `runs` came from the `--cache` file rather than a live fetch.
+    pub from_cache: bool,
+}
+
+/// `git rev-parse --abbrev-ref HEAD` prints `HEAD` when detached, and some
+/// setups print nothing; neither is a branch to filter on.
+pub fn normalize_branch(raw: &str) -> Option<String> {
+    let branch = raw.trim();
+    (!branch.is_empty() && branch != "HEAD").then(|| branch.to_string())
 }
 
 fn render_startup<B: Backend>(
@@ -177,6 +189,7 @@ pub async fn run_startup<B: Backend>(
     limit: usize,
     filter: Option<&str>,
     validate_repo: Option<fn(&str) -> Result<(), String>>,
+    cache_dir: Option<&std::path::Path>,
 ) -> Result<StartupResult> {
     let mut phases: Vec<StartupPhase> = Vec::new();
     let art = platform.ascii_art;
@@ -222,6 +235,7 @@ pub async fn run_startup<B: Backend>(
     }
 
     // Phase 3: Detect branch (non-fatal)
+    let mut branch_undetected = false;
     let branch = if let Some(b) = branch_arg {
         let branch = b.to_string();
         phases.push(StartupPhase {
@@ -240,11 +254,13 @@ pub async fn run_startup<B: Backend>(
             executor.detect_branch(),
         )
         .await;
-        if let Ok(b) = result {
+        if let Ok(raw) = result {
+            let branch = normalize_branch(&raw);
             let idx = phases.len() - 1;
-            phases[idx].detail = Some(b.clone());
+            phases[idx].detail = Some(branch.clone().unwrap_or_else(|| "(detached HEAD)".into()));
+            branch_undetected = branch.is_none();
             render_startup(terminal, art, &phases, 0);
-            Some(b)
+            branch
         } else {
             // Non-fatal: mark as done but indicate it was skipped
             let idx = phases.len() - 1;
@@ -255,13 +271,36 @@ pub async fn run_startup<B: Backend>(
         }
     };
 
-    // Phase 4: Fetch workflow runs
+    // Phase 4: Cached runs skip the blocking fetch — the poller's first fetch
+    // replaces them moments later. A missing or unreadable cache falls through.
+    if let Some(dir) = cache_dir {
+        match crate::cache::load(&crate::cache::cache_file(dir, &repo)) {
+            Ok(runs) => {
+                phases.push(StartupPhase {
+                    label: format!("Loading cached {} runs", platform.name),
+                    detail: Some(format!("{} runs", runs.len())),
+                    status: PhaseStatus::Done,
+                });
+                render_startup(terminal, art, &phases, 0);
+                return Ok(StartupResult {
+                    repo,
+                    branch,
+                    branch_undetected,
+                    runs,
+                    from_cache: true,
+                });
+            }
+            Err(e) => tracing::debug!("cache: {e}"),
+        }
+    }
+
+    // Phase 5: Fetch workflow runs
     let json = run_phase(
         terminal,
         art,
         &mut phases,
         &format!("Fetching {} runs", platform.name),
-        executor.fetch_runs(limit, filter),
+        executor.fetch_runs(limit, filter, None),
     )
     .await?;
 
@@ -270,5 +309,28 @@ pub async fn run_startup<B: Backend>(
     phases[idx].detail = Some(format!("{} runs", runs.len()));
     render_startup(terminal, art, &phases, 0);
 
-    Ok(StartupResult { repo, branch, runs })
+    Ok(StartupResult {
+        repo,
+        branch,
+        branch_undetected,
+        runs,
+        from_cache: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_branch_trims_and_rejects_detached() {
+        assert_eq!(normalize_branch("main\n").as_deref(), Some("main"));
+        assert_eq!(
+            normalize_branch("  feature/x ").as_deref(),
+            Some("feature/x")
+        );
+        assert_eq!(normalize_branch(""), None);
+        assert_eq!(normalize_branch("  \n\t"), None);
+        assert_eq!(normalize_branch("HEAD\n"), None);
+    }
 }
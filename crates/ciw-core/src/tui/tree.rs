@@ -16,11 +16,20 @@ pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
     let inner_width = area.width.saturating_sub(2) as usize;
 
     if state.tree_items.is_empty() && !state.is_loading() {
-        let msg = match state.filter {
+        let base = match state.filter {
             crate::app::FilterMode::ActiveOnly => "No active runs",
             crate::app::FilterMode::CurrentBranch => "No runs for current branch",
+            crate::app::FilterMode::FailuresOnly => "No failed runs",
             crate::app::FilterMode::All => "No workflow runs found",
         };
+        let msg = match &state.event_filter {
+            Some(event) => format!("{base} for event '{event}'"),
+            None => base.to_string(),
+        };
+        let msg = match &state.text_filter {
+            Some(query) => format!("{msg} matching '{query}'"),
+            None => msg,
+        };
         let para = Paragraph::new(msg)
             .style(Style::default().fg(Color::DarkGray))
             .block(Block::default().borders(Borders::NONE));
@@ -52,6 +61,7 @@ pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
     }
 
     let mut lines: Vec<Line> = Vec::new();
+    let mut tinted_rows: Vec<(u16, Color)> = Vec::new();
 
     for (i, item) in state
         .tree_items
@@ -68,36 +78,125 @@ pub fn render(f: &mut Frame, area: Rect, state: &AppState) {
                 .is_some_and(|r| r.database_id == n.run_id)
         });
 
-        let line = if item.level == TreeLevel::Loading {
-            render_loading_line(state.spinner_frame, is_selected)
+        let fetch_error = state
+            .runs
+            .get(item.run_idx)
+            .and_then(|r| state.run_errors.get(&r.database_id));
+        // Under `B` every other row hangs off a branch header
+        let nested = state.group_by_branch && item.level != TreeLevel::Branch;
+        let inner_width = if nested {
+            inner_width.saturating_sub(BRANCH_INDENT.len())
+        } else {
+            inner_width
+        };
+        let mut line = if item.level == TreeLevel::Loading {
+            match fetch_error {
+                Some(err) => render_load_error_line(err, is_selected, inner_width),
+                None => render_loading_line(state.spinner_frame, is_selected),
+            }
         } else {
             match state.resolve_item(item) {
                 Some(ResolvedItem::Run(run)) => {
                     let vis_idx = run_visual_idx.get(&item.run_idx).copied().unwrap_or(0);
                     let has_run_error = state.run_errors.contains_key(&run.database_id);
+                    if state.config.tint_rows && !is_selected {
+                        if let Some(tint) = row_tint(run) {
+                            tinted_rows.push((lines.len() as u16, tint));
+                        }
+                    }
                     render_run_line(
                         run,
                         vis_idx,
                         is_selected,
+                        state.selected_runs.contains(&run.database_id),
                         has_notification,
                         has_run_error,
                         narrow,
+                        state.compact,
                         inner_width,
                         item.expanded,
+                        AppState::run_progress(run),
+                        state.superseded.contains_key(&run.database_id),
+                        AppState::first_failure_label(run),
+                        job_failure_badge(run),
                     )
                 }
-                Some(ResolvedItem::Job(job)) => {
-                    render_job_line(job, is_selected, narrow, inner_width, item.expanded)
+                Some(ResolvedItem::Branch { name, runs }) => {
+                    render_branch_line(name, &runs, is_selected, inner_width, item.expanded)
+                }
+                Some(ResolvedItem::Group { name, jobs }) => {
+                    render_group_line(name, &jobs, is_selected, inner_width, item.expanded)
+                }
+                Some(ResolvedItem::Job(job)) => render_job_line(
+                    job,
+                    is_selected,
+                    narrow,
+                    inner_width,
+                    item.expanded,
+                    item.grouped,
+                ),
+                Some(ResolvedItem::Step(step)) => {
+                    render_step_line(step, is_selected, inner_width, item.grouped)
                 }
-                Some(ResolvedItem::Step(step)) => render_step_line(step, is_selected, inner_width),
                 None => Line::raw(""),
             }
         };
+        if nested {
+            line.spans.insert(0, Span::raw(BRANCH_INDENT));
+        }
         lines.push(line);
     }
 
     let tree = Paragraph::new(lines).block(Block::default().borders(Borders::NONE));
     f.render_widget(tree, area);
+    // Across the whole row, not just under the text; only the background, so
+    // every span keeps its own colors
+    for (row, tint) in tinted_rows {
+        let rect = Rect::new(area.x, area.y + row, area.width, 1);
+        f.buffer_mut().set_style(rect, Style::default().bg(tint));
+    }
+
+    // Overflow markers, drawn over the right end of the first/last visible row
+    let (above, below) = hidden_counts(state.tree_items.len(), scroll_offset, visible_height);
+    let marker_style = Style::default().fg(Color::DarkGray);
+    if above > 0 {
+        let marker = Paragraph::new(Line::from(format!(" ↑{above} more")).right_aligned())
+            .style(marker_style);
+        f.render_widget(marker, Rect::new(area.x, area.y, area.width, 1));
+    }
+    if below > 0 && visible_height > 1 {
+        let marker = Paragraph::new(Line::from(format!(" ↓{below} more")).right_aligned())
+            .style(marker_style);
+        f.render_widget(
+            marker,
+            Rect::new(area.x, area.y + area.height - 1, area.width, 1),
+        );
+    }
+}
+
+/// Items scrolled off above and below the visible window.
+fn hidden_counts(total: usize, scroll_offset: usize, visible_height: usize) -> (usize, usize) {
+    let below = total.saturating_sub(scroll_offset + visible_height);
+    (scroll_offset.min(total), below)
+}
+
+/// Run rows (and their jobs and steps) sit this far right of their branch header.
+const BRANCH_INDENT: &str = "  ";
+
+const SUPERSEDED_ICON: (&str, Color) = ("»", Color::DarkGray);
+
+/// `--tint-rows` background for a finished run: dark palette shades, faint
+/// enough that default-colored text stays readable. `--no-color` strips them
+/// with every other style.
+fn row_tint(run: &crate::app::WorkflowRun) -> Option<Color> {
+    if run.status != RunStatus::Completed {
+        return None;
+    }
+    match run.conclusion? {
+        Conclusion::Success => Some(Color::Indexed(22)),
+        c if c.is_failure() => Some(Color::Indexed(52)),
+        _ => None,
+    }
 }
 
 fn status_icon(status: RunStatus, conclusion: Option<Conclusion>) -> (&'static str, Color) {
@@ -107,10 +206,15 @@ fn status_icon(status: RunStatus, conclusion: Option<Conclusion>) -> (&'static s
             RunStatus::Completed,
             Some(Conclusion::Failure | Conclusion::TimedOut | Conclusion::StartupFailure),
         ) => ("✗", Color::Red),
-        (RunStatus::Completed, Some(Conclusion::ActionRequired)) => ("!", Color::Yellow),
+        (_, Some(Conclusion::ActionRequired)) => ("!", Color::Magenta),
+        // Blocked on something outside the queue (approval, a resource group)
+        (RunStatus::Waiting, _) => ("⧗", Color::Magenta),
         (RunStatus::Completed, Some(Conclusion::Cancelled)) => ("⊘", Color::Yellow),
         (RunStatus::Completed, Some(Conclusion::Skipped)) => ("⊘", Color::DarkGray),
         (RunStatus::InProgress, _) => ("⟳", Color::Yellow),
+        // Not started yet; distinct from ⟳ so idle runners stand out
+        (RunStatus::Queued | RunStatus::Requested | RunStatus::Pending, _) => ("○", Color::Blue),
+        (RunStatus::Unknown, _) => ("?", Color::Cyan),
         (_, _) => ("·", Color::DarkGray),
     }
 }
@@ -123,6 +227,15 @@ fn select_style(is_selected: bool) -> Style {
     }
 }
 
+/// Skipped/neutral jobs and steps recede so the ones that ran stand out.
+fn dim_if_skipped(style: Style, conclusion: Option<Conclusion>) -> Style {
+    if conclusion.is_some_and(Conclusion::is_skipped) {
+        style.fg(Color::DarkGray)
+    } else {
+        style
+    }
+}
+
 #[cfg(test)]
 fn format_duration(secs: i64) -> String {
     app::format_duration(secs)
@@ -132,51 +245,101 @@ fn truncate(s: &str, max_width: usize) -> String {
     app::truncate(s, max_width)
 }
 
+/// Long step names would otherwise squeeze the title down to nothing.
+const FAILURE_NAME_MAX: usize = 24;
+
 fn render_run_line(
     run: &crate::app::WorkflowRun,
     visual_idx: usize,
     is_selected: bool,
+    is_checked: bool,
     has_notification: bool,
     has_run_error: bool,
     narrow: bool,
+    compact: bool,
     max_width: usize,
     expanded: bool,
+    progress: Option<(usize, usize)>,
+    superseded: bool,
+    failure: Option<String>,
+    badge: Option<String>,
 ) -> Line<'static> {
-    let (icon, icon_color) = status_icon(run.status, run.conclusion);
+    // Cancelled by a newer run, not by anyone: nothing to chase
+    let (icon, icon_color) = if superseded {
+        SUPERSEDED_ICON
+    } else {
+        status_icon(run.status, run.conclusion)
+    };
     let arrow = if expanded { "▼" } else { "▶" };
 
-    let number = format!("#{}", run.number);
-    let end = if run.status == RunStatus::Completed {
-        Some(run.updated_at)
+    // Compact keeps only status and title; narrow only drops the suffixes
+    let number = if compact {
+        String::new()
+    } else {
+        format!("#{} ", run.number)
+    };
+    // Reruns only; first attempts stay uncluttered
+    let attempt = if run.attempt > 1 && !compact {
+        format!("↻{} ", run.attempt)
     } else {
-        None
+        String::new()
     };
-    let duration = app::compute_duration(Some(run.created_at), end);
+    let duration = run.duration_label();
+    // Finished/total jobs; short enough to keep on narrow terminals
+    let progress = match progress {
+        Some((done, total)) if !compact => format!(" {done}/{total}"),
+        _ => String::new(),
+    };
+    let badge = badge.filter(|_| !compact).unwrap_or_default();
+    let hide_suffix = narrow || compact;
+    // Which step broke, without expanding the run. Budgeted before the title so
+    // the branch/SHA/actor tail is what gets clipped, not this.
+    let failure = failure
+        .filter(|_| !hide_suffix)
+        .map(|name| format!(" — failed: {}", truncate(&name, FAILURE_NAME_MAX)))
+        .unwrap_or_default();
 
     let icon_display_width = UnicodeWidthStr::width(icon);
     let arrow_display_width = UnicodeWidthStr::width(arrow);
-    let prefix_width = 1 + arrow_display_width + 1 + icon_display_width + 1 + number.len() + 1;
-    let suffix_width = if narrow { 0 } else { duration.len() + 1 };
+    let prefix_width = 1
+        + arrow_display_width
+        + 1
+        + icon_display_width
+        + 1
+        + number.len()
+        + UnicodeWidthStr::width(attempt.as_str());
+    let suffix_width = if hide_suffix { 0 } else { duration.len() + 1 };
     let error_width = if has_run_error { 2 } else { 0 }; // "⚠ "
-    let title_max = max_width.saturating_sub(prefix_width + suffix_width + error_width + 2);
+    let check_width = if is_checked { 4 } else { 0 }; // "[x] "
+    let title_max = max_width.saturating_sub(
+        prefix_width
+            + suffix_width
+            + error_width
+            + check_width
+            + progress.len()
+            + UnicodeWidthStr::width(badge.as_str())
+            + UnicodeWidthStr::width(failure.as_str())
+            + 2,
+    );
     let title = truncate(&run.display_title, title_max);
 
     let sel_style = select_style(is_selected);
 
     let notif_marker = if has_notification { "★ " } else { "" };
-    let idx_label = if visual_idx < crate::app::QUICK_SELECT_MAX {
-        format!("{}", visual_idx + 1)
-    } else {
-        " ".to_string()
-    };
+    let idx_label = crate::app::quick_select_label(visual_idx).unwrap_or(' ');
 
     let mut spans = vec![
         Span::styled(
             format!("{idx_label}{arrow} {icon} "),
             Style::default().fg(icon_color),
         ),
-        Span::styled(format!("{number} "), Style::default().fg(Color::DarkGray)),
+        Span::styled(number, Style::default().fg(Color::DarkGray)),
+        Span::styled(attempt, Style::default().fg(Color::Yellow)),
         Span::styled(notif_marker.to_string(), Style::default().fg(Color::Yellow)),
+        Span::styled(
+            if is_checked { "[x] " } else { "" },
+            Style::default().fg(Color::Cyan),
+        ),
         Span::styled(title, sel_style),
     ];
 
@@ -184,36 +347,140 @@ fn render_run_line(
         spans.push(Span::styled(" ⚠", Style::default().fg(Color::Red)));
     }
 
-    if !narrow {
+    if !progress.is_empty() {
+        spans.push(Span::styled(progress, Style::default().fg(Color::Yellow)));
+    }
+
+    if !badge.is_empty() {
+        spans.push(Span::styled(badge, Style::default().fg(Color::Red)));
+    }
+
+    if !hide_suffix {
         spans.push(Span::styled(
             format!(" {duration}"),
             Style::default().fg(Color::DarkGray),
         ));
     }
 
-    if !narrow {
+    if !failure.is_empty() {
+        spans.push(Span::styled(failure, Style::default().fg(Color::DarkGray)));
+    }
+
+    if !hide_suffix {
         spans.push(Span::styled(
             format!(" {}", run.head_branch),
             Style::default().fg(Color::Blue),
         ));
+        if !run.head_sha.is_empty() {
+            spans.push(Span::styled(
+                format!(" {}", run.short_sha()),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        if let Some(actor) = &run.actor {
+            spans.push(Span::styled(
+                format!(" @{actor}"),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        if run.required == Some(true) {
+            spans.push(Span::styled(
+                " required",
+                Style::default().fg(Color::Magenta),
+            ));
+        }
     }
 
     Line::from(spans)
 }
 
+/// ` 2✗/5` for a finished run with failed jobs: failed over concluded jobs.
+/// Complements the single run icon when only some jobs broke.
+fn job_failure_badge(run: &crate::app::WorkflowRun) -> Option<String> {
+    if run.status != RunStatus::Completed {
+        return None;
+    }
+    let counts = AppState::job_conclusion_counts(run)?;
+    let failed: usize = counts
+        .iter()
+        .filter(|(c, _)| c.is_failure())
+        .map(|(_, n)| n)
+        .sum();
+    (failed > 0).then(|| format!(" {failed}✗/{}", counts.values().sum::<usize>()))
+}
+
+fn render_branch_line(
+    name: &str,
+    runs: &[&crate::app::WorkflowRun],
+    is_selected: bool,
+    max_width: usize,
+    expanded: bool,
+) -> Line<'static> {
+    let arrow = if expanded { "▼" } else { "▶" };
+    let prefix = format!(" {arrow} ");
+    let (active, failed) = app::branch_counts(runs);
+    let noun = if runs.len() == 1 { "run" } else { "runs" };
+    let mut parts = vec![format!("{} {noun}", runs.len())];
+    parts.extend(
+        [(active, "active"), (failed, "failed")]
+            .iter()
+            .filter(|(n, _)| *n > 0)
+            .map(|(n, what)| format!("{n} {what}")),
+    );
+    let count = format!(" ({})", parts.join(", "));
+    let name_max = max_width.saturating_sub(UnicodeWidthStr::width(prefix.as_str()) + count.len());
+    let name = truncate(name, name_max);
+
+    Line::from(vec![
+        Span::styled(prefix, Style::default().fg(Color::Blue)),
+        Span::styled(
+            name,
+            select_style(is_selected)
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(count, Style::default().fg(Color::DarkGray)),
+    ])
+}
+
+fn render_group_line(
+    name: &str,
+    jobs: &[&crate::app::Job],
+    is_selected: bool,
+    max_width: usize,
+    expanded: bool,
+) -> Line<'static> {
+    let (status, conclusion) = app::aggregate_status(jobs);
+    let (icon, icon_color) = status_icon(status, conclusion);
+    let arrow = if expanded { "▼" } else { "▶" };
+
+    let prefix = format!("    {arrow} {icon} ");
+    let count = format!(" ({} jobs)", jobs.len());
+    let name_max = max_width.saturating_sub(UnicodeWidthStr::width(prefix.as_str()) + count.len());
+    let name = truncate(name, name_max);
+
+    Line::from(vec![
+        Span::styled(prefix, Style::default().fg(icon_color)),
+        Span::styled(name, select_style(is_selected)),
+        Span::styled(count, Style::default().fg(Color::DarkGray)),
+    ])
+}
+
 fn render_job_line(
     job: &crate::app::Job,
     is_selected: bool,
     _narrow: bool,
     max_width: usize,
     expanded: bool,
+    grouped: bool,
 ) -> Line<'static> {
     let (icon, icon_color) = status_icon(job.status, job.conclusion);
     let arrow = if expanded { "▼" } else { "▶" };
 
     let duration = app::compute_duration(job.started_at, job.completed_at);
 
-    let prefix = format!("    {arrow} {icon} ");
+    let indent = if grouped { "      " } else { "    " };
+    let prefix = format!("{indent}{arrow} {icon} ");
     let prefix_display_width = UnicodeWidthStr::width(prefix.as_str());
     let suffix_width = if duration.is_empty() {
         0
@@ -223,11 +490,11 @@ fn render_job_line(
     let name_max = max_width.saturating_sub(prefix_display_width + suffix_width);
     let name = truncate(&job.name, name_max);
 
-    let sel_style = select_style(is_selected);
+    let name_style = dim_if_skipped(select_style(is_selected), job.conclusion);
 
     let mut spans = vec![
         Span::styled(prefix, Style::default().fg(icon_color)),
-        Span::styled(name, sel_style),
+        Span::styled(name, name_style),
     ];
 
     if !duration.is_empty() {
@@ -240,12 +507,18 @@ fn render_job_line(
     Line::from(spans)
 }
 
-fn render_step_line(step: &crate::app::Step, is_selected: bool, max_width: usize) -> Line<'static> {
+fn render_step_line(
+    step: &crate::app::Step,
+    is_selected: bool,
+    max_width: usize,
+    grouped: bool,
+) -> Line<'static> {
     let (icon, icon_color) = status_icon(step.status, step.conclusion);
 
     let duration = app::compute_duration(step.started_at, step.completed_at);
 
-    let prefix = format!("        {icon} ");
+    let indent = if grouped { "          " } else { "        " };
+    let prefix = format!("{indent}{icon} ");
     let prefix_display_width = UnicodeWidthStr::width(prefix.as_str());
     let suffix_width = if duration.is_empty() {
         0
@@ -255,11 +528,11 @@ fn render_step_line(step: &crate::app::Step, is_selected: bool, max_width: usize
     let name_max = max_width.saturating_sub(prefix_display_width + suffix_width);
     let name = truncate(&step.name, name_max);
 
-    let sel_style = select_style(is_selected);
+    let name_style = dim_if_skipped(select_style(is_selected), step.conclusion);
 
     let mut spans = vec![
         Span::styled(prefix, Style::default().fg(icon_color)),
-        Span::styled(name, sel_style),
+        Span::styled(name, name_style),
     ];
 
     if !duration.is_empty() {
@@ -284,12 +557,282 @@ fn render_loading_line(spinner_frame: usize, is_selected: bool) -> Line<'static>
     ])
 }
 
+fn render_load_error_line(error: &str, is_selected: bool, max_width: usize) -> Line<'static> {
+    let sel_style = select_style(is_selected);
+    let label = "    ✗ failed to load jobs (r to retry): ";
+    let detail = truncate(error, max_width.saturating_sub(label.width()));
+    Line::from(vec![
+        Span::styled(label, sel_style.fg(Color::Red)),
+        Span::styled(detail, sel_style.fg(Color::DarkGray)),
+    ])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn run(id: u64) -> crate::app::WorkflowRun {
+        let at = chrono::Utc::now();
+        crate::app::WorkflowRun {
+            display_title: format!("Title {id}"),
+            head_branch: "feature-x".to_string(),
+            created_at: at,
+            updated_at: at,
+            number: 40 + id,
+            url: String::new(),
+            head_sha: "abc1234def".to_string(),
+            attempt: 2,
+            ..crate::mock::run(id, RunStatus::Completed, Some(Conclusion::Success))
+        }
+    }
+
+    fn render_rows(compact: bool) -> Vec<String> {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.runs = vec![run(1), run(2), run(3)];
+        state.compact = compact;
+        state.rebuild_tree();
+        rows_of(&state)
+    }
+
+    fn rows_of(state: &AppState) -> Vec<String> {
+        // Wider than NARROW_WIDTH_THRESHOLD so only `compact` changes the layout
+        let mut terminal = Terminal::new(TestBackend::new(100, 5)).unwrap();
+        terminal.draw(|f| render(f, f.area(), state)).unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| {
+                row.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .filter(|row| !row.is_empty())
+            .collect()
+    }
+
+    #[test]
+    fn compact_mode_keeps_one_row_per_run_without_suffixes() {
+        let full = render_rows(false);
+        let compact = render_rows(true);
+        assert_eq!(full.len(), 3);
+        assert_eq!(compact.len(), full.len());
+
+        assert!(
+            full[0].contains("#41") && full[0].contains("feature-x"),
+            "{full:?}"
+        );
+        assert!(full[0].contains("↻2"), "{full:?}");
+        for row in &compact {
+            assert!(!row.contains('#'), "{row}");
+            assert!(!row.contains("feature-x"), "{row}");
+            assert!(!row.contains("abc1234"), "{row}");
+            assert!(!row.contains('↻'), "{row}");
+        }
+        assert!(compact[0].contains("Title 1"), "{compact:?}");
+        assert!(compact[0].len() < full[0].len());
+    }
+
+    #[test]
+    fn runs_are_indented_under_branch_headers() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.runs = vec![run(1), run(2)];
+        state.rebuild_tree();
+        let flat = rows_of(&state);
+        assert!(flat[0].starts_with("1▶"), "{flat:?}");
+
+        state.toggle_group_by_branch();
+        let grouped = rows_of(&state);
+        assert!(
+            grouped[0].starts_with(" ▼ feature-x (2 runs)"),
+            "{grouped:?}"
+        );
+        assert!(
+            grouped[1].starts_with(&format!("{BRANCH_INDENT}1▶")),
+            "{grouped:?}"
+        );
+        assert!(
+            grouped[2].starts_with(&format!("{BRANCH_INDENT}2▶")),
+            "{grouped:?}"
+        );
+    }
+
+    #[test]
+    fn failed_step_label_stays_visible_at_80_columns() {
+        let mut failed = run(1);
+        failed.display_title =
+            "Bump the tokio group across 3 directories with 2 updates".to_string();
+        failed.conclusion = Some(Conclusion::Failure);
+        failed.actor = Some("dependabot".to_string());
+        failed.required = Some(true);
+        let mut build = job(Conclusion::Failure);
+        build.steps = vec![crate::app::Step {
+            name: "Run tests".to_string(),
+            status: RunStatus::Completed,
+            conclusion: Some(Conclusion::Failure),
+            number: 3,
+            started_at: None,
+            completed_at: None,
+        }];
+        failed.jobs = Some(vec![build]);
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.runs = vec![failed];
+        state.rebuild_tree();
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 3)).unwrap();
+        terminal.draw(|f| render(f, f.area(), &state)).unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        let row: String = (0..80).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert!(row.contains("— failed: Run tests"), "{row}");
+        assert!(row.contains("Bump the"), "{row}");
+    }
+
+    #[test]
+    fn tint_rows_colors_failed_row_background_except_cursor() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        let mut failed = run(2);
+        failed.conclusion = Some(Conclusion::Failure);
+        let mut queued = run(3);
+        queued.status = RunStatus::Queued;
+        queued.conclusion = None;
+        state.runs = vec![run(1), failed, queued];
+        state.rebuild_tree();
+        state.cursor = 0;
+
+        let draw = |state: &AppState| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 5)).unwrap();
+            terminal.draw(|f| render(f, f.area(), state)).unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let plain = draw(&state);
+        assert!((0..100).all(|x| plain[(x, 1)].bg == Color::Reset));
+
+        state.config.tint_rows = true;
+        let tinted = draw(&state);
+        // Whole row, title included, with the title text untouched
+        assert!((0..100).all(|x| tinted[(x, 1)].bg == Color::Indexed(52)));
+        let title_x = (0..100).find(|&x| tinted[(x, 1)].symbol() == "T").unwrap();
+        assert_eq!(tinted[(title_x, 1)].fg, Color::Reset);
+        // The selected success row keeps its plain highlight; queued stays plain
+        assert!((0..100).all(|x| tinted[(x, 0)].bg == Color::Reset));
+        assert!((0..100).all(|x| tinted[(x, 2)].bg == Color::Reset));
+
+        state.cursor = 1;
+        let tinted = draw(&state);
+        assert!((0..100).all(|x| tinted[(x, 0)].bg == Color::Indexed(22)));
+        assert!((0..100).all(|x| tinted[(x, 1)].bg == Color::Reset));
+    }
+
+    fn job(conclusion: Conclusion) -> crate::app::Job {
+        crate::mock::job("job", RunStatus::Completed, Some(conclusion))
+    }
+
+    #[test]
+    fn skipped_job_name_is_dimmed() {
+        let dimmed = render_job_line(&job(Conclusion::Skipped), false, false, 40, false, false);
+        let normal = render_job_line(&job(Conclusion::Success), false, false, 40, false, false);
+        assert_eq!(dimmed.spans[1].style.fg, Some(Color::DarkGray));
+        assert_eq!(normal.spans[1].style.fg, None);
+        let neutral = render_job_line(&job(Conclusion::Neutral), true, false, 40, false, false);
+        assert_eq!(neutral.spans[1].style.fg, Some(Color::DarkGray));
+        assert!(neutral.spans[1]
+            .style
+            .add_modifier
+            .contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn run_line_shows_failed_job_badge() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        let mut failed = run(1);
+        failed.conclusion = Some(Conclusion::Failure);
+        failed.jobs = Some(vec![
+            job(Conclusion::Success),
+            job(Conclusion::Failure),
+            job(Conclusion::Success),
+            job(Conclusion::TimedOut),
+            job(Conclusion::Skipped),
+        ]);
+        let mut passed = run(2);
+        passed.jobs = Some(vec![job(Conclusion::Success)]);
+        state.runs = vec![failed, passed, run(3)];
+        state.rebuild_tree();
+
+        let rows = rows_of(&state);
+        assert!(rows[0].contains("Title 1 2✗/5"), "{rows:?}");
+        assert!(!rows[1].contains('✗'), "{rows:?}");
+        assert!(!rows[2].contains('✗'), "jobs not loaded: {rows:?}");
+
+        state.compact = true;
+        assert!(!rows_of(&state)[0].contains("2✗/5"));
+    }
+
+    #[test]
+    fn run_line_shows_actor_when_not_compact() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        let mut by_octocat = run(1);
+        by_octocat.actor = Some("octocat".to_string());
+        state.runs = vec![by_octocat, run(2)];
+        state.rebuild_tree();
+
+        let rows = rows_of(&state);
+        assert!(rows[0].ends_with("abc1234 @octocat"), "{rows:?}");
+        assert!(!rows[1].contains('@'), "{rows:?}");
+
+        state.compact = true;
+        assert!(!rows_of(&state)[0].contains("@octocat"));
+    }
+
+    #[test]
+    fn run_line_marks_required_checks_only_when_known() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        let mut required = run(1);
+        required.required = Some(true);
+        let mut optional = run(2);
+        optional.required = Some(false);
+        state.runs = vec![required, optional, run(3)];
+        state.rebuild_tree();
+
+        let rows = rows_of(&state);
+        assert!(rows[0].ends_with("abc1234 required"), "{rows:?}");
+        assert!(!rows[1].contains("required"), "{rows:?}");
+        assert!(!rows[2].contains("required"), "{rows:?}");
+    }
+
+    #[test]
+    fn loading_row_animates_braille_spinner() {
+        let mut state = AppState::new("o/r".to_string(), None, 20, None);
+        state.runs = vec![run(1)];
+        state.expanded_runs.insert(1);
+        state.rebuild_tree();
+
+        let spinner_of = |state: &AppState| {
+            let rows = rows_of(state);
+            let row = rows.iter().find(|r| r.contains("Loading…")).unwrap();
+            row.chars()
+                .find(|c| ('\u{2800}'..='\u{28FF}').contains(c))
+                .unwrap_or_else(|| panic!("no spinner in {row:?}"))
+        };
+        let first = spinner_of(&state);
+        assert_eq!(first, crate::tui::spinner::frame(0));
+        state.advance_spinner();
+        assert_ne!(spinner_of(&state), first);
+    }
 
     // --- format_duration ---
 
+    #[test]
+    fn hidden_counts_at_top_middle_bottom() {
+        assert_eq!(hidden_counts(5, 0, 10), (0, 0));
+        assert_eq!(hidden_counts(20, 0, 10), (0, 10));
+        assert_eq!(hidden_counts(20, 4, 10), (4, 6));
+        assert_eq!(hidden_counts(20, 10, 10), (10, 0));
+    }
+
     #[test]
     fn duration_zero() {
         assert_eq!(format_duration(0), "0s");
@@ -418,16 +961,42 @@ mod tests {
     }
 
     #[test]
-    fn icon_queued_and_unknown() {
-        for status in [
-            RunStatus::Queued,
-            RunStatus::Waiting,
-            RunStatus::Pending,
-            RunStatus::Unknown,
-        ] {
+    fn icon_awaiting_approval() {
+        let (icon, color) = status_icon(RunStatus::Completed, Some(Conclusion::ActionRequired));
+        assert_eq!(icon, "!");
+        assert_eq!(color, Color::Magenta);
+    }
+
+    #[test]
+    fn icon_waiting_has_its_own_glyph() {
+        let (icon, color) = status_icon(RunStatus::Waiting, None);
+        assert_eq!(icon, "⧗");
+        assert_eq!(color, Color::Magenta);
+    }
+
+    #[test]
+    fn icon_queued_requested_and_pending() {
+        for status in [RunStatus::Queued, RunStatus::Requested, RunStatus::Pending] {
             let (icon, color) = status_icon(status, None);
-            assert_eq!(icon, "·");
-            assert_eq!(color, Color::DarkGray);
+            assert_eq!(icon, "○");
+            assert_eq!(color, Color::Blue);
         }
+        assert_ne!(
+            status_icon(RunStatus::Queued, None).0,
+            status_icon(RunStatus::InProgress, None).0
+        );
+    }
+
+    #[test]
+    fn icon_fallback_for_unfinished_conclusion() {
+        assert_eq!(
+            status_icon(RunStatus::Completed, None),
+            ("·", Color::DarkGray)
+        );
+    }
+
+    #[test]
+    fn icon_unknown_is_distinct() {
+        assert_eq!(status_icon(RunStatus::Unknown, None), ("?", Color::Cyan));
     }
 }
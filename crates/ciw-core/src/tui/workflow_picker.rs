@@ -0,0 +1,56 @@
+use crate::app::WorkflowPicker;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+const ALL_WORKFLOWS: &str = "(all workflows)";
+
+pub fn render(f: &mut Frame, picker: &WorkflowPicker) {
+    let area = f.area();
+
+    let width = 50u16.min(area.width);
+    let height = 14u16.min(area.height);
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    let overlay_area = Rect::new(x, y, width, height);
+
+    f.render_widget(Clear, overlay_area);
+
+    let block = Block::default()
+        .title(" Workflow ")
+        .title_bottom(Line::from(" ↑↓ select | Enter apply | Esc cancel ").centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled(" Filter: ", Style::default().fg(Color::DarkGray)),
+        Span::styled(&picker.query, Style::default().fg(Color::White)),
+        Span::styled("█", Style::default().fg(Color::Yellow)),
+    ])];
+
+    let list_height = height.saturating_sub(3) as usize;
+    let skip = (picker.selected + 1).saturating_sub(list_height);
+    let entries = picker.entries();
+    if entries.is_empty() {
+        lines.push(Line::styled(
+            " No matching workflows",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    for (i, entry) in entries.iter().enumerate().skip(skip).take(list_height) {
+        let label = entry.unwrap_or(ALL_WORKFLOWS);
+        let style = if i == picker.selected {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else if entry.is_none() {
+            Style::default().fg(Color::DarkGray)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::styled(format!(" {label}"), style));
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), overlay_area);
+}
@@ -17,6 +17,22 @@ pub struct Cli {
     #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
     pub interval: u64,
 
+    /// Adaptive interval while any run is in progress
+    #[arg(long, default_value_t = ciw_core::app::POLL_INTERVAL_ACTIVE, value_parser = clap::value_parser!(u64).range(1..))]
+    pub active_interval: u64,
+
+    /// Adaptive interval for a minute after runs complete
+    #[arg(long, default_value_t = ciw_core::app::POLL_INTERVAL_RECENT, value_parser = clap::value_parser!(u64).range(1..))]
+    pub recent_interval: u64,
+
+    /// Adaptive interval when nothing is running
+    #[arg(long, default_value_t = ciw_core::app::POLL_INTERVAL_IDLE, value_parser = clap::value_parser!(u64).range(1..))]
+    pub idle_interval: u64,
+
+    /// Randomize each poll wait by ±10% so several instances don't poll in lockstep
+    #[arg(long)]
+    pub jitter: bool,
+
     /// Maximum number of runs to display
     #[arg(short, long, default_value_t = 20)]
     pub limit: usize,
@@ -29,9 +45,166 @@ pub struct Cli {
     #[arg(long)]
     pub no_notify: bool,
 
+    /// Which finished runs trigger a desktop notification: all, failures, none
+    #[arg(long, default_value = "all")]
+    pub notify_on: ciw_core::app::NotifyOn,
+
+    /// Desktop-notify for runs on every branch or only the current one: all, current-branch
+    #[arg(long, default_value = "all")]
+    pub notify_scope: ciw_core::app::NotifyScope,
+
+    /// Fetch jobs for runs as soon as they fail, so expanding them is instant
+    #[arg(long)]
+    pub prefetch_failed: bool,
+
+    /// Expand the newest in-progress run after the first poll, loading its jobs
+    #[arg(long)]
+    pub auto_expand: bool,
+
+    /// Notify once when a run has been in progress for this many minutes
+    #[arg(long, value_name = "MINUTES", value_parser = clap::value_parser!(u64).range(1..))]
+    pub long_run: Option<u64>,
+
+    /// Keep runs with an unrecognized status in the active filter
+    #[arg(long)]
+    pub unknown_active: bool,
+
+    /// Briefly invert the screen when a run newly fails
+    #[arg(long)]
+    pub flash: bool,
+
+    /// Ring the terminal bell when a run newly fails
+    #[arg(long)]
+    pub bell_on_failure: bool,
+
+    /// Only show runs created within a window (24h, 7d) or after a date (2026-01-31)
+    #[arg(long, value_name = "WHEN")]
+    pub since: Option<ciw_core::app::Since>,
+
+    /// Only show runs triggered by this login (needs --backend http; gh run list has no actor)
+    #[arg(long, value_name = "LOGIN")]
+    pub actor: Option<String>,
+
+    /// Watch only the run with this id, expanded (pairs with --watch-until-complete)
+    #[arg(long, value_name = "ID")]
+    pub watch_run: Option<u64>,
+
+    /// Zone for absolute timestamps in the details panel: utc, local, or an IANA name
+    #[arg(long, default_value = "utc")]
+    pub timezone: ciw_core::app::DisplayTz,
+
+    /// POST a JSON summary of each finished run here (Slack incoming webhooks work)
+    #[arg(long, value_name = "URL")]
+    pub webhook: Option<String>,
+
+    /// Run this instead of the built-in desktop notifier; {title}, {status} and {url} are filled in per run
+    #[arg(long, value_name = "TEMPLATE")]
+    pub notify_command: Option<ciw_core::notify::NotifyCommand>,
+
+    /// Don't ring the terminal bell when a desktop notification can't be shown
+    #[arg(long)]
+    pub no_bell: bool,
+
+    /// Disable colors in the TUI and --once output (also: NO_COLOR)
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Tint failed run rows red and successful ones green
+    #[arg(long)]
+    pub tint_rows: bool,
+
+    /// Print runs as a plain-text table and exit (0 = ok, 1 = failures, 2 = error)
+    #[arg(long)]
+    pub once: bool,
+
+    /// With --once: keep polling until no runs are active, then exit with their outcome
+    #[arg(long, requires = "once")]
+    pub watch_until_complete: bool,
+
+    /// Give up on --watch-until-complete after this many seconds (exit code 2)
+    #[arg(long, default_value_t = 3600, value_parser = clap::value_parser!(u64).range(1..))]
+    pub timeout: u64,
+
+    /// How to reach GitHub: `gh` subprocesses, or the REST API with GH_TOKEN/GITHUB_TOKEN
+    #[arg(long, value_enum, default_value_t = Backend::Gh)]
+    pub backend: Backend,
+
+    /// Maximum simultaneous `gh` subprocesses (or HTTP requests with --backend http)
+    #[arg(long, default_value_t = ciw_core::traits::DEFAULT_MAX_CONCURRENT, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub max_concurrent: usize,
+
+    /// Seconds before a `gh` list/status/action command is abandoned
+    #[arg(long, value_name = "SECS", default_value_t = ciw_core::traits::DEFAULT_TIMEOUT_SECS, value_parser = clap::value_parser!(u64).range(1..))]
+    pub fetch_timeout: u64,
+
+    /// Log lines kept (from the end) when viewing failure logs
+    #[arg(long, value_name = "N", default_value_t = ciw_core::app::LOG_MAX_LINES, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(10..=100_000))]
+    pub log_lines: usize,
+
+    /// Seconds before a failed-log fetch is abandoned
+    #[arg(long, value_name = "SECS", default_value_t = ciw_core::traits::DEFAULT_TIMEOUT_SECS, value_parser = clap::value_parser!(u64).range(1..))]
+    pub log_timeout: u64,
+
+    /// Path to the `gh` binary when it isn't on PATH as `gh` (also: GHW_GH_BIN)
+    #[arg(long, value_name = "PATH")]
+    pub gh_bin: Option<std::path::PathBuf>,
+
+    /// GitHub Enterprise hostname, e.g. github.example.com (also: GH_HOST)
+    #[arg(long, value_name = "HOSTNAME")]
+    pub host: Option<String>,
+
+    /// Show the last fetched runs at startup from $XDG_STATE_HOME/ghw/cache/ and keep it updated
+    #[arg(long)]
+    pub cache: bool,
+
+    /// Restore filter, sort, expanded runs and cursor from $XDG_STATE_HOME/ghw/session/ and save them on quit
+    #[arg(long)]
+    pub session: bool,
+
     /// Enable verbose logging to $XDG_STATE_HOME/ghw/debug.log (fallback: ~/.local/state/ghw/)
     #[arg(long)]
     pub verbose: bool,
+
+    /// With --once: send debug logs to stderr instead of the log file
+    #[arg(long, requires = "once")]
+    pub log_stderr: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    Gh,
+    Http,
+}
+
+/// `--gh-bin`, then a non-empty `GHW_GH_BIN`, then plain `gh` from PATH.
+pub fn resolve_gh_bin(flag: Option<&std::path::Path>) -> std::path::PathBuf {
+    flag.map(std::path::Path::to_path_buf)
+        .or_else(|| {
+            std::env::var_os("GHW_GH_BIN")
+                .filter(|v| !v.is_empty())
+                .map(std::path::PathBuf::from)
+        })
+        .unwrap_or_else(|| std::path::PathBuf::from("gh"))
+}
+
+/// `--host`, then a non-empty `GH_HOST`; `None` means github.com.
+pub fn resolve_host(flag: Option<&str>) -> Option<String> {
+    flag.map(str::to_string)
+        .or_else(|| std::env::var("GH_HOST").ok())
+        .map(|h| h.trim().trim_end_matches('/').to_string())
+        .filter(|h| !h.is_empty() && h != "github.com")
+}
+
+/// `gh run list` reports no actor, so `--actor` on the gh backend would hide
+/// every run without saying why.
+pub fn validate_actor_backend(actor: Option<&str>, backend: Backend) -> Result<(), String> {
+    match (actor, backend) {
+        (Some(_), Backend::Gh) => Err(
+            "--actor needs --backend http: `gh run list` doesn't report who triggered a run"
+                .to_string(),
+        ),
+        _ => Ok(()),
+    }
 }
 
 /// Validates that `repo` matches the `owner/repo` pattern.
@@ -42,8 +215,14 @@ pub fn validate_repo_format(repo: &str) -> Result<(), String> {
         || parts[1].is_empty()
         || repo.contains(char::is_whitespace)
     {
+        // `gh` accepts HOST/OWNER/REPO; here the host goes in its own flag
+        let host_hint = if parts.len() == 3 {
+            " Pass the host with --host."
+        } else {
+            ""
+        };
         return Err(format!(
-            "Invalid repo format '{repo}'. Expected 'owner/repo' (e.g. 'cli/cli')."
+            "Invalid repo format '{repo}'. Expected 'owner/repo' (e.g. 'cli/cli').{host_hint}"
         ));
     }
     Ok(())
@@ -53,6 +232,13 @@ pub fn validate_repo_format(repo: &str) -> Result<(), String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn actor_requires_http_backend() {
+        assert!(validate_actor_backend(Some("me"), Backend::Gh).is_err());
+        assert!(validate_actor_backend(Some("me"), Backend::Http).is_ok());
+        assert!(validate_actor_backend(None, Backend::Gh).is_ok());
+    }
+
     #[test]
     fn valid_repo_format() {
         assert!(validate_repo_format("owner/repo").is_ok());
@@ -68,6 +254,9 @@ mod tests {
     #[test]
     fn invalid_repo_multiple_slashes() {
         assert!(validate_repo_format("a/b/c").is_err());
+        assert!(validate_repo_format("ghe.example.com/o/r")
+            .unwrap_err()
+            .contains("--host"));
     }
 
     #[test]
@@ -1,41 +1,111 @@
 use async_trait::async_trait;
-use ciw_core::traits::CiExecutor;
+use ciw_core::app::RateLimit;
+use ciw_core::traits::{self, CiExecutor};
 use color_eyre::eyre::{eyre, Result};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::process::Command;
+use tokio::sync::Semaphore;
 
-const GH_TIMEOUT: Duration = Duration::from_secs(30);
+/// Local `git` calls; not worth a flag.
+const GIT_TIMEOUT: Duration = Duration::from_secs(30);
 const CLIPBOARD_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct GhExecutor {
     pub repo: String,
+    /// Bounds concurrent `gh` processes — rapid expands would otherwise fork dozens.
+    permits: Arc<Semaphore>,
+    timeouts: traits::Timeouts,
+    /// `--gh-bin`; `gh` from PATH by default.
+    bin: PathBuf,
+    /// `--host` / `GH_HOST` for GitHub Enterprise; `None` leaves gh's default.
+    host: Option<String>,
 }
 
 impl GhExecutor {
+    #[cfg(test)]
     pub fn new(repo: String) -> Self {
-        Self { repo }
+        Self::with_concurrency(repo, traits::DEFAULT_MAX_CONCURRENT)
+    }
+
+    pub fn with_concurrency(repo: String, max_concurrent: usize) -> Self {
+        Self {
+            repo,
+            permits: Arc::new(Semaphore::new(max_concurrent.max(1))),
+            timeouts: traits::Timeouts::default(),
+            bin: PathBuf::from("gh"),
+            host: None,
+        }
+    }
+
+    pub fn with_host(mut self, host: Option<String>) -> Self {
+        self.host = host;
+        self
+    }
+
+    pub fn with_bin(mut self, bin: PathBuf) -> Self {
+        self.bin = bin;
+        self
+    }
+
+    pub fn with_timeouts(mut self, timeouts: traits::Timeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    async fn gh(&self, args: &[&str]) -> Result<String> {
+        traits::with_permit(
+            &self.permits,
+            run_gh(self.command(args), self.timeouts.fetch),
+        )
+        .await
+    }
+
+    async fn gh_log(&self, args: &[&str]) -> Result<String> {
+        traits::with_permit(&self.permits, run_gh(self.command(args), self.timeouts.log)).await
+    }
+
+    /// The seam every `gh` spawn goes through, so tests can check the program.
+    /// `GH_HOST` rather than `--hostname`: only `gh api` has the flag, and gh
+    /// resolves a bare `--repo owner/name` against `GH_HOST` too.
+    fn command(&self, args: &[&str]) -> GhCommand {
+        let mut cmd = Command::new(&self.bin);
+        cmd.args(args);
+        if let Some(host) = &self.host {
+            cmd.env("GH_HOST", host);
+        }
+        GhCommand {
+            cmd,
+            bin: self.bin.clone(),
+            args: args.iter().map(|a| (*a).to_string()).collect(),
+        }
     }
 }
 
+/// Same fields for `run list` and `run view`, so both parse alike.
+const RUN_FIELDS: &str = "databaseId,displayTitle,name,headBranch,status,conclusion,createdAt,updatedAt,event,number,url,headSha,attempt";
+
 #[async_trait]
 impl CiExecutor for GhExecutor {
     async fn check_available(&self) -> Result<()> {
         // Use `gh auth token` instead of `gh auth status` — the latter exits 1
         // if *any* account (even inactive) has a stale token, even when the
         // active account works fine.
-        run_gh(&["auth", "token"]).await.map(|_| ())
+        self.gh(&["auth", "token"]).await.map(|_| ())
     }
 
     async fn detect_repo(&self) -> Result<String> {
-        let output = run_gh(&[
-            "repo",
-            "view",
-            "--json",
-            "nameWithOwner",
-            "-q",
-            ".nameWithOwner",
-        ])
-        .await?;
+        let output = self
+            .gh(&[
+                "repo",
+                "view",
+                "--json",
+                "nameWithOwner",
+                "-q",
+                ".nameWithOwner",
+            ])
+            .await?;
         let repo = output.trim().to_string();
         if repo.is_empty() {
             return Err(eyre!("Could not detect repository. Use --repo flag."));
@@ -44,43 +114,53 @@ impl CiExecutor for GhExecutor {
     }
 
     async fn detect_branch(&self) -> Result<String> {
-        let output = tokio::time::timeout(
-            GH_TIMEOUT,
-            Command::new("git")
-                .args(["rev-parse", "--abbrev-ref", "HEAD"])
-                .output(),
-        )
-        .await
-        .map_err(|_| eyre!("git command timed out after {}s", GH_TIMEOUT.as_secs()))?
-        .map_err(|e| eyre!("Failed to detect branch: {}", e))?;
-
-        if !output.status.success() {
-            return Err(eyre!(
-                "Failed to detect branch: {}",
-                String::from_utf8_lossy(&output.stderr).trim()
-            ));
-        }
-        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        run_git(&["rev-parse", "--abbrev-ref", "HEAD"])
+            .await
+            .map(|b| b.trim().to_string())
+            .map_err(|e| eyre!("Failed to detect branch: {e}"))
     }
 
-    async fn fetch_runs(&self, limit: usize, workflow: Option<&str>) -> Result<String> {
+    async fn fetch_runs(
+        &self,
+        limit: usize,
+        workflow: Option<&str>,
+        branch: Option<&str>,
+    ) -> Result<String> {
         let limit_str = limit.to_string();
         let mut args = vec![
-            "run", "list",
-            "--repo", &self.repo,
-            "--limit", &limit_str,
-            "--json", "databaseId,displayTitle,name,headBranch,status,conclusion,createdAt,updatedAt,event,number,url",
+            "run", "list", "--repo", &self.repo, "--limit", &limit_str, "--json", RUN_FIELDS,
         ];
         if let Some(w) = workflow {
             args.push("--workflow");
             args.push(w);
         }
-        run_gh(&args).await
+        if let Some(b) = branch {
+            args.push("--branch");
+            args.push(b);
+        }
+        self.gh(&args).await
+    }
+
+    /// `gh run view` prints a bare object; wrapped so `parse_runs` takes it.
+    async fn fetch_run(&self, run_id: u64) -> Result<String> {
+        let run_id_str = run_id.to_string();
+        let json = self
+            .gh(&[
+                "run",
+                "view",
+                "--repo",
+                &self.repo,
+                &run_id_str,
+                "--json",
+                RUN_FIELDS,
+            ])
+            .await?;
+        Ok(format!("[{}]", json.trim()))
     }
 
     async fn fetch_jobs(&self, run_id: u64) -> Result<String> {
         let run_id_str = run_id.to_string();
-        run_gh(&[
+        self.gh(&[
             "run",
             "view",
             "--repo",
@@ -94,19 +174,21 @@ impl CiExecutor for GhExecutor {
 
     async fn cancel_run(&self, run_id: u64) -> Result<()> {
         let run_id_str = run_id.to_string();
-        run_gh(&["run", "cancel", "--repo", &self.repo, &run_id_str]).await?;
+        self.gh(&["run", "cancel", "--repo", &self.repo, &run_id_str])
+            .await?;
         Ok(())
     }
 
     async fn delete_run(&self, run_id: u64) -> Result<()> {
         let run_id_str = run_id.to_string();
-        run_gh(&["run", "delete", "--repo", &self.repo, &run_id_str]).await?;
+        self.gh(&["run", "delete", "--repo", &self.repo, &run_id_str])
+            .await?;
         Ok(())
     }
 
     async fn rerun_failed(&self, run_id: u64) -> Result<()> {
         let run_id_str = run_id.to_string();
-        run_gh(&[
+        self.gh(&[
             "run",
             "rerun",
             "--failed",
@@ -118,17 +200,68 @@ impl CiExecutor for GhExecutor {
         Ok(())
     }
 
-    async fn fetch_failed_logs(&self, run_id: u64) -> Result<String> {
-        let run_id_str = run_id.to_string();
-        let result = run_gh(&[
-            "run",
-            "view",
-            "--repo",
-            &self.repo,
-            &run_id_str,
-            "--log-failed",
+    /// Deployment reviews are approved via `pending_deployments`; runs with none
+    /// pending are assumed to be fork PRs awaiting contributor approval.
+    async fn approve(&self, run_id: u64) -> Result<()> {
+        let endpoint = format!(
+            "repos/{}/actions/runs/{run_id}/pending_deployments",
+            self.repo
+        );
+        let pending = self.gh(&["api", &endpoint]).await?;
+        let env_ids = parse_pending_environment_ids(&pending)?;
+        if env_ids.is_empty() {
+            let approve = format!("repos/{}/actions/runs/{run_id}/approve", self.repo);
+            self.gh(&["api", "-X", "POST", &approve]).await?;
+            return Ok(());
+        }
+        let fields: Vec<String> = env_ids
+            .iter()
+            .map(|id| format!("environment_ids[]={id}"))
+            .collect();
+        let mut args = vec!["api", "-X", "POST", &endpoint];
+        for field in &fields {
+            args.push("-F");
+            args.push(field);
+        }
+        args.extend(["-f", "state=approved", "-f", "comment=Approved from ghw"]);
+        self.gh(&args).await?;
+        Ok(())
+    }
+
+    async fn dispatch_workflow(&self, workflow: &str, branch: &str) -> Result<()> {
+        self.gh(&[
+            "workflow", "run", workflow, "--repo", &self.repo, "--ref", branch,
         ])
         .await?;
+        Ok(())
+    }
+
+    async fn current_user(&self) -> Result<String> {
+        let json = self.gh(&["api", "user"]).await?;
+        parse_login(&json)
+    }
+
+    async fn rate_limit(&self) -> Result<RateLimit> {
+        let json = self.gh(&["api", "rate_limit"]).await?;
+        parse_rate_limit(&json)
+    }
+
+    fn supports_rate_limit(&self) -> bool {
+        true
+    }
+
+    async fn fetch_failed_logs(&self, run_id: u64) -> Result<String> {
+        let run_id_str = run_id.to_string();
+        let result = self
+            .gh_log(&[
+                "run",
+                "view",
+                "--repo",
+                &self.repo,
+                &run_id_str,
+                "--log-failed",
+            ])
+            .await?;
         check_log_size(&result)?;
         Ok(result)
     }
@@ -136,19 +269,46 @@ impl CiExecutor for GhExecutor {
     async fn fetch_failed_logs_for_job(&self, run_id: u64, job_id: u64) -> Result<String> {
         let run_id_str = run_id.to_string();
         let job_id_str = job_id.to_string();
-        let result = run_gh(&[
+        let result = self
+            .gh_log(&[
+                "run",
+                "view",
+                "--repo",
+                &self.repo,
+                &run_id_str,
+                "--log-failed",
+                "--job",
+                &job_id_str,
+            ])
+            .await?;
+        check_log_size(&result)?;
+        Ok(result)
+    }
+
+    async fn fetch_failed_logs_streaming(
+        &self,
+        run_id: u64,
+        job_id: Option<u64>,
+        progress: &(dyn Fn(usize) + Send + Sync),
+    ) -> Result<String> {
+        let run_id_str = run_id.to_string();
+        let job_id_str = job_id.map(|id| id.to_string());
+        let mut args = vec![
             "run",
             "view",
             "--repo",
             &self.repo,
             &run_id_str,
             "--log-failed",
-            "--job",
-            &job_id_str,
-        ])
-        .await?;
-        check_log_size(&result)?;
-        Ok(result)
+        ];
+        if let Some(job_id) = &job_id_str {
+            args.extend(["--job", job_id]);
+        }
+        traits::with_permit(
+            &self.permits,
+            run_gh_streaming(self.command(&args), self.timeouts.log, progress),
+        )
+        .await
     }
 
     fn open_in_browser(&self, url: &str) -> Result<()> {
@@ -160,18 +320,49 @@ impl CiExecutor for GhExecutor {
     }
 }
 
-async fn run_gh(args: &[&str]) -> Result<String> {
+/// Raw stdout of a `git` command in the cwd; shared with the HTTP backend.
+pub(crate) async fn run_git(args: &[&str]) -> Result<String> {
+    let output = tokio::time::timeout(GIT_TIMEOUT, Command::new("git").args(args).output())
+        .await
+        .map_err(|_| eyre!("git command timed out after {}s", GIT_TIMEOUT.as_secs()))?
+        .map_err(|e| eyre!("Failed to run git: {e}"))?;
+
+    if !output.status.success() {
+        return Err(eyre!("{}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// A ready-to-spawn `gh` plus what error messages and debug logs need.
+struct GhCommand {
+    cmd: Command,
+    bin: PathBuf,
+    args: Vec<String>,
+}
+
+/// Names a custom `--gh-bin` in the not-found error; pointing at the install
+/// page would be misleading when the user did install it, just elsewhere.
+fn spawn_error(bin: &Path, e: &std::io::Error) -> color_eyre::Report {
+    if e.kind() != std::io::ErrorKind::NotFound {
+        return eyre!("Failed to run {}: {e}", bin.display());
+    }
+    if bin == Path::new("gh") {
+        eyre!("gh CLI not found. Install it from https://cli.github.com/")
+    } else {
+        eyre!(
+            "gh CLI not found at '{}' (from --gh-bin / GHW_GH_BIN)",
+            bin.display()
+        )
+    }
+}
+
+async fn run_gh(command: GhCommand, timeout: Duration) -> Result<String> {
+    let GhCommand { mut cmd, bin, args } = command;
     let start = std::time::Instant::now();
-    let output = tokio::time::timeout(GH_TIMEOUT, Command::new("gh").args(args).output())
+    let output = tokio::time::timeout(timeout, cmd.output())
         .await
-        .map_err(|_| eyre!("gh command timed out after {}s", GH_TIMEOUT.as_secs()))?
-        .map_err(|e| {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                eyre!("gh CLI not found. Install it from https://cli.github.com/")
-            } else {
-                eyre!("Failed to run gh: {}", e)
-            }
-        })?;
+        .map_err(|_| eyre!("gh command timed out after {}s", timeout.as_secs()))?
+        .map_err(|e| spawn_error(&bin, &e))?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -186,13 +377,141 @@ async fn run_gh(args: &[&str]) -> Result<String> {
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
+/// [`run_gh`] for large output: reads stdout as it arrives, reporting progress,
+/// and gives up as soon as it passes `LOG_SIZE_LIMIT` rather than buffering it all.
+async fn run_gh_streaming(
+    command: GhCommand,
+    timeout: Duration,
+    progress: &(dyn Fn(usize) + Send + Sync),
+) -> Result<String> {
+    use std::process::Stdio;
+    use tokio::io::AsyncReadExt;
+
+    let GhCommand { mut cmd, bin, args } = command;
+    let start = std::time::Instant::now();
+    // Dropping the child on an early return (timeout, size cap) kills it
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| spawn_error(&bin, &e))?;
+    let mut stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| eyre!("Failed to capture gh output"))?;
+    let mut stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| eyre!("Failed to capture gh output"))?;
+    // Drained concurrently so a chatty stderr can't fill its pipe and stall gh
+    let stderr_task = tokio::spawn(async move {
+        let mut buf = Vec::new();
+        let _ = stderr.read_to_end(&mut buf).await;
+        buf
+    });
+
+    let read = async {
+        let mut out = Vec::new();
+        let mut throttle = traits::ProgressThrottle::default();
+        let mut buf = vec![0u8; 16 * 1024];
+        loop {
+            let n = stdout
+                .read(&mut buf)
+                .await
+                .map_err(|e| eyre!("Failed to read gh output: {e}"))?;
+            if n == 0 {
+                break;
+            }
+            out.extend_from_slice(&buf[..n]);
+            check_log_size(&out)?;
+            if let Some(total) = throttle.add(n) {
+                progress(total);
+            }
+        }
+        let status = child
+            .wait()
+            .await
+            .map_err(|e| eyre!("Failed to run gh: {e}"))?;
+        Ok::<_, color_eyre::Report>((status, out))
+    };
+    let (status, out) = tokio::time::timeout(timeout, read)
+        .await
+        .map_err(|_| eyre!("gh command timed out after {}s", timeout.as_secs()))??;
+
+    if !status.success() {
+        let stderr = stderr_task.await.unwrap_or_default();
+        return Err(eyre!(
+            "{}",
+            classify_gh_error(&String::from_utf8_lossy(&stderr))
+        ));
+    }
+
+    tracing::debug!(
+        args = ?args,
+        bytes = out.len(),
+        elapsed_ms = start.elapsed().as_millis(),
+        "gh command streamed"
+    );
+    Ok(String::from_utf8_lossy(&out).to_string())
+}
+
+/// Extracts environment ids from a `pending_deployments` response.
+pub(crate) fn parse_pending_environment_ids(json: &str) -> Result<Vec<u64>> {
+    let parsed: serde_json::Value = serde_json::from_str(json)
+        .map_err(|e| eyre!("Failed to parse pending deployments: {e}"))?;
+    Ok(parsed
+        .as_array()
+        .map(|deployments| {
+            deployments
+                .iter()
+                .filter_map(|d| d["environment"]["id"].as_u64())
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// `login` of a `GET /user` response; shared with the HTTP backend.
+pub(crate) fn parse_login(json: &str) -> Result<String> {
+    let parsed: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| eyre!("Failed to parse user: {e}"))?;
+    parsed["login"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| eyre!("User response missing login"))
+}
+
+/// Reads the REST `core` bucket from a `rate_limit` response — the one `gh run`
+/// and `gh api` draw from.
+pub(crate) fn parse_rate_limit(json: &str) -> Result<RateLimit> {
+    let parsed: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| eyre!("Failed to parse rate limit: {e}"))?;
+    let core = &parsed["resources"]["core"];
+    let field = |name: &str| {
+        core[name]
+            .as_u64()
+            .ok_or_else(|| eyre!("Rate limit response missing resources.core.{name}"))
+    };
+    let reset = i64::try_from(field("reset")?)
+        .ok()
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .ok_or_else(|| eyre!("Rate limit reset time out of range"))?;
+    Ok(RateLimit {
+        limit: field("limit")?,
+        remaining: field("remaining")?,
+        reset,
+    })
+}
+
 const LOG_SIZE_LIMIT: usize = 10 * 1024 * 1024; // 10 MB
 
-fn check_log_size(log: &str) -> Result<()> {
-    if log.len() > LOG_SIZE_LIMIT {
+pub(crate) fn check_log_size(log: impl AsRef<[u8]>) -> Result<()> {
+    let len = log.as_ref().len();
+    if len > LOG_SIZE_LIMIT {
         return Err(eyre!(
             "Log output too large ({:.1} MB, max {} MB)",
-            log.len() as f64 / (1024.0 * 1024.0),
+            len as f64 / (1024.0 * 1024.0),
             LOG_SIZE_LIMIT / (1024 * 1024)
         ));
     }
@@ -203,7 +522,7 @@ fn check_log_size(log: &str) -> Result<()> {
 ///
 /// Uses compile-time detection for Windows/macOS, then runtime detection for WSL2
 /// (which compiles as `target_os = "linux"` but needs `wslview` instead of `xdg-open`).
-fn open_in_browser_impl(url: &str) -> Result<()> {
+pub(crate) fn open_in_browser_impl(url: &str) -> Result<()> {
     use std::process::{Command, Stdio};
 
     // Validate URL scheme to prevent opening arbitrary protocols or shell injection
@@ -263,7 +582,7 @@ fn open_in_browser_impl(url: &str) -> Result<()> {
     ))
 }
 
-async fn copy_to_clipboard_impl(text: &str) -> Result<()> {
+pub(crate) async fn copy_to_clipboard_impl(text: &str) -> Result<()> {
     use tokio::io::AsyncWriteExt;
 
     // Determine clipboard command: try clip.exe first (WSL), then wl-copy (Wayland), then xclip (X11)
@@ -316,7 +635,9 @@ async fn copy_to_clipboard_impl(text: &str) -> Result<()> {
 }
 
 pub fn classify_gh_error(stderr: &str) -> String {
-    if stderr.contains("token") && stderr.contains("invalid") {
+    if ciw_core::poller::is_rate_limit_error(stderr) {
+        format!("{}: {}", ciw_core::poller::RATE_LIMITED, stderr.trim())
+    } else if stderr.contains("token") && stderr.contains("invalid") {
         // Stale account with expired/invalid token — suggest removing it
         "A gh account has an invalid token.\n  \
          Run `gh auth status` to identify it, then:\n  \
@@ -340,6 +661,103 @@ pub fn classify_gh_error(stderr: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn executor_keeps_configured_timeouts() {
+        let exec = GhExecutor::new("o/r".to_string());
+        assert_eq!(exec.timeouts, traits::Timeouts::default());
+        let timeouts = traits::Timeouts::from_secs(5, 300);
+        let exec = exec.with_timeouts(timeouts);
+        assert_eq!(exec.timeouts.fetch, Duration::from_secs(5));
+        assert_eq!(exec.timeouts.log, Duration::from_mins(5));
+    }
+
+    #[test]
+    fn executor_spawns_configured_binary() {
+        let exec = GhExecutor::new("o/r".to_string());
+        let program = |exec: &GhExecutor| {
+            exec.command(&["auth", "token"])
+                .cmd
+                .as_std()
+                .get_program()
+                .to_owned()
+        };
+        assert_eq!(program(&exec), "gh");
+        let exec = exec.with_bin(PathBuf::from("/opt/tools/gh"));
+        assert_eq!(program(&exec), "/opt/tools/gh");
+    }
+
+    #[test]
+    fn host_is_passed_to_every_gh_command() {
+        let gh_host = |exec: &GhExecutor| {
+            exec.command(&["run", "list"])
+                .cmd
+                .as_std()
+                .get_envs()
+                .find(|(k, _)| *k == "GH_HOST")
+                .and_then(|(_, v)| v.map(std::ffi::OsStr::to_owned))
+        };
+        let exec = GhExecutor::new("o/r".to_string());
+        assert_eq!(gh_host(&exec), None);
+        let exec = exec.with_host(Some("ghe.example.com".to_string()));
+        assert_eq!(gh_host(&exec).as_deref(), Some("ghe.example.com".as_ref()));
+    }
+
+    #[test]
+    fn not_found_error_names_custom_binary() {
+        let missing = std::io::Error::from(std::io::ErrorKind::NotFound);
+        let default = spawn_error(Path::new("gh"), &missing).to_string();
+        assert!(default.contains("https://cli.github.com/"));
+        let custom = spawn_error(Path::new("/opt/tools/gh"), &missing).to_string();
+        assert!(custom.contains("'/opt/tools/gh'"), "{custom}");
+    }
+
+    #[test]
+    fn pending_environment_ids_parsed() {
+        let json = r#"[
+            {"environment": {"id": 161088068, "name": "staging"}, "current_user_can_approve": true},
+            {"environment": {"id": 161088069, "name": "production"}}
+        ]"#;
+        assert_eq!(
+            parse_pending_environment_ids(json).unwrap(),
+            vec![161_088_068, 161_088_069]
+        );
+    }
+
+    #[test]
+    fn rate_limit_parsed_from_core_bucket() {
+        let json = r#"{
+            "resources": {
+                "core": {"limit": 5000, "used": 321, "remaining": 4679, "reset": 1767225600},
+                "graphql": {"limit": 5000, "used": 0, "remaining": 5000, "reset": 1767229200},
+                "search": {"limit": 30, "used": 0, "remaining": 30, "reset": 1767225660}
+            },
+            "rate": {"limit": 5000, "used": 321, "remaining": 4679, "reset": 1767225600}
+        }"#;
+        let quota = parse_rate_limit(json).unwrap();
+        assert_eq!(quota.limit, 5000);
+        assert_eq!(quota.remaining, 4679);
+        assert_eq!(quota.reset.to_rfc3339(), "2026-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn rate_limit_missing_fields_is_error() {
+        assert!(parse_rate_limit(r#"{"resources": {}}"#).is_err());
+        assert!(parse_rate_limit("not json").is_err());
+    }
+
+    #[test]
+    fn login_parsed_from_user_response() {
+        let json = r#"{"login": "octocat", "id": 1, "type": "User"}"#;
+        assert_eq!(parse_login(json).unwrap(), "octocat");
+        assert!(parse_login(r#"{"id": 1}"#).is_err());
+    }
+
+    #[test]
+    fn pending_environment_ids_empty() {
+        assert!(parse_pending_environment_ids("[]").unwrap().is_empty());
+        assert!(parse_pending_environment_ids("not json").is_err());
+    }
+
     #[test]
     fn classify_not_logged_in() {
         let msg = classify_gh_error("You are not logged into any GitHub hosts");
@@ -364,6 +782,17 @@ mod tests {
         assert!(msg.contains("Not in a GitHub repository"));
     }
 
+    #[test]
+    fn classify_rate_limit() {
+        let msg = classify_gh_error("HTTP 403: API rate limit exceeded for user ID 1.");
+        assert!(ciw_core::poller::is_rate_limited(&msg));
+        let msg = classify_gh_error("HTTP 429: Too Many Requests");
+        assert!(ciw_core::poller::is_rate_limited(&msg));
+        assert!(!ciw_core::poller::is_rate_limited(&classify_gh_error(
+            "HTTP 500"
+        )));
+    }
+
     #[test]
     fn classify_generic_error() {
         let msg = classify_gh_error("something went wrong");
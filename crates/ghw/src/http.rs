@@ -0,0 +1,837 @@
+//! `--backend http`: talks to the GitHub REST API directly with a token instead
+//! of spawning `gh`. Responses are converted into the `gh --json` shape so
+//! [`crate::parser::GhParser`] stays the only parse path the TUI sees.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ciw_core::app::{Conclusion, Job, RateLimit, RunStatus, Step, WorkflowRun};
+use ciw_core::traits::{self, CiExecutor};
+use color_eyre::eyre::{eyre, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+use crate::executor;
+
+const DEFAULT_API_BASE: &str = "https://api.github.com";
+/// REST maximum; larger `--limit` values are paginated.
+const MAX_PER_PAGE: usize = 100;
+
+pub struct GhHttpExecutor {
+    pub repo: String,
+    base_url: String,
+    token: String,
+    client: reqwest::Client,
+    /// Same role as in `GhExecutor`: bounds in-flight requests.
+    permits: Arc<Semaphore>,
+    timeouts: traits::Timeouts,
+    /// Workflow name/file → id. Ids never change, and without this a
+    /// `--workflow` filter would cost an extra request on every poll.
+    workflow_ids: Mutex<HashMap<String, u64>>,
+}
+
+impl GhHttpExecutor {
+    /// Reads `GH_TOKEN` (then `GITHUB_TOKEN`) and, for GitHub Enterprise,
+    /// `GITHUB_API_URL` — else derives the API root from `host`.
+    pub fn from_env(repo: String, max_concurrent: usize, host: Option<&str>) -> Result<Self> {
+        let token = ["GH_TOKEN", "GITHUB_TOKEN"]
+            .iter()
+            .find_map(|var| std::env::var(var).ok().filter(|t| !t.trim().is_empty()))
+            .ok_or_else(|| eyre!("--backend http needs GH_TOKEN or GITHUB_TOKEN to be set"))?;
+        let base_url = std::env::var("GITHUB_API_URL")
+            .ok()
+            .filter(|u| !u.is_empty())
+            .unwrap_or_else(|| {
+                host.map_or_else(|| DEFAULT_API_BASE.to_string(), enterprise_api_base)
+            });
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("ghw/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| eyre!("Failed to build HTTP client: {e}"))?;
+        Ok(Self {
+            repo,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token: token.trim().to_string(),
+            client,
+            permits: Arc::new(Semaphore::new(max_concurrent.max(1))),
+            timeouts: traits::Timeouts::default(),
+            workflow_ids: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn with_timeouts(mut self, timeouts: traits::Timeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<String> {
+        self.request_within(method, path, body, self.timeouts.fetch)
+            .await
+    }
+
+    async fn request_within(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+        timeout: Duration,
+    ) -> Result<String> {
+        let url = api_url(&self.base_url, path);
+        traits::with_permit(&self.permits, async {
+            let start = std::time::Instant::now();
+            let mut req = self
+                .client
+                .request(method.clone(), &url)
+                .timeout(timeout)
+                .bearer_auth(&self.token)
+                .header("Accept", "application/vnd.github+json")
+                .header("X-GitHub-Api-Version", "2022-11-28");
+            if let Some(body) = body {
+                req = req
+                    .header("Content-Type", "application/json")
+                    .body(body.to_string());
+            }
+            let resp = req.send().await.map_err(|e| {
+                if e.is_timeout() {
+                    eyre!("GitHub API timed out after {}s", timeout.as_secs())
+                } else {
+                    eyre!("GitHub API request failed: {}", error_chain(&e))
+                }
+            })?;
+            let status = resp.status().as_u16();
+            let remaining = resp
+                .headers()
+                .get("x-ratelimit-remaining")
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let text = resp
+                .text()
+                .await
+                .map_err(|e| eyre!("Failed to read GitHub API response: {e}"))?;
+            if !(200..300).contains(&status) {
+                return Err(eyre!(
+                    "{}",
+                    classify_http_error(status, remaining.as_deref(), &text)
+                ));
+            }
+            tracing::debug!(
+                %method,
+                path,
+                elapsed_ms = start.elapsed().as_millis(),
+                "GitHub API request completed"
+            );
+            Ok(text)
+        })
+        .await
+    }
+
+    async fn get(&self, path: &str) -> Result<String> {
+        self.request(reqwest::Method::GET, path, None).await
+    }
+
+    async fn get_log(&self, path: &str) -> Result<String> {
+        self.request_within(reqwest::Method::GET, path, None, self.timeouts.log)
+            .await
+    }
+
+    async fn post(&self, path: &str, body: Option<serde_json::Value>) -> Result<String> {
+        self.request(reqwest::Method::POST, path, body).await
+    }
+
+    /// `gh` accepts a workflow's display name; the REST API wants its id or file name.
+    async fn workflow_id(&self, workflow: &str) -> Result<u64> {
+        if let Some(&id) = self.workflow_ids.lock().unwrap().get(workflow) {
+            return Ok(id);
+        }
+        let json = self
+            .get(&format!(
+                "repos/{}/actions/workflows?per_page={MAX_PER_PAGE}",
+                self.repo
+            ))
+            .await?;
+        let id = find_workflow_id(&json, workflow)
+            .ok_or_else(|| eyre!("Workflow '{workflow}' not found in {}", self.repo))?;
+        self.workflow_ids
+            .lock()
+            .unwrap()
+            .insert(workflow.to_string(), id);
+        Ok(id)
+    }
+
+    async fn fetch_all_jobs(&self, run_id: u64) -> Result<Vec<Job>> {
+        let mut jobs = Vec::new();
+        for page in 1.. {
+            let json = self.get(&jobs_path(&self.repo, run_id, page)).await?;
+            let (batch, total) = parse_rest_jobs(&json)?;
+            let done = batch.len() < MAX_PER_PAGE;
+            jobs.extend(batch);
+            if done || jobs.len() as u64 >= total {
+                break;
+            }
+        }
+        Ok(jobs)
+    }
+}
+
+#[async_trait]
+impl CiExecutor for GhHttpExecutor {
+    async fn check_available(&self) -> Result<()> {
+        // Doesn't count against the quota and fails fast on a bad token
+        self.get("rate_limit").await.map(|_| ())
+    }
+
+    async fn detect_repo(&self) -> Result<String> {
+        let url = executor::run_git(&["remote", "get-url", "origin"]).await?;
+        repo_from_remote_url(url.trim()).ok_or_else(|| {
+            eyre!("Could not detect a GitHub repository from the origin remote. Use --repo flag.")
+        })
+    }
+
+    async fn detect_branch(&self) -> Result<String> {
+        executor::run_git(&["rev-parse", "--abbrev-ref", "HEAD"])
+            .await
+            .map(|b| b.trim().to_string())
+    }
+
+    async fn fetch_runs(
+        &self,
+        limit: usize,
+        workflow: Option<&str>,
+        branch: Option<&str>,
+    ) -> Result<String> {
+        let workflow_id = match workflow {
+            Some(w) => Some(self.workflow_id(w).await?),
+            None => None,
+        };
+        let per_page = limit.clamp(1, MAX_PER_PAGE);
+        let mut runs = Vec::new();
+        for page in 1.. {
+            let json = self
+                .get(&runs_path(&self.repo, workflow_id, branch, per_page, page))
+                .await?;
+            let batch = parse_rest_runs(&json)?;
+            let done = batch.len() < per_page;
+            runs.extend(batch);
+            if done || runs.len() >= limit {
+                break;
+            }
+        }
+        runs.truncate(limit);
+        Ok(serde_json::to_string(&runs)?)
+    }
+
+    async fn fetch_run(&self, run_id: u64) -> Result<String> {
+        let json = self
+            .get(&format!("repos/{}/actions/runs/{run_id}", self.repo))
+            .await?;
+        Ok(serde_json::to_string(&[parse_rest_run(&json)?])?)
+    }
+
+    async fn fetch_jobs(&self, run_id: u64) -> Result<String> {
+        let jobs = self.fetch_all_jobs(run_id).await?;
+        Ok(serde_json::json!({ "jobs": jobs }).to_string())
+    }
+
+    async fn cancel_run(&self, run_id: u64) -> Result<()> {
+        self.post(
+            &format!("repos/{}/actions/runs/{run_id}/cancel", self.repo),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_run(&self, run_id: u64) -> Result<()> {
+        self.request(
+            reqwest::Method::DELETE,
+            &format!("repos/{}/actions/runs/{run_id}", self.repo),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn rerun_failed(&self, run_id: u64) -> Result<()> {
+        self.post(
+            &format!(
+                "repos/{}/actions/runs/{run_id}/rerun-failed-jobs",
+                self.repo
+            ),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Same flow as `GhExecutor::approve`.
+    async fn approve(&self, run_id: u64) -> Result<()> {
+        let endpoint = format!(
+            "repos/{}/actions/runs/{run_id}/pending_deployments",
+            self.repo
+        );
+        let pending = self.get(&endpoint).await?;
+        let env_ids = executor::parse_pending_environment_ids(&pending)?;
+        if env_ids.is_empty() {
+            self.post(
+                &format!("repos/{}/actions/runs/{run_id}/approve", self.repo),
+                None,
+            )
+            .await?;
+            return Ok(());
+        }
+        let body = serde_json::json!({
+            "environment_ids": env_ids,
+            "state": "approved",
+            "comment": "Approved from ghw",
+        });
+        self.post(&endpoint, Some(body)).await?;
+        Ok(())
+    }
+
+    async fn dispatch_workflow(&self, workflow: &str, branch: &str) -> Result<()> {
+        let id = self.workflow_id(workflow).await?;
+        self.post(
+            &format!("repos/{}/actions/workflows/{id}/dispatches", self.repo),
+            Some(serde_json::json!({ "ref": branch })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn current_user(&self) -> Result<String> {
+        let json = self.get("user").await?;
+        executor::parse_login(&json)
+    }
+
+    async fn rate_limit(&self) -> Result<RateLimit> {
+        let json = self.get("rate_limit").await?;
+        executor::parse_rate_limit(&json)
+    }
+
+    fn supports_rate_limit(&self) -> bool {
+        true
+    }
+
+    /// REST has no `--log-failed`: fetches each failed job's full log, prefixed
+    /// with the job name like `gh run view --log-failed` does.
+    async fn fetch_failed_logs(&self, run_id: u64) -> Result<String> {
+        let jobs = self.fetch_all_jobs(run_id).await?;
+        let mut out = String::new();
+        for job in jobs
+            .iter()
+            .filter(|j| j.conclusion.is_some_and(Conclusion::is_failure))
+        {
+            let Some(job_id) = job.database_id else {
+                continue;
+            };
+            let log = self
+                .get_log(&format!("repos/{}/actions/jobs/{job_id}/logs", self.repo))
+                .await?;
+            for line in log.lines() {
+                out.push_str(&job.name);
+                out.push('\t');
+                out.push_str(line);
+                out.push('\n');
+            }
+            executor::check_log_size(&out)?;
+        }
+        Ok(out)
+    }
+
+    /// The whole job log — the REST API can't narrow it to failed steps.
+    async fn fetch_failed_logs_for_job(&self, _run_id: u64, job_id: u64) -> Result<String> {
+        let log = self
+            .get_log(&format!("repos/{}/actions/jobs/{job_id}/logs", self.repo))
+            .await?;
+        executor::check_log_size(&log)?;
+        Ok(log)
+    }
+
+    fn open_in_browser(&self, url: &str) -> Result<()> {
+        executor::open_in_browser_impl(url)
+    }
+
+    async fn copy_to_clipboard(&self, text: &str) -> Result<()> {
+        executor::copy_to_clipboard_impl(text).await
+    }
+}
+
+/// reqwest's `Display` stops at "error sending request"; the cause (DNS, TLS,
+/// refused connection) is in the source chain.
+fn error_chain(e: &dyn std::error::Error) -> String {
+    let mut msg = e.to_string();
+    let mut source = e.source();
+    while let Some(cause) = source {
+        msg = format!("{msg}: {cause}");
+        source = cause.source();
+    }
+    msg
+}
+
+fn api_url(base: &str, path: &str) -> String {
+    format!("{base}/{}", path.trim_start_matches('/'))
+}
+
+fn runs_path(
+    repo: &str,
+    workflow_id: Option<u64>,
+    branch: Option<&str>,
+    per_page: usize,
+    page: usize,
+) -> String {
+    let mut path = match workflow_id {
+        Some(id) => {
+            format!("repos/{repo}/actions/workflows/{id}/runs?per_page={per_page}&page={page}")
+        }
+        None => format!("repos/{repo}/actions/runs?per_page={per_page}&page={page}"),
+    };
+    if let Some(b) = branch {
+        path.push_str("&branch=");
+        path.push_str(&traits::query_escape(b));
+    }
+    path
+}
+
+fn jobs_path(repo: &str, run_id: u64, page: usize) -> String {
+    format!("repos/{repo}/actions/runs/{run_id}/jobs?per_page={MAX_PER_PAGE}&page={page}")
+}
+
+/// GitHub Enterprise Server serves REST under `/api/v3` on the web host.
+fn enterprise_api_base(host: &str) -> String {
+    format!("https://{host}/api/v3")
+}
+
+/// `owner/repo` from an HTTPS, SSH, or scp-style GitHub remote.
+fn repo_from_remote_url(url: &str) -> Option<String> {
+    let path = if let Some(rest) = url.strip_prefix("git@") {
+        rest.split_once(':')?.1
+    } else {
+        let after_scheme = url.split_once("://")?.1;
+        after_scheme.split_once('/')?.1
+    };
+    let path = path.trim_end_matches('/').trim_end_matches(".git");
+    let (owner, name) = path.split_once('/')?;
+    (!owner.is_empty() && !name.is_empty() && !name.contains('/'))
+        .then(|| format!("{owner}/{name}"))
+}
+
+/// Matches a workflow by display name, file name, or path.
+fn find_workflow_id(json: &str, workflow: &str) -> Option<u64> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    parsed["workflows"].as_array()?.iter().find_map(|w| {
+        let name = w["name"].as_str().unwrap_or_default();
+        let path = w["path"].as_str().unwrap_or_default();
+        let file = path.rsplit('/').next().unwrap_or_default();
+        (name == workflow || path == workflow || file == workflow)
+            .then(|| w["id"].as_u64())
+            .flatten()
+    })
+}
+
+/// Maps an API failure to the toast text. Rate limits keep the
+/// [`ciw_core::poller::RATE_LIMITED`] prefix so the poller backs off.
+fn classify_http_error(status: u16, ratelimit_remaining: Option<&str>, body: &str) -> String {
+    let message = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| v["message"].as_str().map(str::to_string))
+        .unwrap_or_else(|| body.trim().to_string());
+    let rate_limited = status == 429
+        || (status == 403
+            && (ratelimit_remaining == Some("0")
+                || message.to_ascii_lowercase().contains("rate limit")));
+    if rate_limited {
+        format!(
+            "{}: HTTP {status}: {message}",
+            ciw_core::poller::RATE_LIMITED
+        )
+    } else if status == 401 {
+        "GitHub rejected the token (HTTP 401). Check GH_TOKEN / GITHUB_TOKEN.".to_string()
+    } else if status == 403 {
+        format!("Forbidden (HTTP 403): {message}. The token may lack the needed scope.")
+    } else if status == 404 {
+        "Not found (HTTP 404). Check --repo and that the token can access it.".to_string()
+    } else if message.is_empty() {
+        format!("GitHub API error (HTTP {status})")
+    } else {
+        format!("GitHub API error (HTTP {status}): {message}")
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct RestRuns {
+    workflow_runs: Vec<RestRun>,
+}
+
+#[derive(serde::Deserialize)]
+struct RestRun {
+    id: u64,
+    #[serde(default)]
+    display_title: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    head_branch: Option<String>,
+    status: RunStatus,
+    conclusion: Option<Conclusion>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    event: String,
+    run_number: u64,
+    html_url: String,
+    #[serde(default)]
+    head_sha: String,
+    #[serde(default = "first_attempt")]
+    run_attempt: u64,
+    #[serde(default)]
+    head_commit: Option<RestCommit>,
+    #[serde(default)]
+    pull_requests: Vec<RestPr>,
+    #[serde(default)]
+    path: Option<String>,
+    #[serde(default)]
+    actor: Option<RestActor>,
+}
+
+fn first_attempt() -> u64 {
+    1
+}
+
+#[derive(serde::Deserialize)]
+struct RestCommit {
+    message: String,
+}
+
+#[derive(serde::Deserialize)]
+struct RestPr {
+    number: u64,
+}
+
+#[derive(serde::Deserialize)]
+struct RestActor {
+    login: String,
+}
+
+#[derive(serde::Deserialize)]
+struct RestJobs {
+    total_count: u64,
+    jobs: Vec<RestJob>,
+}
+
+#[derive(serde::Deserialize)]
+struct RestJob {
+    id: u64,
+    name: String,
+    status: RunStatus,
+    conclusion: Option<Conclusion>,
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    html_url: Option<String>,
+    #[serde(default)]
+    steps: Vec<RestStep>,
+    #[serde(default)]
+    runner_name: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct RestStep {
+    name: String,
+    status: RunStatus,
+    conclusion: Option<Conclusion>,
+    number: u64,
+    #[serde(default)]
+    started_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    completed_at: Option<DateTime<Utc>>,
+}
+
+fn parse_rest_runs(json: &str) -> Result<Vec<WorkflowRun>> {
+    let resp: RestRuns =
+        serde_json::from_str(json).map_err(|e| eyre!("Failed to parse workflow runs: {e}"))?;
+    Ok(resp
+        .workflow_runs
+        .into_iter()
+        .map(WorkflowRun::from)
+        .collect())
+}
+
+/// `GET /actions/runs/{id}` returns the run object without the list wrapper.
+fn parse_rest_run(json: &str) -> Result<WorkflowRun> {
+    let run: RestRun =
+        serde_json::from_str(json).map_err(|e| eyre!("Failed to parse workflow run: {e}"))?;
+    Ok(run.into())
+}
+
+impl From<RestRun> for WorkflowRun {
+    fn from(r: RestRun) -> Self {
+        WorkflowRun {
+            database_id: r.id,
+            display_title: r.display_title,
+            name: r.name.unwrap_or_default(),
+            head_branch: r.head_branch.unwrap_or_default(),
+            status: r.status,
+            conclusion: r.conclusion,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+            event: r.event,
+            number: r.run_number,
+            url: r.html_url,
+            head_sha: r.head_sha,
+            head_commit_message: r.head_commit.map(|c| c.message),
+            workflow_path: r.path,
+            pr_number: r.pull_requests.first().map(|pr| pr.number),
+            attempt: r.run_attempt,
+            required: None,
+            actor: r.actor.map(|a| a.login),
+            timestamps_unknown: false,
+            jobs: None,
+        }
+    }
+}
+
+/// Returns one page of jobs plus the run's `total_count` for pagination.
+fn parse_rest_jobs(json: &str) -> Result<(Vec<Job>, u64)> {
+    let resp: RestJobs =
+        serde_json::from_str(json).map_err(|e| eyre!("Failed to parse jobs: {e}"))?;
+    let jobs = resp
+        .jobs
+        .into_iter()
+        .map(|j| Job {
+            database_id: Some(j.id),
+            name: j.name,
+            status: j.status,
+            conclusion: j.conclusion,
+            started_at: j.started_at,
+            completed_at: j.completed_at,
+            url: j.html_url.unwrap_or_default(),
+            steps: j
+                .steps
+                .into_iter()
+                .map(|s| Step {
+                    name: s.name,
+                    status: s.status,
+                    conclusion: s.conclusion,
+                    number: s.number,
+                    started_at: s.started_at,
+                    completed_at: s.completed_at,
+                })
+                .collect(),
+            stage: None,
+            // The REST API reports an empty name until a runner is assigned
+            runner_name: j.runner_name.filter(|n| !n.is_empty()),
+            labels: j.labels,
+        })
+        .collect();
+    Ok((jobs, resp.total_count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::GhParser;
+    use ciw_core::traits::CiParser;
+
+    #[test]
+    fn urls_built_from_base_and_path() {
+        assert_eq!(
+            api_url("https://api.github.com", "/rate_limit"),
+            "https://api.github.com/rate_limit"
+        );
+        assert_eq!(
+            runs_path("o/r", None, None, 20, 1),
+            "repos/o/r/actions/runs?per_page=20&page=1"
+        );
+        assert_eq!(
+            runs_path("o/r", Some(42), None, 100, 3),
+            "repos/o/r/actions/workflows/42/runs?per_page=100&page=3"
+        );
+        assert_eq!(
+            runs_path("o/r", None, Some("feat/x"), 20, 1),
+            "repos/o/r/actions/runs?per_page=20&page=1&branch=feat%2Fx"
+        );
+        assert_eq!(
+            jobs_path("o/r", 7, 2),
+            "repos/o/r/actions/runs/7/jobs?per_page=100&page=2"
+        );
+    }
+
+    #[test]
+    fn enterprise_host_maps_to_api_v3() {
+        assert_eq!(
+            api_url(&enterprise_api_base("ghe.example.com"), "/rate_limit"),
+            "https://ghe.example.com/api/v3/rate_limit"
+        );
+    }
+
+    #[test]
+    fn repo_from_remote_url_variants() {
+        for url in [
+            "https://github.com/owner/repo.git",
+            "https://github.com/owner/repo",
+            "git@github.com:owner/repo.git",
+            "ssh://git@github.com/owner/repo.git",
+        ] {
+            assert_eq!(
+                repo_from_remote_url(url).as_deref(),
+                Some("owner/repo"),
+                "{url}"
+            );
+        }
+        assert_eq!(repo_from_remote_url("https://github.com/owner"), None);
+        assert_eq!(repo_from_remote_url("not a url"), None);
+    }
+
+    #[test]
+    fn workflow_matched_by_name_or_file() {
+        let json = r#"{"total_count": 2, "workflows": [
+            {"id": 1, "name": "CI", "path": ".github/workflows/ci.yml"},
+            {"id": 2, "name": "Deploy", "path": ".github/workflows/deploy.yml"}
+        ]}"#;
+        assert_eq!(find_workflow_id(json, "Deploy"), Some(2));
+        assert_eq!(find_workflow_id(json, "ci.yml"), Some(1));
+        assert_eq!(find_workflow_id(json, "Nightly"), None);
+    }
+
+    #[test]
+    fn classify_rate_limits() {
+        for (status, remaining, body) in [
+            (
+                403,
+                Some("0"),
+                r#"{"message": "API rate limit exceeded for user"}"#,
+            ),
+            (
+                403,
+                None,
+                r#"{"message": "You have exceeded a secondary rate limit"}"#,
+            ),
+            (429, None, ""),
+        ] {
+            let msg = classify_http_error(status, remaining, body);
+            assert!(ciw_core::poller::is_rate_limited(&msg), "{msg}");
+        }
+    }
+
+    #[test]
+    fn classify_auth_and_missing() {
+        assert!(
+            classify_http_error(401, None, r#"{"message": "Bad credentials"}"#)
+                .contains("GH_TOKEN")
+        );
+        let forbidden = classify_http_error(
+            403,
+            Some("4999"),
+            r#"{"message": "Resource not accessible"}"#,
+        );
+        assert!(forbidden.starts_with("Forbidden"));
+        assert!(!ciw_core::poller::is_rate_limited(&forbidden));
+        assert!(classify_http_error(404, None, "").contains("--repo"));
+        assert_eq!(
+            classify_http_error(500, None, "oops"),
+            "GitHub API error (HTTP 500): oops"
+        );
+    }
+
+    const REST_RUNS: &str = r#"{
+        "total_count": 1,
+        "workflow_runs": [{
+            "id": 123,
+            "name": "CI",
+            "display_title": "Fix the thing",
+            "head_branch": "main",
+            "head_sha": "abc123",
+            "run_number": 45,
+            "run_attempt": 2,
+            "event": "pull_request",
+            "status": "completed",
+            "conclusion": "failure",
+            "url": "https://api.github.com/repos/o/r/actions/runs/123",
+            "html_url": "https://github.com/o/r/actions/runs/123",
+            "created_at": "2026-01-01T10:00:00Z",
+            "updated_at": "2026-01-01T10:05:00Z",
+            "pull_requests": [{"number": 9}],
+            "head_commit": {"message": "Fix the thing\n\nDetails"},
+            "path": ".github/workflows/ci.yml",
+            "actor": {"login": "octocat", "id": 1}
+        }]
+    }"#;
+
+    #[test]
+    fn rest_single_run_matches_list_entry() {
+        let list: serde_json::Value = serde_json::from_str(REST_RUNS).unwrap();
+        let single = list["workflow_runs"][0].to_string();
+        let run = parse_rest_run(&single).unwrap();
+        let listed = &parse_rest_runs(REST_RUNS).unwrap()[0];
+        assert_eq!(run.database_id, listed.database_id);
+        assert_eq!(run.number, 45);
+        assert_eq!(run.pr_number, Some(9));
+        assert!(
+            parse_rest_run(REST_RUNS).is_err(),
+            "list wrapper is not a run"
+        );
+    }
+
+    #[test]
+    fn rest_runs_round_trip_through_gh_parser() {
+        let runs = parse_rest_runs(REST_RUNS).unwrap();
+        let gh_json = serde_json::to_string(&runs).unwrap();
+        let parsed = GhParser.parse_runs(&gh_json).unwrap();
+        let run = &parsed[0];
+        assert_eq!(run.database_id, 123);
+        assert_eq!(run.number, 45);
+        assert_eq!(run.attempt, 2);
+        assert_eq!(run.url, "https://github.com/o/r/actions/runs/123");
+        assert_eq!(run.pr_number, Some(9));
+        assert_eq!(run.conclusion, Some(Conclusion::Failure));
+        assert_eq!(
+            run.head_commit_message.as_deref(),
+            Some("Fix the thing\n\nDetails")
+        );
+        assert_eq!(
+            run.workflow_path.as_deref(),
+            Some(".github/workflows/ci.yml")
+        );
+        assert_eq!(run.actor.as_deref(), Some("octocat"));
+    }
+
+    #[test]
+    fn rest_jobs_round_trip_through_gh_parser() {
+        let json = r#"{
+            "total_count": 1,
+            "jobs": [{
+                "id": 99,
+                "name": "build",
+                "status": "completed",
+                "conclusion": "success",
+                "started_at": "2026-01-01T10:00:00Z",
+                "completed_at": "2026-01-01T10:02:00Z",
+                "html_url": "https://github.com/o/r/actions/runs/123/job/99",
+                "steps": [{"name": "Checkout", "status": "completed", "conclusion": "success",
+                           "number": 1, "started_at": "2026-01-01T10:00:00Z",
+                           "completed_at": "2026-01-01T10:00:05Z"}],
+                "runner_name": "GitHub Actions 7",
+                "labels": ["ubuntu-latest"]
+            }]
+        }"#;
+        let (jobs, total) = parse_rest_jobs(json).unwrap();
+        assert_eq!(total, 1);
+        let gh_json = serde_json::json!({ "jobs": jobs }).to_string();
+        let parsed = GhParser.parse_jobs(&gh_json).unwrap();
+        assert_eq!(parsed[0].database_id, Some(99));
+        assert_eq!(parsed[0].steps.len(), 1);
+        assert_eq!(parsed[0].runner_name.as_deref(), Some("GitHub Actions 7"));
+        assert_eq!(parsed[0].labels, vec!["ubuntu-latest"]);
+        assert_eq!(
+            parsed[0].url,
+            "https://github.com/o/r/actions/runs/123/job/99"
+        );
+    }
+}
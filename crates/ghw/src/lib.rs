@@ -18,6 +18,7 @@
 
 pub mod cli;
 pub mod executor;
+pub mod http;
 pub mod parser;
 
 // Re-export ciw_core modules for backward compatibility with tests
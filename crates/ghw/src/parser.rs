@@ -1,5 +1,5 @@
-use ciw_core::app::{Job, WorkflowRun};
-use ciw_core::traits::CiParser;
+use ciw_core::app::{Job, RunStatus, WorkflowRun};
+use ciw_core::traits::{self, CiParser};
 use color_eyre::eyre::{eyre, Result};
 
 const MAX_RESPONSE_SIZE: usize = 10 * 1024 * 1024; // 10 MB
@@ -21,22 +21,46 @@ impl CiParser for GhParser {
     fn parse_runs(&self, json: &str) -> Result<Vec<WorkflowRun>> {
         check_response_size(json)?;
         let runs: Vec<WorkflowRun> = serde_json::from_str(json)?;
+        if runs.iter().any(|r| r.status == RunStatus::Unknown) {
+            log_unknown_statuses(json, None);
+        }
         Ok(runs)
     }
 
     fn parse_jobs(&self, json: &str) -> Result<Vec<Job>> {
         check_response_size(json)?;
         let resp: JobsResponse = serde_json::from_str(json)?;
+        if resp.jobs.iter().any(|j| j.status == RunStatus::Unknown) {
+            log_unknown_statuses(json, Some("jobs"));
+        }
         Ok(resp.jobs)
     }
 
     fn process_log_output(&self, raw: &str, max_lines: usize) -> (String, bool) {
-        let lines: Vec<&str> = raw.lines().collect();
-        if lines.len() > max_lines {
-            let truncated = &lines[lines.len() - max_lines..];
-            (truncated.join("\n"), true)
-        } else {
-            (raw.to_string(), false)
+        traits::tail_log(raw, max_lines)
+    }
+}
+
+/// `#[serde(other)]` drops the raw string, so re-read it for the debug log —
+/// that's how new GitHub statuses get noticed and added to `RunStatus`.
+fn log_unknown_statuses(json: &str, key: Option<&str>) {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(json) else {
+        return;
+    };
+    let items = match key {
+        Some(key) => value.get(key),
+        None => Some(&value),
+    };
+    for status in items
+        .and_then(serde_json::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|item| item.get("status")?.as_str())
+    {
+        let parsed: Result<RunStatus, _> =
+            serde_json::from_value(serde_json::Value::String(status.to_string()));
+        if matches!(parsed, Ok(RunStatus::Unknown)) {
+            tracing::debug!("unrecognized status {status:?}");
         }
     }
 }
@@ -47,6 +71,8 @@ struct JobsResponse {
 }
 
 #[cfg(test)]
+// Positional format args predate the lint; left as written.
+#[allow(clippy::uninlined_format_args)]
 mod tests {
     use super::*;
     use ciw_core::app::{Conclusion, RunStatus};
@@ -88,6 +114,131 @@ mod tests {
         assert_eq!(run.url, "https://github.com/test/repo/actions/runs/123");
     }
 
+    #[test]
+    fn parse_head_sha() {
+        let json = r#"[{
+            "databaseId": 1, "displayTitle": "t", "name": "n",
+            "headBranch": "main", "status": "completed", "conclusion": "success",
+            "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z",
+            "event": "push", "number": 1, "url": "u",
+            "headSha": "0123456789abcdef0123456789abcdef01234567"
+        }]"#;
+        let runs = parser().parse_runs(json).unwrap();
+        assert_eq!(runs[0].head_sha, "0123456789abcdef0123456789abcdef01234567");
+        assert_eq!(runs[0].short_sha(), "0123456");
+        assert_eq!(runs[0].head_commit_message, None);
+        assert_eq!(runs[0].workflow_path, None);
+    }
+
+    #[test]
+    fn parse_workflow_path() {
+        let json = r#"[{
+            "databaseId": 1, "displayTitle": "t", "name": "n",
+            "headBranch": "main", "status": "completed", "conclusion": "success",
+            "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z",
+            "event": "push", "number": 1, "url": "u",
+            "workflowPath": ".github/workflows/release.yml"
+        }]"#;
+        let runs = parser().parse_runs(json).unwrap();
+        assert_eq!(
+            runs[0].workflow_path.as_deref(),
+            Some(".github/workflows/release.yml")
+        );
+
+        let rest_alias = json.replace("workflowPath", "path");
+        let runs = parser().parse_runs(&rest_alias).unwrap();
+        assert_eq!(
+            runs[0].workflow_path.as_deref(),
+            Some(".github/workflows/release.yml")
+        );
+    }
+
+    #[test]
+    fn parse_without_head_sha_defaults_empty() {
+        let runs = parser().parse_runs(SINGLE_RUN_JSON).unwrap();
+        assert_eq!(runs[0].head_sha, "");
+        assert_eq!(runs[0].short_sha(), "");
+    }
+
+    #[test]
+    fn parse_run_attempt() {
+        let json = r#"[{
+            "databaseId": 1, "displayTitle": "t", "name": "n",
+            "headBranch": "main", "status": "completed", "conclusion": "success",
+            "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z",
+            "event": "push", "number": 1, "url": "u", "runAttempt": 3
+        }]"#;
+        assert_eq!(parser().parse_runs(json).unwrap()[0].attempt, 3);
+
+        let json = json.replace("runAttempt", "attempt");
+        assert_eq!(parser().parse_runs(&json).unwrap()[0].attempt, 3);
+    }
+
+    #[test]
+    fn parse_required_check_defaults_to_unknown() {
+        let runs = parser().parse_runs(SINGLE_RUN_JSON).unwrap();
+        assert_eq!(runs[0].required, None);
+
+        let json = r#"[{
+            "databaseId": 1, "displayTitle": "t", "name": "n",
+            "headBranch": "main", "status": "completed", "conclusion": "failure",
+            "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z",
+            "event": "pull_request", "number": 1, "url": "u", "required": true
+        }]"#;
+        assert_eq!(parser().parse_runs(json).unwrap()[0].required, Some(true));
+    }
+
+    #[test]
+    fn parse_actor_login_when_present() {
+        let runs = parser().parse_runs(SINGLE_RUN_JSON).unwrap();
+        assert_eq!(runs[0].actor, None);
+
+        let json = r#"[{
+            "databaseId": 1, "displayTitle": "t", "name": "n",
+            "headBranch": "main", "status": "completed", "conclusion": "success",
+            "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z",
+            "event": "push", "number": 1, "url": "u", "actor": {"login": "octocat"}
+        }]"#;
+        assert_eq!(
+            parser().parse_runs(json).unwrap()[0].actor.as_deref(),
+            Some("octocat")
+        );
+    }
+
+    #[test]
+    fn parse_without_attempt_defaults_to_first() {
+        let runs = parser().parse_runs(SINGLE_RUN_JSON).unwrap();
+        assert_eq!(runs[0].attempt, 1);
+    }
+
+    #[test]
+    fn parse_pull_request_number() {
+        let json = r#"[{
+            "databaseId": 1, "displayTitle": "t", "name": "n",
+            "headBranch": "feat", "status": "completed", "conclusion": "success",
+            "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z",
+            "event": "pull_request", "number": 1, "url": "u",
+            "pull_requests": [{"number": 88}, {"number": 90}]
+        }]"#;
+        let runs = parser().parse_runs(json).unwrap();
+        assert_eq!(runs[0].pr_number, Some(88));
+    }
+
+    #[test]
+    fn parse_without_pull_request() {
+        let runs = parser().parse_runs(SINGLE_RUN_JSON).unwrap();
+        assert_eq!(runs[0].pr_number, None);
+
+        let json = r#"[{
+            "databaseId": 1, "displayTitle": "t", "name": "n",
+            "headBranch": "main", "status": "completed", "conclusion": "success",
+            "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z",
+            "event": "push", "number": 1, "url": "u", "pullRequests": []
+        }]"#;
+        let runs = parser().parse_runs(json).unwrap();
+        assert_eq!(runs[0].pr_number, None);
+    }
+
     #[test]
     fn parse_in_progress_with_null_conclusion() {
         let json = r#"[{
@@ -256,6 +407,28 @@ mod tests {
         assert_eq!(jobs[0].steps[1].conclusion, Some(Conclusion::Failure));
     }
 
+    #[test]
+    fn gh_jobs_json_has_no_runner_or_labels() {
+        // Shape of `gh run view --json jobs`; the runner only comes via REST
+        // (see `http::tests::rest_jobs_round_trip_through_gh_parser`)
+        let json = r#"{"jobs":[{
+            "completedAt": "2026-01-01T10:02:00Z",
+            "conclusion": "success",
+            "databaseId": 99,
+            "name": "build",
+            "startedAt": "2026-01-01T10:00:00Z",
+            "status": "completed",
+            "steps": [{"completedAt": "2026-01-01T10:00:05Z", "conclusion": "success",
+                       "name": "Checkout", "number": 1,
+                       "startedAt": "2026-01-01T10:00:00Z", "status": "completed"}],
+            "url": "https://github.com/o/r/actions/runs/123/job/99"
+        }]}"#;
+        let jobs = parser().parse_jobs(json).unwrap();
+        assert_eq!(jobs[0].database_id, Some(99));
+        assert_eq!(jobs[0].runner_name, None);
+        assert!(jobs[0].labels.is_empty());
+    }
+
     #[test]
     fn parse_jobs_empty() {
         let json = r#"{"jobs":[]}"#;
@@ -328,6 +501,21 @@ mod tests {
         assert!(!truncated);
     }
 
+    #[test]
+    fn process_log_output_flags_invalid_utf8() {
+        // What the executor hands over: stdout decoded lossily
+        let bytes = b"build\tRun\tok\nbuild\tRun\tbad \xff\xfe bytes\n";
+        let raw = String::from_utf8_lossy(bytes);
+        let (text, truncated) = parser().process_log_output(&raw, 10);
+        assert!(!truncated);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], ciw_core::traits::INVALID_UTF8_NOTE);
+        assert_eq!(lines[2], "build\tRun\tbad \u{FFFD}\u{FFFD} bytes");
+
+        let (clean, _) = parser().process_log_output("all fine", 10);
+        assert_eq!(clean, "all fine");
+    }
+
     #[test]
     fn process_log_output_truncates() {
         let p = parser();
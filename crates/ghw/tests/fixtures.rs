@@ -9,18 +9,9 @@ pub fn default_run() -> WorkflowRun {
 
 pub fn run_with_id(id: u64) -> WorkflowRun {
     WorkflowRun {
-        database_id: id,
         display_title: format!("CI Build #{}", id),
-        name: "CI".to_string(),
-        head_branch: "main".to_string(),
-        status: RunStatus::Completed,
-        conclusion: Some(Conclusion::Success),
-        created_at: Utc::now(),
-        updated_at: Utc::now(),
-        event: "push".to_string(),
-        number: id,
         url: format!("https://github.com/test/repo/actions/runs/{}", id),
-        jobs: None,
+        ..ciw_core::mock::run(id, RunStatus::Completed, Some(Conclusion::Success))
     }
 }
 
@@ -47,13 +38,11 @@ pub fn run_on_branch(id: u64, branch: &str) -> WorkflowRun {
 pub fn default_job() -> Job {
     Job {
         database_id: Some(1),
-        name: "build".to_string(),
-        status: RunStatus::Completed,
-        conclusion: Some(Conclusion::Success),
         started_at: Some(Utc::now()),
         completed_at: Some(Utc::now()),
         url: "https://github.com/test/repo/actions/runs/1/jobs/1".to_string(),
         steps: vec![default_step()],
+        ..ciw_core::mock::job("build", RunStatus::Completed, Some(Conclusion::Success))
     }
 }
 
@@ -74,3 +63,11 @@ pub fn make_state_with_runs(runs: Vec<WorkflowRun>) -> AppState {
     state.rebuild_tree();
     state
 }
+
+/// `main` sizes the pool from `--max-concurrent`; tests take the default.
+pub fn gh_executor(repo: &str) -> ghw::executor::GhExecutor {
+    ghw::executor::GhExecutor::with_concurrency(
+        repo.to_string(),
+        ciw_core::traits::DEFAULT_MAX_CONCURRENT,
+    )
+}
@@ -215,6 +215,7 @@ fn input_to_state_action_flow() {
     // Cycle again to get back to All
     state.cycle_filter();
     state.cycle_filter();
+    state.cycle_filter();
     assert_eq!(state.filter, FilterMode::All);
     assert_eq!(state.tree_items.len(), 3);
 }
@@ -227,11 +228,6 @@ fn log_overlay_lifecycle() {
     let mut run = run_failed(1);
     let job = Job {
         database_id: Some(10),
-        name: "build".to_string(),
-        status: RunStatus::Completed,
-        conclusion: Some(Conclusion::Failure),
-        started_at: None,
-        completed_at: None,
         url: "https://example.com".to_string(),
         steps: vec![Step {
             name: "Test".to_string(),
@@ -241,6 +237,7 @@ fn log_overlay_lifecycle() {
             started_at: None,
             completed_at: None,
         }],
+        ..ciw_core::mock::job("build", RunStatus::Completed, Some(Conclusion::Failure))
     };
     run.jobs = Some(vec![job]);
 
@@ -421,6 +418,66 @@ fn tui_header_contains_repo_name() {
     );
 }
 
+#[test]
+fn tui_header_shows_paused_indicator() {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let mut state = make_state_with_runs(vec![run_with_id(1)]);
+    state.toggle_pause();
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|f| {
+            ciw_core::tui::render::render(f, &state);
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer().clone();
+    let text: String = (0..buffer.area.width)
+        .map(|x| buffer.cell((x, 0)).unwrap().symbol().to_string())
+        .collect();
+    assert!(
+        text.contains("PAUSED"),
+        "Header should show PAUSED indicator, got: {text}"
+    );
+}
+
+#[test]
+fn tui_run_line_shows_attempt_badge_for_reruns() {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let mut rerun = run_with_id(1);
+    rerun.attempt = 3;
+    let state = make_state_with_runs(vec![rerun, run_with_id(2)]);
+    let backend = TestBackend::new(100, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|f| {
+            ciw_core::tui::render::render(f, &state);
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer().clone();
+    let rows: Vec<String> = (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer.cell((x, y)).unwrap().symbol().to_string())
+                .collect()
+        })
+        .collect();
+    let run1 = rows.iter().find(|r| r.contains("#1 ")).unwrap();
+    let run2 = rows.iter().find(|r| r.contains("#2 ")).unwrap();
+    assert!(
+        run1.contains("↻3"),
+        "rerun should show attempt badge: {run1}"
+    );
+    assert!(!run2.contains('↻'), "first attempt has no badge: {run2}");
+}
+
 #[test]
 fn tui_footer_contains_key_hints() {
     use ratatui::backend::TestBackend;
@@ -482,6 +539,42 @@ fn tui_tree_renders_run_titles() {
     );
 }
 
+#[test]
+fn tui_tree_shows_more_below_marker_on_overflow() {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let runs = (1..=10).map(run_with_id).collect();
+    let state = make_state_with_runs(runs);
+    let mut terminal = Terminal::new(TestBackend::new(80, 8)).unwrap();
+
+    terminal
+        .draw(|f| {
+            ciw_core::tui::render::render(f, &state);
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer().clone();
+    let screen: String = (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer.cell((x, y)).unwrap().symbol().to_string())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    // 10 runs, 4 tree rows on an 8-row terminal
+    assert!(
+        screen.contains("↓6 more"),
+        "Overflowing tree should show a 'more below' marker, got:\n{screen}"
+    );
+    assert_eq!(
+        screen.matches(" more").count(),
+        1,
+        "cursor at top, nothing hidden above:\n{screen}"
+    );
+}
+
 #[test]
 fn tui_empty_state_shows_no_runs_message() {
     use ratatui::backend::TestBackend;
@@ -510,13 +603,63 @@ fn tui_empty_state_shows_no_runs_message() {
     );
 }
 
+// ========== Poller tests (mock executor) ==========
+
+#[tokio::test]
+async fn poller_feeds_mock_gh_output_into_state() {
+    use ciw_core::events::AppEvent;
+    use ciw_core::mock::MockExecutor;
+    use ciw_core::poller::{Poller, POLL_PAUSED};
+    use std::sync::Arc;
+    use tokio::sync::{mpsc, watch};
+
+    let json = r#"[{
+        "databaseId": 200,
+        "displayTitle": "Mocked Run",
+        "name": "CI",
+        "headBranch": "main",
+        "status": "in_progress",
+        "conclusion": null,
+        "createdAt": "2024-06-01T10:00:00Z",
+        "updatedAt": "2024-06-01T10:01:00Z",
+        "event": "push",
+        "number": 60,
+        "url": "https://github.com/test/repo/actions/runs/200"
+    }]"#;
+    let executor = MockExecutor::new().with_response("fetch_runs", json);
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let (_filter_tx, filter_rx) = watch::channel(None);
+    let (_interval_tx, interval_rx) = watch::channel(POLL_PAUSED);
+    let poller = Poller::new(
+        Arc::new(executor),
+        Arc::new(parser()),
+        10,
+        filter_rx,
+        tx,
+        interval_rx,
+    );
+    let handle = tokio::spawn(poller.run());
+
+    let Some(AppEvent::PollResult { runs, manual }) = rx.recv().await else {
+        panic!("expected PollResult");
+    };
+    handle.abort();
+    assert!(!manual);
+
+    let mut state = AppState::new("test/repo".to_string(), None, 10, None);
+    state.update_runs(runs);
+    assert_eq!(state.runs.len(), 1);
+    assert_eq!(state.runs[0].display_title, "Mocked Run");
+    assert_eq!(state.runs[0].status, RunStatus::InProgress);
+}
+
 // ========== Live gh CLI tests (ignored by default) ==========
 
 #[tokio::test]
 #[ignore]
 async fn gh_check_available() {
     use ciw_core::traits::CiExecutor;
-    let executor = ghw::executor::GhExecutor::new(String::new());
+    let executor = gh_executor("");
     executor
         .check_available()
         .await
@@ -527,10 +670,10 @@ async fn gh_check_available() {
 #[ignore]
 async fn gh_fetch_runs_from_public_repo() {
     use ciw_core::traits::CiExecutor;
-    let executor = ghw::executor::GhExecutor::new("cli/cli".to_string());
+    let executor = gh_executor("cli/cli");
     let p = parser();
     let json = executor
-        .fetch_runs(5, None)
+        .fetch_runs(5, None, None)
         .await
         .expect("should fetch runs from cli/cli");
     let runs = p.parse_runs(&json).expect("should parse runs");
@@ -547,10 +690,10 @@ async fn gh_fetch_runs_from_public_repo() {
 #[ignore]
 async fn gh_fetch_jobs_from_public_repo() {
     use ciw_core::traits::CiExecutor;
-    let executor = ghw::executor::GhExecutor::new("cli/cli".to_string());
+    let executor = gh_executor("cli/cli");
     let p = parser();
     let json = executor
-        .fetch_runs(1, None)
+        .fetch_runs(1, None, None)
         .await
         .expect("should fetch runs");
     let runs = p.parse_runs(&json).expect("should parse");
@@ -570,9 +713,12 @@ async fn gh_fetch_jobs_from_public_repo() {
 #[ignore]
 async fn gh_full_pipeline_fetch_parse_state() {
     use ciw_core::traits::CiExecutor;
-    let executor = ghw::executor::GhExecutor::new("cli/cli".to_string());
+    let executor = gh_executor("cli/cli");
     let p = parser();
-    let json = executor.fetch_runs(5, None).await.expect("fetch runs");
+    let json = executor
+        .fetch_runs(5, None, None)
+        .await
+        .expect("fetch runs");
     let runs = p.parse_runs(&json).expect("parse runs");
 
     let mut state = AppState::new("cli/cli".to_string(), None, 5, None);
@@ -17,6 +17,22 @@ pub struct Cli {
     #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
     pub interval: u64,
 
+    /// Adaptive interval while any run is in progress
+    #[arg(long, default_value_t = ciw_core::app::POLL_INTERVAL_ACTIVE, value_parser = clap::value_parser!(u64).range(1..))]
+    pub active_interval: u64,
+
+    /// Adaptive interval for a minute after runs complete
+    #[arg(long, default_value_t = ciw_core::app::POLL_INTERVAL_RECENT, value_parser = clap::value_parser!(u64).range(1..))]
+    pub recent_interval: u64,
+
+    /// Adaptive interval when nothing is running
+    #[arg(long, default_value_t = ciw_core::app::POLL_INTERVAL_IDLE, value_parser = clap::value_parser!(u64).range(1..))]
+    pub idle_interval: u64,
+
+    /// Randomize each poll wait by ±10% so several instances don't poll in lockstep
+    #[arg(long)]
+    pub jitter: bool,
+
     /// Maximum number of pipelines to display
     #[arg(short, long, default_value_t = 20)]
     pub limit: usize,
@@ -29,9 +45,132 @@ pub struct Cli {
     #[arg(long)]
     pub no_notify: bool,
 
+    /// Which finished runs trigger a desktop notification: all, failures, none
+    #[arg(long, default_value = "all")]
+    pub notify_on: ciw_core::app::NotifyOn,
+
+    /// Desktop-notify for runs on every branch or only the current one: all, current-branch
+    #[arg(long, default_value = "all")]
+    pub notify_scope: ciw_core::app::NotifyScope,
+
+    /// Fetch jobs for runs as soon as they fail, so expanding them is instant
+    #[arg(long)]
+    pub prefetch_failed: bool,
+
+    /// Expand the newest in-progress pipeline after the first poll, loading its jobs
+    #[arg(long)]
+    pub auto_expand: bool,
+
+    /// Notify once when a run has been in progress for this many minutes
+    #[arg(long, value_name = "MINUTES", value_parser = clap::value_parser!(u64).range(1..))]
+    pub long_run: Option<u64>,
+
+    /// Keep runs with an unrecognized status in the active filter
+    #[arg(long)]
+    pub unknown_active: bool,
+
+    /// Briefly invert the screen when a run newly fails
+    #[arg(long)]
+    pub flash: bool,
+
+    /// Ring the terminal bell when a run newly fails
+    #[arg(long)]
+    pub bell_on_failure: bool,
+
+    /// Only show runs created within a window (24h, 7d) or after a date (2026-01-31)
+    #[arg(long, value_name = "WHEN")]
+    pub since: Option<ciw_core::app::Since>,
+
+    /// Only show pipelines triggered by this username (known only with --watch-run)
+    #[arg(long, value_name = "LOGIN")]
+    pub actor: Option<String>,
+
+    /// Watch only the pipeline with this id, expanded (pairs with --watch-until-complete)
+    #[arg(long, value_name = "ID")]
+    pub watch_run: Option<u64>,
+
+    /// Zone for absolute timestamps in the details panel: utc, local, or an IANA name
+    #[arg(long, default_value = "utc")]
+    pub timezone: ciw_core::app::DisplayTz,
+
+    /// POST a JSON summary of each finished pipeline here (Slack incoming webhooks work)
+    #[arg(long, value_name = "URL")]
+    pub webhook: Option<String>,
+
+    /// Run this instead of the built-in desktop notifier; {title}, {status} and {url} are filled in per pipeline
+    #[arg(long, value_name = "TEMPLATE")]
+    pub notify_command: Option<ciw_core::notify::NotifyCommand>,
+
+    /// Don't ring the terminal bell when a desktop notification can't be shown
+    #[arg(long)]
+    pub no_bell: bool,
+
+    /// Disable colors in the TUI and --once output (also: NO_COLOR)
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Tint failed pipeline rows red and successful ones green
+    #[arg(long)]
+    pub tint_rows: bool,
+
+    /// Print pipelines as a plain-text table and exit (0 = ok, 1 = failures, 2 = error)
+    #[arg(long)]
+    pub once: bool,
+
+    /// With --once: keep polling until no pipelines are active, then exit with their outcome
+    #[arg(long, requires = "once")]
+    pub watch_until_complete: bool,
+
+    /// Give up on --watch-until-complete after this many seconds (exit code 2)
+    #[arg(long, default_value_t = 3600, value_parser = clap::value_parser!(u64).range(1..))]
+    pub timeout: u64,
+
+    /// Maximum simultaneous `glab` subprocesses
+    #[arg(long, default_value_t = ciw_core::traits::DEFAULT_MAX_CONCURRENT, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub max_concurrent: usize,
+
+    /// Seconds before a `glab` list/status/action command is abandoned
+    #[arg(long, value_name = "SECS", default_value_t = ciw_core::traits::DEFAULT_TIMEOUT_SECS, value_parser = clap::value_parser!(u64).range(1..))]
+    pub fetch_timeout: u64,
+
+    /// Log lines kept (from the end) when viewing failure logs
+    #[arg(long, value_name = "N", default_value_t = ciw_core::app::LOG_MAX_LINES, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(10..=100_000))]
+    pub log_lines: usize,
+
+    /// Seconds before a failed-log fetch is abandoned
+    #[arg(long, value_name = "SECS", default_value_t = ciw_core::traits::DEFAULT_TIMEOUT_SECS, value_parser = clap::value_parser!(u64).range(1..))]
+    pub log_timeout: u64,
+
+    /// Path to the `glab` binary when it isn't on PATH as `glab` (also: GLW_GLAB_BIN)
+    #[arg(long, value_name = "PATH")]
+    pub glab_bin: Option<std::path::PathBuf>,
+
+    /// Show the last fetched runs at startup from $XDG_STATE_HOME/glw/cache/ and keep it updated
+    #[arg(long)]
+    pub cache: bool,
+
+    /// Restore filter, sort, expanded runs and cursor from $XDG_STATE_HOME/glw/session/ and save them on quit
+    #[arg(long)]
+    pub session: bool,
+
     /// Enable verbose logging to $XDG_STATE_HOME/glw/debug.log
     #[arg(long)]
     pub verbose: bool,
+
+    /// With --once: send debug logs to stderr instead of the log file
+    #[arg(long, requires = "once")]
+    pub log_stderr: bool,
+}
+
+/// `--glab-bin`, then a non-empty `GLW_GLAB_BIN`, then plain `glab` from PATH.
+pub fn resolve_glab_bin(flag: Option<&std::path::Path>) -> std::path::PathBuf {
+    flag.map(std::path::Path::to_path_buf)
+        .or_else(|| {
+            std::env::var_os("GLW_GLAB_BIN")
+                .filter(|v| !v.is_empty())
+                .map(std::path::PathBuf::from)
+        })
+        .unwrap_or_else(|| std::path::PathBuf::from("glab"))
 }
 
 /// Validates that `project` has at least 2 segments (group/project).
@@ -1,27 +1,58 @@
 use async_trait::async_trait;
-use ciw_core::traits::CiExecutor;
+use ciw_core::traits::{self, CiExecutor};
 use color_eyre::eyre::{eyre, Result};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::process::Command;
+use tokio::sync::Semaphore;
 
-const GLAB_TIMEOUT: Duration = Duration::from_secs(30);
+/// Local `git` calls; not worth a flag.
+const GIT_TIMEOUT: Duration = Duration::from_secs(30);
 const CLIPBOARD_TIMEOUT: Duration = Duration::from_secs(10);
 const LOG_SIZE_LIMIT: usize = 10 * 1024 * 1024; // 10 MB
 
 pub struct GlabExecutor {
-    #[allow(dead_code)]
-    project: String,
     encoded_project: String,
+    /// Bounds concurrent `glab` processes — rapid expands would otherwise fork dozens.
+    permits: Arc<Semaphore>,
+    timeouts: traits::Timeouts,
+    /// `--glab-bin`; `glab` from PATH by default.
+    bin: PathBuf,
 }
 
 impl GlabExecutor {
+    #[cfg(test)]
     pub fn new(project: String) -> Self {
-        let encoded_project = project.replace('/', "%2F");
+        Self::with_concurrency(project, traits::DEFAULT_MAX_CONCURRENT)
+    }
+
+    pub fn with_concurrency(project: String, max_concurrent: usize) -> Self {
         Self {
-            project,
-            encoded_project,
+            encoded_project: project.replace('/', "%2F"),
+            permits: Arc::new(Semaphore::new(max_concurrent.max(1))),
+            timeouts: traits::Timeouts::default(),
+            bin: PathBuf::from("glab"),
         }
     }
+
+    pub fn with_bin(mut self, bin: PathBuf) -> Self {
+        self.bin = bin;
+        self
+    }
+
+    pub fn with_timeouts(mut self, timeouts: traits::Timeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    async fn glab(&self, args: &[&str]) -> Result<String> {
+        traits::with_permit(
+            &self.permits,
+            run_glab(&self.bin, args, self.timeouts.fetch),
+        )
+        .await
+    }
 }
 
 /// Uses `glab api` (raw REST) instead of `glab ci` subcommands because `glab ci`
@@ -29,11 +60,11 @@ impl GlabExecutor {
 #[async_trait]
 impl CiExecutor for GlabExecutor {
     async fn check_available(&self) -> Result<()> {
-        run_glab(&["auth", "status"]).await.map(|_| ())
+        self.glab(&["auth", "status"]).await.map(|_| ())
     }
 
     async fn detect_repo(&self) -> Result<String> {
-        let output = run_glab(&["repo", "view", "--output", "json"]).await?;
+        let output = self.glab(&["repo", "view", "--output", "json"]).await?;
         let parsed: serde_json::Value =
             serde_json::from_str(&output).map_err(|e| eyre!("Failed to parse repo info: {e}"))?;
         let project = parsed["path_with_namespace"]
@@ -48,13 +79,13 @@ impl CiExecutor for GlabExecutor {
 
     async fn detect_branch(&self) -> Result<String> {
         let output = tokio::time::timeout(
-            GLAB_TIMEOUT,
+            GIT_TIMEOUT,
             Command::new("git")
                 .args(["rev-parse", "--abbrev-ref", "HEAD"])
                 .output(),
         )
         .await
-        .map_err(|_| eyre!("git command timed out after {}s", GLAB_TIMEOUT.as_secs()))?
+        .map_err(|_| eyre!("git command timed out after {}s", GIT_TIMEOUT.as_secs()))?
         .map_err(|e| eyre!("Failed to detect branch: {}", e))?;
 
         if !output.status.success() {
@@ -66,7 +97,12 @@ impl CiExecutor for GlabExecutor {
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     }
 
-    async fn fetch_runs(&self, limit: usize, source: Option<&str>) -> Result<String> {
+    async fn fetch_runs(
+        &self,
+        limit: usize,
+        source: Option<&str>,
+        branch: Option<&str>,
+    ) -> Result<String> {
         let mut url = format!(
             "/projects/{}/pipelines?per_page={}",
             self.encoded_project, limit
@@ -74,7 +110,31 @@ impl CiExecutor for GlabExecutor {
         if let Some(s) = source {
             url.push_str(&format!("&source={}", s));
         }
-        run_glab(&["api", &url]).await
+        if let Some(b) = branch {
+            url.push_str(&format!("&ref={}", traits::query_escape(b)));
+        }
+        self.glab(&["api", &url]).await
+    }
+
+    /// The single-pipeline endpoint returns a bare object; wrapped so
+    /// `parse_runs` takes it.
+    async fn fetch_run(&self, pipeline_id: u64) -> Result<String> {
+        let url = format!(
+            "/projects/{}/pipelines/{}",
+            self.encoded_project, pipeline_id
+        );
+        let json = self.glab(&["api", &url]).await?;
+        Ok(format!("[{}]", json.trim()))
+    }
+
+    async fn current_user(&self) -> Result<String> {
+        let json = self.glab(&["api", "/user"]).await?;
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json).map_err(|e| eyre!("Failed to parse user: {e}"))?;
+        parsed["username"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| eyre!("User response missing username"))
     }
 
     async fn fetch_jobs(&self, pipeline_id: u64) -> Result<String> {
@@ -82,7 +142,7 @@ impl CiExecutor for GlabExecutor {
             "/projects/{}/pipelines/{}/jobs?per_page=100",
             self.encoded_project, pipeline_id
         );
-        run_glab(&["api", &url]).await
+        self.glab(&["api", &url]).await
     }
 
     async fn cancel_run(&self, pipeline_id: u64) -> Result<()> {
@@ -90,7 +150,7 @@ impl CiExecutor for GlabExecutor {
             "/projects/{}/pipelines/{}/cancel",
             self.encoded_project, pipeline_id
         );
-        run_glab(&["api", "-X", "POST", &url]).await?;
+        self.glab(&["api", "-X", "POST", &url]).await?;
         Ok(())
     }
 
@@ -99,7 +159,7 @@ impl CiExecutor for GlabExecutor {
             "/projects/{}/pipelines/{}",
             self.encoded_project, pipeline_id
         );
-        run_glab(&["api", "-X", "DELETE", &url]).await?;
+        self.glab(&["api", "-X", "DELETE", &url]).await?;
         Ok(())
     }
 
@@ -108,7 +168,7 @@ impl CiExecutor for GlabExecutor {
             "/projects/{}/pipelines/{}/retry",
             self.encoded_project, pipeline_id
         );
-        run_glab(&["api", "-X", "POST", &url]).await?;
+        self.glab(&["api", "-X", "POST", &url]).await?;
         Ok(())
     }
 
@@ -176,22 +236,45 @@ impl CiExecutor for GlabExecutor {
 impl GlabExecutor {
     async fn fetch_job_trace(&self, job_id: u64) -> Result<String> {
         let url = format!("/projects/{}/jobs/{}/trace", self.encoded_project, job_id);
-        run_glab(&["api", &url]).await
+        traits::with_permit(
+            &self.permits,
+            run_glab(&self.bin, &["api", &url], self.timeouts.log),
+        )
+        .await
     }
 }
 
-async fn run_glab(args: &[&str]) -> Result<String> {
+/// The seam every `glab` spawn goes through, so tests can check the program.
+fn glab_command(bin: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(bin);
+    // Aborted fetches (a log overlay closed mid-load) drop the future;
+    // glab goes with it instead of running on unread
+    cmd.args(args).kill_on_drop(true);
+    cmd
+}
+
+/// Names a custom `--glab-bin` in the not-found error; pointing at the install
+/// page would be misleading when the user did install it, just elsewhere.
+fn spawn_error(bin: &Path, e: &std::io::Error) -> color_eyre::Report {
+    if e.kind() != std::io::ErrorKind::NotFound {
+        return eyre!("Failed to run {}: {}", bin.display(), e);
+    }
+    if bin == Path::new("glab") {
+        eyre!("glab CLI not found. Install it from https://gitlab.com/gitlab-org/cli")
+    } else {
+        eyre!(
+            "glab CLI not found at '{}' (from --glab-bin / GLW_GLAB_BIN)",
+            bin.display()
+        )
+    }
+}
+
+async fn run_glab(bin: &Path, args: &[&str], timeout: Duration) -> Result<String> {
     let start = std::time::Instant::now();
-    let output = tokio::time::timeout(GLAB_TIMEOUT, Command::new("glab").args(args).output())
+    let output = tokio::time::timeout(timeout, glab_command(bin, args).output())
         .await
-        .map_err(|_| eyre!("glab command timed out after {}s", GLAB_TIMEOUT.as_secs()))?
-        .map_err(|e| {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                eyre!("glab CLI not found. Install it from https://gitlab.com/gitlab-org/cli")
-            } else {
-                eyre!("Failed to run glab: {}", e)
-            }
-        })?;
+        .map_err(|_| eyre!("glab command timed out after {}s", timeout.as_secs()))?
+        .map_err(|e| spawn_error(bin, &e))?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -334,7 +417,9 @@ async fn copy_to_clipboard_impl(text: &str) -> Result<()> {
 }
 
 pub fn classify_glab_error(stderr: &str) -> String {
-    if stderr.contains("not logged") || stderr.contains("auth login") {
+    if ciw_core::poller::is_rate_limit_error(stderr) {
+        format!("{}: {}", ciw_core::poller::RATE_LIMITED, stderr.trim())
+    } else if stderr.contains("not logged") || stderr.contains("auth login") {
         "Not authenticated with glab. Run `glab auth login` first.".to_string()
     } else if stderr.contains("not a git repository") || stderr.contains("could not determine") {
         "Not in a GitLab repository. Use --project flag or cd into a repo.".to_string()
@@ -377,6 +462,15 @@ mod tests {
         assert!(msg.contains("Not in a GitLab repository"));
     }
 
+    #[test]
+    fn classify_rate_limit() {
+        let msg = classify_glab_error("GET https://gitlab.com/api/v4/...: 429 Too Many Requests");
+        assert!(ciw_core::poller::is_rate_limited(&msg));
+        assert!(!ciw_core::poller::is_rate_limited(&classify_glab_error(
+            "500"
+        )));
+    }
+
     #[test]
     fn classify_generic_error() {
         let msg = classify_glab_error("something went wrong");
@@ -401,6 +495,38 @@ mod tests {
         assert_eq!(exec.encoded_project, "group%2Fsubgroup%2Fproject");
     }
 
+    #[test]
+    fn executor_keeps_configured_timeouts() {
+        let exec = GlabExecutor::new("g/p".to_string());
+        assert_eq!(exec.timeouts, traits::Timeouts::default());
+        let exec = exec.with_timeouts(traits::Timeouts::from_secs(5, 300));
+        assert_eq!(exec.timeouts.fetch, Duration::from_secs(5));
+        assert_eq!(exec.timeouts.log, Duration::from_mins(5));
+    }
+
+    #[test]
+    fn executor_spawns_configured_binary() {
+        let exec = GlabExecutor::new("g/p".to_string());
+        let program = |exec: &GlabExecutor| {
+            glab_command(&exec.bin, &["auth", "status"])
+                .as_std()
+                .get_program()
+                .to_owned()
+        };
+        assert_eq!(program(&exec), "glab");
+        let exec = exec.with_bin(PathBuf::from("/opt/tools/glab"));
+        assert_eq!(program(&exec), "/opt/tools/glab");
+    }
+
+    #[test]
+    fn not_found_error_names_custom_binary() {
+        let missing = std::io::Error::from(std::io::ErrorKind::NotFound);
+        let default = spawn_error(Path::new("glab"), &missing).to_string();
+        assert!(default.contains("https://gitlab.com/gitlab-org/cli"));
+        let custom = spawn_error(Path::new("/opt/tools/glab"), &missing).to_string();
+        assert!(custom.contains("'/opt/tools/glab'"), "{custom}");
+    }
+
     #[test]
     fn encoded_project_simple() {
         let exec = GlabExecutor::new("mygroup/myproject".to_string());
@@ -3,13 +3,16 @@ mod executor;
 mod parser;
 
 use ciw_core::app;
+use ciw_core::cache;
 use ciw_core::diff;
 use ciw_core::events;
+use ciw_core::headless;
 use ciw_core::input;
 use ciw_core::notify;
 use ciw_core::platform::PlatformConfig;
 use ciw_core::poller::{self, Poller};
-use ciw_core::traits::{CiExecutor, CiParser};
+use ciw_core::session;
+use ciw_core::traits::{CiExecutor, CiParser, Timeouts};
 use ciw_core::tui;
 
 use app::AppState;
@@ -30,6 +33,9 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::watch;
 
+/// Input poll timeout; also how long `EventHandler::suspend` waits.
+const TICK_RATE: Duration = Duration::from_millis(100);
+
 const GLW_ART: &[&str] = &[
     r"            __                                    ",
     r"          |  \                                    ",
@@ -74,6 +80,15 @@ fn setup_verbose_logging() -> Result<()> {
     Ok(())
 }
 
+/// `--log-stderr`: plain text so it can be piped or grepped.
+fn setup_stderr_logging() {
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_ansi(false)
+        .init();
+    tracing::info!("glw v{} logging to stderr", env!("CARGO_PKG_VERSION"));
+}
+
 fn dirs_next_or_fallback() -> std::path::PathBuf {
     if let Some(state) = std::env::var_os("XDG_STATE_HOME") {
         std::path::PathBuf::from(state).join("glw")
@@ -93,10 +108,16 @@ fn spawn_monitored(
     tx: tokio::sync::mpsc::UnboundedSender<AppEvent>,
     label: &'static str,
     fut: impl Future<Output = ()> + Send + 'static,
-) {
+) -> tokio::task::AbortHandle {
+    let handle = tokio::spawn(fut);
+    let abort = handle.abort_handle();
     tokio::spawn(async move {
-        let handle = tokio::spawn(fut);
         if let Err(join_err) = handle.await {
+            // Aborted on purpose through the returned handle
+            if join_err.is_cancelled() {
+                tracing::debug!("{label} aborted");
+                return;
+            }
             let msg = if join_err.is_panic() {
                 match join_err.into_panic().downcast::<String>() {
                     Ok(s) => *s,
@@ -117,6 +138,13 @@ fn spawn_monitored(
             }
         }
     });
+    abort
+}
+
+fn make_executor(args: &Cli, project: String) -> GlabExecutor {
+    GlabExecutor::with_concurrency(project, args.max_concurrent)
+        .with_timeouts(Timeouts::from_secs(args.fetch_timeout, args.log_timeout))
+        .with_bin(cli::resolve_glab_bin(args.glab_bin.as_deref()))
 }
 
 #[tokio::main]
@@ -124,9 +152,63 @@ async fn main() -> Result<()> {
     color_eyre::install()?;
     let args = Cli::parse();
 
-    // Setup verbose logging
-    if args.verbose {
-        setup_verbose_logging()?;
+    match headless::log_target(args.verbose, args.log_stderr, args.once) {
+        headless::LogTarget::File => setup_verbose_logging()?,
+        headless::LogTarget::Stderr => setup_stderr_logging(),
+        headless::LogTarget::Off => {}
+    }
+
+    let intervals = app::Intervals::new(
+        args.active_interval,
+        args.recent_interval,
+        args.idle_interval,
+    )
+    .map_err(|e| eyre!(e))?;
+
+    if args.once {
+        let probe = make_executor(&args, String::new());
+        let code = match headless::resolve_repo(
+            &probe,
+            args.project.as_deref(),
+            Some(cli::validate_project_format),
+        )
+        .await
+        {
+            Ok(project) if args.watch_until_complete => {
+                headless::watch_until_complete(
+                    Arc::new(make_executor(&args, project)),
+                    Arc::new(GlabParser),
+                    args.limit,
+                    args.source.clone(),
+                    args.branch.clone(),
+                    args.since,
+                    args.watch_run,
+                    args.interval,
+                    intervals,
+                    Duration::from_secs(args.timeout),
+                    headless::color_enabled(args.no_color),
+                )
+                .await
+            }
+            Ok(project) => {
+                headless::run_once(
+                    &make_executor(&args, project),
+                    &GlabParser,
+                    args.limit,
+                    args.source.as_deref(),
+                    args.branch.as_deref(),
+                    args.since,
+                    args.watch_run,
+                    headless::color_enabled(args.no_color),
+                )
+                .await
+            }
+            Err(e) => {
+                eprintln!("Error: {e}");
+                headless::EXIT_ERROR
+            }
+        };
+        std::process::exit(code);
     }
 
     // Setup terminal with panic hook early, before any data fetching
@@ -149,7 +231,8 @@ async fn main() -> Result<()> {
     terminal.clear()?;
 
     // Create executor and parser for startup (project not yet known)
-    let startup_executor = GlabExecutor::new(String::new());
+    let startup_executor = make_executor(&args, String::new());
+    let cache_dir = args.cache.then(dirs_next_or_fallback);
     let glab_parser = GlabParser;
 
     // Run startup phases with animated spinner
@@ -163,6 +246,7 @@ async fn main() -> Result<()> {
         args.limit,
         args.source.as_deref(),
         Some(cli::validate_project_format),
+        cache_dir.as_deref(),
     )
     .await
     {
@@ -190,17 +274,57 @@ async fn main() -> Result<()> {
     let mut state = AppState::new(project.clone(), branch, args.limit, args.source.clone());
     state.config.version_string = version_string;
     state.poll_interval = args.interval;
+    state.intervals = intervals;
     state.desktop_notify = !args.no_notify;
+    state.config.notify_on = args.notify_on;
+    state.config.notify_scope = args.notify_scope;
+    state.config.bell_fallback = !args.no_bell;
+    state.config.timezone = args.timezone;
+    state.config.prefetch_failed_jobs = args.prefetch_failed;
+    state.config.auto_expand = args.auto_expand;
+    state.config.long_run_threshold = args.long_run;
+    state.config.unknown_is_active = args.unknown_active;
+    state.config.flash_on_failure = args.flash;
+    state.config.bell_on_failure = args.bell_on_failure;
+    state.config.since = args.since;
+    state.actor_filter = args.actor.clone();
+    state.config.webhook_url = args.webhook.clone();
+    state.config.notify_command = args.notify_command.clone();
+    state.config.log_max_lines = args.log_lines;
+    state.config.color = !args.no_color && !app::no_color_env();
+    state.config.tint_rows = args.tint_rows;
     state.runs = startup_result.runs;
+    state.from_cache = startup_result.from_cache;
+    if startup_result.branch_undetected {
+        state.add_notification(
+            0,
+            "branch detection unavailable (detached HEAD)".to_string(),
+        );
+    }
+    state.config.cache_path = cache_dir.map(|dir| cache::cache_file(&dir, &project));
     state.rebuild_tree();
+    state.config.session_path = args
+        .session
+        .then(|| session::session_file(&dirs_next_or_fallback(), &project));
+    let restored_jobs = match state.config.session_path.as_deref().map(session::load) {
+        Some(Ok(saved)) => saved.restore(&mut state),
+        Some(Err(e)) => {
+            tracing::debug!("session: {e}");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    if let Some(run_id) = args.watch_run {
+        state.focus_run(run_id);
+    }
     state.last_poll = Some(Instant::now());
 
     // Create the real executor (with project) and parser as Arc trait objects
-    let executor: Arc<dyn CiExecutor> = Arc::new(GlabExecutor::new(project.clone()));
+    let executor: Arc<dyn CiExecutor> = Arc::new(make_executor(&args, project.clone()));
     let parser: Arc<dyn CiParser> = Arc::new(GlabParser);
 
     // Event handler
-    let events = EventHandler::new(Duration::from_millis(100));
+    let events = EventHandler::new(TICK_RATE);
     let tx = events.sender();
 
     // Adaptive polling interval channel
@@ -210,32 +334,73 @@ async fn main() -> Result<()> {
     let poller_tx = tx.clone();
     let poller_executor = executor.clone();
     let poller_parser = parser.clone();
-    let poller_source = args.source.clone();
+    let (workflow_tx, workflow_rx) = watch::channel(args.source.clone());
     let poller_limit = args.limit;
+    let poller_jitter = args.jitter;
+    let poller_watch_run = args.watch_run;
     let poller_handle = tokio::spawn(async move {
         let poller = Poller::new(
             poller_executor,
             poller_parser,
             poller_limit,
-            poller_source,
+            workflow_rx,
             poller_tx,
             interval_rx,
-        );
+        )
+        .with_watch_run(poller_watch_run);
+        let poller = if poller_jitter {
+            poller.with_jitter(poller::Jitter::from_clock())
+        } else {
+            poller
+        };
         poller.run().await;
     });
 
+    // Resolved once for `M`; a failure only surfaces if the key is pressed
+    {
+        let tx2 = tx.clone();
+        let executor2 = executor.clone();
+        spawn_monitored(tx.clone(), "current_user", async move {
+            match executor2.current_user().await {
+                Ok(login) => {
+                    if tx2.send(AppEvent::CurrentUser(login)).is_err() {
+                        tracing::warn!("current_user: channel closed");
+                    }
+                }
+                Err(e) => tracing::debug!("current_user: {e}"),
+            }
+        });
+    }
+
+    // Restored expansions need their jobs; nothing else fetches unchanged runs
+    for run_id in restored_jobs {
+        let tx2 = tx.clone();
+        let executor2 = executor.clone();
+        let parser2 = parser.clone();
+        spawn_monitored(tx.clone(), "session_jobs", async move {
+            poller::fetch_jobs_for_run(&*executor2, &*parser2, run_id, &tx2).await;
+        });
+    }
+
     let result = run_app(
         &mut terminal,
         &mut state,
         events,
         &tx,
         &interval_tx,
+        &workflow_tx,
         poller_handle,
         executor,
         parser,
     )
     .await;
 
+    if let Some(path) = &state.config.session_path {
+        if let Err(e) = session::save(path, &session::SessionState::capture(&state)) {
+            tracing::warn!("session: {e}");
+        }
+    }
+
     // Restore terminal
     terminal::disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen, SetTitle(""))?;
@@ -244,17 +409,13 @@ async fn main() -> Result<()> {
     result
 }
 
-/// Visible height of the log overlay area (80% of terminal height minus borders).
-/// Must stay in sync with the sizing logic in `tui::log_overlay::render`.
+/// Visible height of the log overlay, as `tui::log_overlay::render` sizes it.
 fn log_overlay_height(terminal: &Terminal<CrosstermBackend<io::Stdout>>) -> usize {
-    terminal
-        .size()
-        .map(|s| s.height as usize * 8 / 10)
-        .unwrap_or_else(|e| {
-            tracing::warn!("terminal size query failed: {e}");
-            20
-        })
-        .saturating_sub(2)
+    let height = terminal.size().map(|s| s.height).unwrap_or_else(|e| {
+        tracing::warn!("terminal size query failed: {e}");
+        25
+    });
+    tui::log_overlay::visible_height(height)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -264,12 +425,14 @@ async fn run_app(
     mut events: EventHandler,
     tx: &tokio::sync::mpsc::UnboundedSender<AppEvent>,
     interval_tx: &watch::Sender<u64>,
+    workflow_tx: &watch::Sender<Option<String>>,
     poller_handle: tokio::task::JoinHandle<()>,
     executor: Arc<dyn CiExecutor>,
     parser: Arc<dyn CiParser>,
 ) -> Result<()> {
     let mut last_tick = Instant::now();
     let mut poll_start = Instant::now();
+    let mut log_fetches = LogFetches::new();
 
     loop {
         // Render
@@ -283,6 +446,14 @@ async fn run_app(
         state.prune_notifications();
         state.prune_error();
         state.prune_log_cache();
+        // A closed overlay (or one switched to another log) drops its fetch
+        log_fetches.retain(|&(run_id, job_id), handle| {
+            let wanted = state.log_result_wanted(run_id, job_id);
+            if !wanted {
+                handle.abort();
+            }
+            wanted
+        });
 
         // Process events
         if let Some(event) = events.next().await {
@@ -297,11 +468,22 @@ async fn run_app(
                             OverlayMode::Detail
                         } else if state.has_confirm_overlay() {
                             OverlayMode::Confirm
+                        } else if state.has_input_overlay() {
+                            OverlayMode::Input
+                        } else if state.has_workflow_picker() {
+                            OverlayMode::WorkflowPicker
                         } else {
                             OverlayMode::None
                         },
+                        pending_key: state.pending_key.take(),
+                        pending_count: state.pending_count,
+                        on_run_error: state.current_run_error().is_some(),
                     };
-                    match input::map_key(key, &ctx) {
+                    let action = input::map_key(key, &ctx);
+                    if !action.extends_count() {
+                        state.pending_count = None;
+                    }
+                    match action {
                         Action::Quit => state.should_quit = true,
                         Action::DismissError => state.clear_error(),
                         Action::MoveUp => state.move_cursor_up(),
@@ -327,41 +509,44 @@ async fn run_app(
                                 }
                             }
                         }
+                        Action::Retry => {
+                            if let Some(run_id) = state.retry_current() {
+                                let tx2 = tx.clone();
+                                let executor2 = executor.clone();
+                                let parser2 = parser.clone();
+                                spawn_monitored(tx.clone(), "retry_jobs", async move {
+                                    poller::fetch_jobs_for_run(
+                                        &*executor2,
+                                        &*parser2,
+                                        run_id,
+                                        &tx2,
+                                    )
+                                    .await;
+                                });
+                            }
+                        }
+                        Action::RefreshJobs => {
+                            if let Some(run_id) = state.refresh_current_jobs() {
+                                let tx2 = tx.clone();
+                                let executor2 = executor.clone();
+                                let parser2 = parser.clone();
+                                spawn_monitored(tx.clone(), "refresh_jobs", async move {
+                                    poller::fetch_jobs_for_run(
+                                        &*executor2,
+                                        &*parser2,
+                                        run_id,
+                                        &tx2,
+                                    )
+                                    .await;
+                                });
+                            }
+                        }
                         Action::Collapse => state.collapse_current(),
                         Action::Toggle => state.toggle_expand(),
                         Action::Refresh => {
-                            state.begin_loading();
-                            let tx2 = tx.clone();
-                            let executor2 = executor.clone();
-                            let parser2 = parser.clone();
-                            let limit = state.config.limit;
-                            let wf = state.config.workflow_filter.clone();
-                            spawn_monitored(tx.clone(), "refresh", async move {
-                                match executor2.fetch_runs(limit, wf.as_deref()).await {
-                                    Ok(json) => match parser2.parse_runs(&json) {
-                                        Ok(runs) => {
-                                            if tx2
-                                                .send(AppEvent::PollResult { runs, manual: true })
-                                                .is_err()
-                                            {
-                                                tracing::warn!("refresh: channel closed");
-                                            }
-                                        }
-                                        Err(e) => {
-                                            if tx2.send(AppEvent::Error(format!("{}", e))).is_err()
-                                            {
-                                                tracing::warn!("refresh: channel closed");
-                                            }
-                                        }
-                                    },
-                                    Err(e) => {
-                                        if tx2.send(AppEvent::Error(format!("{}", e))).is_err() {
-                                            tracing::warn!("refresh: channel closed");
-                                        }
-                                    }
-                                }
-                            });
-                            poll_start = Instant::now();
+                            if spawn_refresh(state, &executor, &parser, tx) {
+                                poll_start = Instant::now();
+                            }
                         }
                         Action::RerunFailed => {
                             if let Some(run_id) = state.current_run_id() {
@@ -380,26 +565,14 @@ async fn run_app(
                                             .to_string(),
                                     );
                                 } else {
-                                    let executor2 = executor.clone();
-                                    let tx2 = tx.clone();
-                                    spawn_monitored(tx.clone(), "rerun", async move {
-                                        match executor2.rerun_failed(run_id).await {
-                                            Ok(()) => {
-                                                if tx2.send(AppEvent::RerunSuccess(run_id)).is_err()
-                                                {
-                                                    tracing::warn!("rerun: channel closed");
-                                                }
-                                            }
-                                            Err(e) => {
-                                                if tx2
-                                                    .send(AppEvent::Error(format!("{}", e)))
-                                                    .is_err()
-                                                {
-                                                    tracing::warn!("rerun: channel closed");
-                                                }
-                                            }
-                                        }
-                                    });
+                                    let title = state
+                                        .current_run_display_title()
+                                        .unwrap_or_else(|| format!("run {run_id}"));
+                                    state.open_confirm_overlay(
+                                        "Confirm Rerun".to_string(),
+                                        format!("Rerun failed jobs of \"{title}\"?"),
+                                        app::ConfirmAction::RerunRun(run_id),
+                                    );
                                 }
                             }
                         }
@@ -439,10 +612,141 @@ async fn run_app(
                                 }
                             }
                         }
+                        Action::ToggleSelect => state.toggle_selected(),
+                        Action::ClearSelection => state.clear_selection(),
+                        Action::CancelSelected => state.open_batch_confirm(app::BatchOp::Cancel),
+                        Action::DeleteSelected => state.open_batch_confirm(app::BatchOp::Delete),
+                        Action::CancelAllActive => state.open_cancel_all_confirm(),
+                        Action::DispatchWorkflow => {
+                            if let Some(workflow) = state.current_run_name().map(str::to_string) {
+                                let branch = state.config.branch.clone().unwrap_or_default();
+                                state.open_input_overlay(
+                                    format!("Dispatch {workflow}"),
+                                    "Branch".to_string(),
+                                    branch,
+                                    app::InputPurpose::DispatchBranch { workflow },
+                                );
+                            }
+                        }
+                        Action::OpenFilterInput => state.open_text_filter(),
+                        Action::OpenWorkflowPicker => state.open_workflow_picker(),
+                        Action::PickerUp => state.picker_move_up(),
+                        Action::PickerDown => state.picker_move_down(),
+                        Action::InputSubmit if state.has_workflow_picker() => {
+                            if let Some(source) = state.take_workflow_pick() {
+                                if source != state.config.workflow_filter {
+                                    state.config.workflow_filter.clone_from(&source);
+                                    if workflow_tx.send(source).is_err() {
+                                        tracing::warn!("source: poller channel closed");
+                                    }
+                                    spawn_refresh(state, &executor, &parser, tx);
+                                }
+                            }
+                        }
+                        Action::InputChar(c) => state.input_push(c),
+                        Action::InputBackspace => state.input_pop(),
+                        Action::InputSubmit => match state.take_input() {
+                            Some((app::InputPurpose::DispatchBranch { .. }, branch))
+                                if branch.is_empty() =>
+                            {
+                                state.set_error("Branch is required to dispatch".to_string());
+                            }
+                            Some((app::InputPurpose::DispatchBranch { workflow }, branch)) => {
+                                state.open_confirm_overlay(
+                                    "Confirm Dispatch".to_string(),
+                                    format!("Run \"{workflow}\" on {branch}?"),
+                                    app::ConfirmAction::DispatchWorkflow { workflow, branch },
+                                );
+                            }
+                            Some((app::InputPurpose::TextFilter, _)) | None => {}
+                        },
+                        Action::Approve => {
+                            let target = state
+                                .tree_items
+                                .get(state.cursor)
+                                .and_then(|item| state.runs.get(item.run_idx));
+                            match target {
+                                Some(run) if run.needs_approval() => {
+                                    let run_id = run.database_id;
+                                    let title = format!("{} #{}", run.name, run.number);
+                                    state.open_confirm_overlay(
+                                        "Confirm Approve".to_string(),
+                                        format!("Approve \"{title}\"?"),
+                                        app::ConfirmAction::ApproveRun(run_id),
+                                    );
+                                }
+                                Some(_) => {
+                                    state.set_error("Run is not awaiting approval".to_string());
+                                }
+                                None => {}
+                            }
+                        }
                         Action::ConfirmYes => {
                             if let Some(action) = state.confirm_action() {
                                 state.close_confirm_overlay();
                                 match action {
+                                    app::ConfirmAction::RerunRun(run_id) => {
+                                        let executor2 = executor.clone();
+                                        let tx2 = tx.clone();
+                                        spawn_monitored(tx.clone(), "rerun", async move {
+                                            match executor2.rerun_failed(run_id).await {
+                                                Ok(()) => {
+                                                    if tx2
+                                                        .send(AppEvent::RerunSuccess(run_id))
+                                                        .is_err()
+                                                    {
+                                                        tracing::warn!("rerun: channel closed");
+                                                    }
+                                                }
+                                                Err(e) => {
+                                                    if tx2
+                                                        .send(AppEvent::Error(format!("{}", e)))
+                                                        .is_err()
+                                                    {
+                                                        tracing::warn!("rerun: channel closed");
+                                                    }
+                                                }
+                                            }
+                                        });
+                                    }
+                                    app::ConfirmAction::Batch {
+                                        op,
+                                        run_ids,
+                                        from_marks,
+                                    } => {
+                                        if from_marks {
+                                            state.clear_selection();
+                                        }
+                                        let executor2 = executor.clone();
+                                        let tx2 = tx.clone();
+                                        spawn_monitored(tx.clone(), "batch", async move {
+                                            // Sequential to stay gentle on the API quota.
+                                            let mut succeeded = Vec::new();
+                                            let mut failed = Vec::new();
+                                            for run_id in run_ids {
+                                                let result = match op {
+                                                    app::BatchOp::Cancel => {
+                                                        executor2.cancel_run(run_id).await
+                                                    }
+                                                    app::BatchOp::Delete => {
+                                                        executor2.delete_run(run_id).await
+                                                    }
+                                                };
+                                                match result {
+                                                    Ok(()) => succeeded.push(run_id),
+                                                    Err(e) => failed.push((run_id, format!("{e}"))),
+                                                }
+                                            }
+                                            let event = AppEvent::BatchResult {
+                                                op,
+                                                succeeded,
+                                                failed,
+                                            };
+                                            if tx2.send(event).is_err() {
+                                                tracing::warn!("batch: channel closed");
+                                            }
+                                        });
+                                    }
                                     app::ConfirmAction::CancelRun(run_id) => {
                                         let executor2 = executor.clone();
                                         let tx2 = tx.clone();
@@ -471,6 +775,37 @@ async fn run_app(
                                             }
                                         });
                                     }
+                                    app::ConfirmAction::ApproveRun(run_id) => {
+                                        let executor2 = executor.clone();
+                                        let tx2 = tx.clone();
+                                        spawn_monitored(tx.clone(), "approve", async move {
+                                            let event = match executor2.approve(run_id).await {
+                                                Ok(()) => AppEvent::ApproveSuccess(run_id),
+                                                Err(e) => AppEvent::Error(format!("{e}")),
+                                            };
+                                            if tx2.send(event).is_err() {
+                                                tracing::warn!("approve: channel closed");
+                                            }
+                                        });
+                                    }
+                                    app::ConfirmAction::DispatchWorkflow { workflow, branch } => {
+                                        let executor2 = executor.clone();
+                                        let tx2 = tx.clone();
+                                        spawn_monitored(tx.clone(), "dispatch", async move {
+                                            let event = match executor2
+                                                .dispatch_workflow(&workflow, &branch)
+                                                .await
+                                            {
+                                                Ok(()) => {
+                                                    AppEvent::DispatchSuccess { workflow, branch }
+                                                }
+                                                Err(e) => AppEvent::Error(format!("{e}")),
+                                            };
+                                            if tx2.send(event).is_err() {
+                                                tracing::warn!("dispatch: channel closed");
+                                            }
+                                        });
+                                    }
                                     app::ConfirmAction::DeleteRun(run_id) => {
                                         let executor2 = executor.clone();
                                         let tx2 = tx.clone();
@@ -509,6 +844,17 @@ async fn run_app(
                                 }
                             }
                         }
+                        Action::ToggleMatrixGroups => state.toggle_matrix_groups(),
+                        Action::ToggleCompact => state.compact = !state.compact,
+                        Action::OpenPr => {
+                            if let Some(url) = state.current_run_pr_url() {
+                                if let Err(e) = executor.open_in_browser(&url) {
+                                    state.set_error(format!("{e}"));
+                                }
+                            } else if state.current_run_id().is_some() {
+                                state.set_error("No pull request for this run".to_string());
+                            }
+                        }
                         Action::ViewLogs => {
                             if !state.current_item_is_failed() {
                                 state.set_error("No failure logs for this item".to_string());
@@ -539,13 +885,33 @@ async fn run_app(
                                                 None
                                             }
                                         });
+                                    // Steps reuse the job's log, narrowed on display. A
+                                    // cached log may already be cut to its tail, so a step
+                                    // fetches the whole log again.
+                                    let step = state.current_item_step();
+                                    let cached = cached.filter(|_| step.is_none());
+                                    let mut title = build_log_title(state, run_id, job_id);
+                                    if let Some((number, name)) = &step {
+                                        title = format!("{title} > {number}. {name}");
+                                    }
+                                    state.open_log_overlay_loading(title, run_id, job_id);
+                                    state.set_log_step(step.map(|(_, name)| name));
                                     if let Some(content) = cached {
-                                        let title = build_log_title(state, run_id, job_id);
-                                        state.open_log_overlay(title, &content, run_id, job_id);
+                                        state.refresh_log_overlay(
+                                            run_id,
+                                            job_id,
+                                            &content,
+                                            log_overlay_height(terminal),
+                                        );
                                     } else {
-                                        let title = build_log_title(state, run_id, job_id);
                                         fetch_logs_async(
-                                            &executor, &parser, run_id, job_id, &title, tx,
+                                            &executor,
+                                            &parser,
+                                            run_id,
+                                            job_id,
+                                            state.log_fetch_max_lines(),
+                                            &mut log_fetches,
+                                            tx,
                                         );
                                     }
                                 }
@@ -558,14 +924,44 @@ async fn run_app(
                         Action::ScrollDown => {
                             state.scroll_log_down(1, log_overlay_height(terminal));
                         }
-                        Action::PageUp => state.scroll_log_up(20),
+                        Action::PageUp => {
+                            let height = log_overlay_height(terminal);
+                            state.scroll_log_up(app::log_page_step(height, false));
+                        }
+                        Action::HalfPageUp => {
+                            let height = log_overlay_height(terminal);
+                            state.scroll_log_up(app::log_page_step(height, true));
+                        }
                         Action::PageDown => {
-                            state.scroll_log_down(20, log_overlay_height(terminal));
+                            let height = log_overlay_height(terminal);
+                            state.scroll_log_down(app::log_page_step(height, false), height);
+                        }
+                        Action::HalfPageDown => {
+                            let height = log_overlay_height(terminal);
+                            state.scroll_log_down(app::log_page_step(height, true), height);
                         }
                         Action::ScrollToTop => state.scroll_log_to_top(),
                         Action::ScrollToBottom => {
                             state.scroll_log_to_bottom(log_overlay_height(terminal));
                         }
+                        Action::ToggleFollow => state.toggle_log_follow(),
+                        Action::ToggleFullLog => {
+                            if let Some((run_id, job_id)) =
+                                state.toggle_full_log(log_overlay_height(terminal))
+                            {
+                                state.add_notification(run_id, "Loading full log…".to_string());
+                                fetch_logs_async(
+                                    &executor,
+                                    &parser,
+                                    run_id,
+                                    job_id,
+                                    usize::MAX,
+                                    &mut log_fetches,
+                                    tx,
+                                );
+                            }
+                        }
+                        Action::ToggleLineNumbers => state.toggle_log_line_numbers(),
                         Action::CopyToClipboard => {
                             if let Some(text) = state.log_overlay_text() {
                                 let executor2 = executor.clone();
@@ -581,6 +977,44 @@ async fn run_app(
                                 });
                             }
                         }
+                        Action::CopyUrl => {
+                            if let Some(url) = state.current_item_url() {
+                                let executor2 = executor.clone();
+                                let tx2 = tx.clone();
+                                spawn_monitored(tx.clone(), "clipboard", async move {
+                                    let result = executor2
+                                        .copy_to_clipboard(&url)
+                                        .await
+                                        .map_err(|e| format!("{e}"));
+                                    if tx2.send(AppEvent::ClipboardResult(result)).is_err() {
+                                        tracing::warn!("clipboard: channel closed");
+                                    }
+                                });
+                            }
+                        }
+                        Action::CopyFailedLogs => {
+                            if let Some(run_id) = state.current_failed_run_id() {
+                                copy_failed_logs_async(&executor, run_id, tx);
+                            } else if state.current_run_id().is_some() {
+                                state.set_error("No failed jobs in this pipeline".to_string());
+                            }
+                        }
+                        Action::OpenLogExternal => {
+                            if let Some(text) = state.log_overlay_text() {
+                                events.suspend(TICK_RATE);
+                                let result = tui::pager::open(terminal, &text);
+                                events.resume();
+                                match result {
+                                    Ok(None) => {}
+                                    Ok(Some(path)) => state.add_notification(
+                                        0,
+                                        format!("Pager failed; log saved to {}", path.display()),
+                                    ),
+                                    Err(e) => state.set_error(format!("Pager failed: {e}")),
+                                }
+                            }
+                        }
+                        Action::ShowNotificationHistory => state.open_notification_history(),
                         Action::ShowDetails => {
                             if let Some(item) = state.tree_items.get(state.cursor).cloned() {
                                 if let Some(resolved) = state.resolve_item(&item) {
@@ -591,11 +1025,57 @@ async fn run_app(
                             }
                         }
                         Action::CycleFilter => state.cycle_filter(),
+                        Action::CycleSort => state.cycle_sort(),
+                        Action::ToggleReverse => state.toggle_reverse(),
+                        Action::ToggleBranchGroups => state.toggle_group_by_branch(),
+                        Action::ToggleHideSkipped => state.toggle_hide_skipped(),
+                        Action::ToggleMyRuns => state.toggle_my_runs(),
+                        Action::CycleEventFilter => state.cycle_event_filter(),
                         Action::FilterBranch => {
                             state.filter = app::FilterMode::CurrentBranch;
                             state.rebuild_tree();
                         }
-                        Action::QuickSelect(n) => state.quick_select(n),
+                        Action::QuickSelect(n) => {
+                            state.quick_select(n);
+                            state.push_count_digit(n);
+                        }
+                        Action::CountDigit(d) => state.push_count_digit(d),
+                        Action::JumpToLabel(c) => state.jump_to_label(c),
+                        Action::JumpToFirst => state.jump_to_first(),
+                        Action::JumpToLast => state.jump_to_last(),
+                        Action::JumpToRow(n) => state.jump_to_row(n),
+                        Action::TogglePause => {
+                            state.toggle_pause();
+                            let interval = if state.paused {
+                                poller::POLL_PAUSED
+                            } else {
+                                state.poll_interval
+                            };
+                            if interval_tx.send(interval).is_err() {
+                                tracing::warn!("interval: poller channel closed");
+                            }
+                        }
+                        Action::ToggleNotify => state.toggle_desktop_notify(),
+                        Action::CollapseAll => state.collapse_all(),
+                        Action::ExpandAll => {
+                            for run_id in state.expand_all() {
+                                let tx2 = tx.clone();
+                                let executor2 = executor.clone();
+                                let parser2 = parser.clone();
+                                spawn_monitored(tx.clone(), "expand_jobs", async move {
+                                    poller::fetch_jobs_for_run(
+                                        &*executor2,
+                                        &*parser2,
+                                        run_id,
+                                        &tx2,
+                                    )
+                                    .await;
+                                });
+                            }
+                        }
+                        Action::Prefix(c) => state.pending_key = Some(c),
+                        Action::NextFailure => state.next_failure(),
+                        Action::PrevFailure => state.prev_failure(),
                         Action::None => {}
                     }
                 }
@@ -604,121 +1084,192 @@ async fn run_app(
                         state.advance_spinner();
                         last_tick = Instant::now();
                     }
+                    state.check_long_runs(chrono::Utc::now());
                     // Check if the poller task has died unexpectedly
                     if poller_handle.is_finished() {
                         state.set_error(
                             "Poller stopped unexpectedly. Press r to refresh manually.".to_string(),
                         );
                     }
-                    // Adaptive polling: adjust interval and notify poller
-                    let new_interval = if state.has_active_runs() {
-                        app::POLL_INTERVAL_ACTIVE
-                    } else if state
-                        .last_poll
-                        .is_some_and(|t| t.elapsed().as_secs() < app::POLL_RECENT_THRESHOLD_SECS)
-                    {
-                        app::POLL_INTERVAL_RECENT
-                    } else {
-                        app::POLL_INTERVAL_IDLE
-                    };
-                    if new_interval != state.poll_interval {
-                        state.poll_interval = new_interval;
-                        if interval_tx.send(new_interval).is_err() {
-                            tracing::warn!("interval: poller channel closed");
+                    // Adaptive polling: adjust interval and notify poller (frozen while paused)
+                    if !state.paused {
+                        let new_interval = app::select_poll_interval(
+                            state.has_active_runs(),
+                            state
+                                .last_poll
+                                .is_some_and(|t| app::is_recent(t.elapsed().as_secs())),
+                            &state.intervals,
+                        );
+                        if new_interval != state.poll_interval {
+                            state.poll_interval = new_interval;
+                            if interval_tx.send(new_interval).is_err() {
+                                tracing::warn!("interval: poller channel closed");
+                            }
                         }
                     }
                 }
+                AppEvent::RateLimitStatus(quota) => state.api_quota = Some(quota),
+                AppEvent::CurrentUser(login) => state.current_user = Some(login),
+                AppEvent::RateLimited { retry_in } => {
+                    state.set_rate_limited(retry_in);
+                }
+                AppEvent::NotifyFallback {
+                    run_id,
+                    fallback,
+                    reason,
+                } => {
+                    tracing::warn!("desktop notification failed: {reason}");
+                    match fallback {
+                        notify::Fallback::Bell => notify::ring_bell(),
+                        notify::Fallback::InApp => state.add_notification(run_id, reason),
+                    }
+                }
                 AppEvent::PollResult {
                     runs: new_runs,
                     manual,
                 } => {
                     if manual {
-                        state.end_loading();
+                        state.end_refresh();
                     }
                     state.clear_error();
-                    state.run_errors.clear();
+                    // Fetch errors stay until retried so the tree doesn't fall back
+                    // to an endless `Loading…`; drop those for runs that are gone.
+                    state
+                        .run_errors
+                        .retain(|id, _| new_runs.iter().any(|r| r.database_id == *id));
+                    state
+                        .selected_runs
+                        .retain(|id| new_runs.iter().any(|r| r.database_id == *id));
+                    state.rate_limited_until = None;
 
-                    let old_snapshot = if state.desktop_notify {
-                        Some(state.previous_snapshot.clone())
-                    } else {
-                        None
-                    };
+                    let changes = diff::detect_changes(state, &new_runs);
+                    state.set_poll_summary(changes.summary.label());
+                    let completions = changes.completions;
+                    if std::mem::take(&mut state.bell_pending) {
+                        notify::ring_bell();
+                    }
 
-                    diff::detect_changes(state, &new_runs);
+                    if let Some(url) = &state.config.webhook_url {
+                        let notify_on = state.config.notify_on;
+                        let runs = completions
+                            .iter()
+                            .filter(|c| notify_on.allows(c.failed))
+                            .filter_map(|c| new_runs.iter().find(|r| r.database_id == c.run_id))
+                            .filter(|run| {
+                                notify::should_notify(
+                                    run,
+                                    state.config.notify_scope,
+                                    state.config.branch.as_deref(),
+                                )
+                            });
+                        for run in runs {
+                            let payload = notify::webhook_payload(run, &state.config.repo);
+                            post_webhook_async(url.clone(), payload, tx);
+                        }
+                    }
 
-                    if let Some(old_snapshot) = old_snapshot {
-                        for run in &new_runs {
-                            if run.status == app::RunStatus::Completed {
-                                if let Some(entry) = old_snapshot.get(&run.database_id) {
-                                    if entry.status != app::RunStatus::Completed {
-                                        let run_clone = run.clone();
-                                        let tx2 = tx.clone();
-                                        tokio::task::spawn_blocking(move || {
-                                            let result = std::panic::catch_unwind(
-                                                std::panic::AssertUnwindSafe(|| {
-                                                    notify::send_desktop(&run_clone)
-                                                }),
-                                            );
-                                            match result {
-                                                Ok(Some(err)) => {
-                                                    if tx2.send(AppEvent::Error(err)).is_err() {
-                                                        tracing::warn!("notify: channel closed");
-                                                    }
-                                                }
-                                                Err(panic_payload) => {
-                                                    let msg = panic_payload
-                                                        .downcast::<String>()
-                                                        .map(|s| *s)
-                                                        .unwrap_or_else(|p| {
-                                                            p.downcast::<&str>()
-                                                                .map(|s| s.to_string())
-                                                                .unwrap_or_else(|_| {
-                                                                    "unknown panic".to_string()
-                                                                })
-                                                        });
-                                                    tracing::error!("notify panicked: {msg}");
-                                                    if tx2
-                                                        .send(AppEvent::Error(format!(
-                                                            "Notification crashed: {msg}"
-                                                        )))
-                                                        .is_err()
-                                                    {
-                                                        tracing::warn!("notify: channel closed");
-                                                    }
-                                                }
-                                                Ok(None) => {} // success
+                    if state.desktop_notify {
+                        let notify_on = state.config.notify_on;
+                        let bell = state.config.bell_fallback;
+                        for completion in completions.iter().filter(|c| notify_on.allows(c.failed))
+                        {
+                            let Some(run) =
+                                new_runs.iter().find(|r| r.database_id == completion.run_id)
+                            else {
+                                continue;
+                            };
+                            if !notify::should_notify(
+                                run,
+                                state.config.notify_scope,
+                                state.config.branch.as_deref(),
+                            ) {
+                                continue;
+                            }
+                            let run_clone = run.clone();
+                            let command = state.config.notify_command.clone();
+                            let run_id = run.database_id;
+                            let tx2 = tx.clone();
+                            tokio::task::spawn_blocking(move || {
+                                let result =
+                                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                                        notify::send_desktop(&run_clone, command.as_ref())
+                                    }));
+                                match result {
+                                    Ok(err) => {
+                                        if let Some(fallback) =
+                                            notify::fallback_for(err.as_deref(), bell)
+                                        {
+                                            let event = AppEvent::NotifyFallback {
+                                                run_id,
+                                                fallback,
+                                                reason: err.unwrap_or_default(),
+                                            };
+                                            if tx2.send(event).is_err() {
+                                                tracing::warn!("notify: channel closed");
                                             }
-                                        });
+                                        }
+                                    }
+                                    Err(panic_payload) => {
+                                        let msg = panic_payload
+                                            .downcast::<String>()
+                                            .map(|s| *s)
+                                            .unwrap_or_else(|p| {
+                                                p.downcast::<&str>()
+                                                    .map(|s| s.to_string())
+                                                    .unwrap_or_else(|_| "unknown panic".to_string())
+                                            });
+                                        tracing::error!("notify panicked: {msg}");
+                                        if tx2
+                                            .send(AppEvent::Error(format!(
+                                                "Notification crashed: {msg}"
+                                            )))
+                                            .is_err()
+                                        {
+                                            tracing::warn!("notify: channel closed");
+                                        }
                                     }
                                 }
-                            }
+                            });
                         }
                     }
 
-                    // Carry forward existing job data so expanded runs keep their
-                    // children across poll cycles (poll only returns runs, not jobs).
-                    // If a run's `updated_at` changed, its job data is stale — discard
-                    // it and re-fetch if the run is currently expanded.
+                    // Carry forward job data so expanded runs keep their children
+                    // across polls; stale jobs are re-fetched if the run is expanded
+                    // (or, with --prefetch-failed, still failed).
                     let mut runs = new_runs;
-                    let mut refetch_run_ids = Vec::new();
-                    for run in &mut runs {
-                        if let Some(old) =
-                            state.runs.iter().find(|r| r.database_id == run.database_id)
-                        {
-                            if old.jobs.is_some() {
-                                if old.updated_at == run.updated_at {
-                                    run.jobs = old.jobs.clone();
-                                } else if state.expanded_runs.contains(&run.database_id) {
-                                    // Run changed and is expanded -- re-fetch jobs
-                                    refetch_run_ids.push(run.database_id);
-                                }
+                    let mut refetch_run_ids = app::carry_over_jobs(&state.runs, &mut runs, |run| {
+                        state.expanded_runs.contains(&run.database_id)
+                            || (state.config.prefetch_failed_jobs
+                                && run.conclusion.is_some_and(app::Conclusion::is_failure))
+                    });
+                    state.anchor_cursor();
+                    state.runs = runs;
+                    if state.config.prefetch_failed_jobs {
+                        for run_id in diff::prefetch_targets(&completions, &state.runs) {
+                            if !refetch_run_ids.contains(&run_id) {
+                                refetch_run_ids.push(run_id);
                             }
                         }
                     }
-                    state.runs = runs;
+                    if let Some(run_id) = state.watch_run_needing_jobs() {
+                        if !refetch_run_ids.contains(&run_id) {
+                            refetch_run_ids.push(run_id);
+                        }
+                    }
+                    if let Some(run_id) = state.auto_expand_newest_active() {
+                        if !refetch_run_ids.contains(&run_id) {
+                            refetch_run_ids.push(run_id);
+                        }
+                    }
                     state.rebuild_tree();
                     state.last_poll = Some(Instant::now());
+                    state.from_cache = false;
                     poll_start = Instant::now();
+                    if let Some(path) = &state.config.cache_path {
+                        if let Err(e) = cache::save(path, &state.runs) {
+                            tracing::warn!("cache: {e}");
+                        }
+                    }
 
                     // Close log overlay if its run no longer exists
                     if let Some(overlay) = state.log_overlay_ref() {
@@ -728,6 +1279,19 @@ async fn run_app(
                         }
                     }
 
+                    // Follow mode: re-fetch the open log so it tracks the running job
+                    if let Some((run_id, job_id)) = state.followed_log() {
+                        fetch_logs_async(
+                            &executor,
+                            &parser,
+                            run_id,
+                            job_id,
+                            state.log_fetch_max_lines(),
+                            &mut log_fetches,
+                            tx,
+                        );
+                    }
+
                     // Prune expanded_runs and expanded_jobs for runs no longer present
                     let run_ids: std::collections::HashSet<u64> =
                         state.runs.iter().map(|r| r.database_id).collect();
@@ -735,8 +1299,29 @@ async fn run_app(
                     state
                         .expanded_jobs
                         .retain(|(run_id, _)| run_ids.contains(run_id));
+                    state
+                        .toggled_groups
+                        .retain(|(run_id, _)| run_ids.contains(run_id));
+
+                    if executor.supports_rate_limit()
+                        && state.poll_count % app::RATE_LIMIT_CHECK_POLLS == 1
+                    {
+                        let tx2 = tx.clone();
+                        let executor2 = executor.clone();
+                        spawn_monitored(tx.clone(), "rate_limit", async move {
+                            match executor2.rate_limit().await {
+                                Ok(quota) => {
+                                    if tx2.send(AppEvent::RateLimitStatus(quota)).is_err() {
+                                        tracing::warn!("rate_limit: channel closed");
+                                    }
+                                }
+                                Err(e) => tracing::debug!("rate_limit: {e}"),
+                            }
+                        });
+                    }
 
-                    // Re-fetch jobs for expanded runs whose data has changed
+                    // Re-fetch jobs for changed expanded runs and prefetch targets;
+                    // the executor's permit pool bounds how many run at once.
                     for run_id in refetch_run_ids {
                         let tx2 = tx.clone();
                         let executor2 = executor.clone();
@@ -747,26 +1332,51 @@ async fn run_app(
                     }
                 }
                 AppEvent::JobsResult { run_id, jobs } => {
+                    state.run_errors.remove(&run_id);
                     // Find run by ID (index may have changed)
                     if let Some(run) = state.runs.iter_mut().find(|r| r.database_id == run_id) {
                         run.jobs = Some(jobs);
                     }
                     state.rebuild_tree();
                 }
+                AppEvent::LogFetchProgress {
+                    run_id,
+                    job_id,
+                    bytes,
+                } => state.set_log_progress(run_id, job_id, bytes),
+                AppEvent::LogFetchFailed {
+                    run_id,
+                    job_id,
+                    error,
+                } => {
+                    log_fetches.remove(&(run_id, job_id));
+                    if state.log_result_wanted(run_id, job_id) {
+                        state.abort_log_loading(run_id, job_id);
+                        state.set_error(error);
+                    }
+                }
                 AppEvent::FailedLogResult {
                     run_id,
                     job_id,
-                    title,
                     content,
                 } => {
-                    state.open_log_overlay(title, &content, run_id, job_id);
-                    state.log_cache.insert(
-                        (run_id, job_id),
-                        app::FailedLog {
-                            content,
-                            fetched_at: Instant::now(),
-                        },
-                    );
+                    log_fetches.remove(&(run_id, job_id));
+                    // Overlay closed while in flight and the abort came too late
+                    if state.log_result_wanted(run_id, job_id) {
+                        state.refresh_log_overlay(
+                            run_id,
+                            job_id,
+                            &content,
+                            log_overlay_height(terminal),
+                        );
+                        state.log_cache.insert(
+                            (run_id, job_id),
+                            app::FailedLog {
+                                content,
+                                fetched_at: Instant::now(),
+                            },
+                        );
+                    }
                 }
                 AppEvent::ClipboardResult(result) => match result {
                     Ok(()) => {
@@ -787,18 +1397,53 @@ async fn run_app(
                     state.remove_run(run_id);
                     state.add_notification(run_id, "Pipeline deleted".to_string());
                 }
+                AppEvent::BatchResult {
+                    op,
+                    succeeded,
+                    failed,
+                } => {
+                    state.report_batch_result(op, &succeeded, &failed);
+                    match op {
+                        app::BatchOp::Delete => {
+                            for run_id in succeeded {
+                                state.remove_run(run_id);
+                            }
+                        }
+                        app::BatchOp::Cancel if !succeeded.is_empty() => {
+                            poll_start = Instant::now()
+                                .checked_sub(Duration::from_secs(state.poll_interval))
+                                .unwrap_or(poll_start);
+                        }
+                        app::BatchOp::Cancel => {}
+                    }
+                }
+                AppEvent::ApproveSuccess(run_id) => {
+                    state.add_notification(run_id, "Run approved".to_string());
+                    spawn_refresh(state, &executor, &parser, tx);
+                    poll_start = Instant::now();
+                }
+                AppEvent::DispatchSuccess { workflow, branch } => {
+                    state.add_notification(0, format!("Dispatched {workflow} on {branch}"));
+                    spawn_refresh(state, &executor, &parser, tx);
+                    poll_start = Instant::now();
+                }
                 AppEvent::RerunSuccess(run_id) => {
                     state.log_cache.retain(|(r, _), _| *r != run_id);
+                    state.log_scroll.retain(|(r, _), _| *r != run_id);
                     state.add_notification(run_id, "Retry triggered".to_string());
+                    // Pick up the retried jobs without waiting a full interval
+                    spawn_refresh(state, &executor, &parser, tx);
+                    poll_start = Instant::now();
                 }
                 AppEvent::RunError { run_id, error } => {
                     state.run_errors.insert(run_id, error);
                     state.rebuild_tree();
                 }
-                AppEvent::Error(e) => {
-                    state.end_loading();
+                AppEvent::RefreshFailed(e) => {
+                    state.end_refresh();
                     state.set_error(e);
                 }
+                AppEvent::Error(e) => state.set_error(e),
             }
         }
 
@@ -830,52 +1475,108 @@ fn build_detail_lines(
     match resolved {
         app::ResolvedItem::Run(run) => {
             let title = format!("Pipeline #{}", run.number);
-            let conclusion_str = run.conclusion.map_or("-".into(), |c| format!("{c:?}"));
-            let end = if run.status == app::RunStatus::Completed {
-                Some(run.updated_at)
-            } else {
-                None
+            let conclusion_str =
+                app::conclusion_label(run, state.superseded.get(&run.database_id).copied());
+            let duration = run.duration_label();
+            let stamp = |dt| {
+                if run.timestamps_unknown {
+                    "unknown".to_string()
+                } else {
+                    format!(
+                        "{} ({})",
+                        app::format_relative(dt),
+                        state.config.timezone.format(dt)
+                    )
+                }
             };
-            let duration = app::compute_duration(Some(run.created_at), end);
-            let lines = vec![
+            let mut lines = vec![
                 ("Title".into(), run.display_title.clone()),
                 ("Source".into(), run.name.clone()),
                 ("Branch".into(), run.head_branch.clone()),
                 ("Source".into(), run.event.clone()),
-                ("Status".into(), format!("{:?}", run.status)),
+                ("Status".into(), app::status_label(run.status)),
                 ("Conclusion".into(), conclusion_str),
                 ("Duration".into(), duration),
-                (
-                    "Created".into(),
-                    run.created_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-                ),
-                (
-                    "Updated".into(),
-                    run.updated_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-                ),
+                ("Attempt".into(), run.attempt.to_string()),
+                ("Created".into(), stamp(run.created_at)),
+                ("Updated".into(), stamp(run.updated_at)),
                 ("URL".into(), run.url.clone()),
             ];
+            if let Some(actor) = &run.actor {
+                let at = lines
+                    .iter()
+                    .position(|(k, _)| k == "Branch")
+                    .map_or(0, |i| i + 1);
+                lines.insert(at, ("Triggered by".into(), actor.clone()));
+            }
+            if let Some(secs) = AppState::run_billable_seconds(run) {
+                let at = lines
+                    .iter()
+                    .position(|(k, _)| k == "Duration")
+                    .map_or(0, |i| i + 1);
+                lines.insert(at, ("Compute time".into(), app::format_duration(secs)));
+            }
+            if let Some(url) = run.pr_url() {
+                lines.push(("Pull Request".into(), url));
+            }
+            if !run.head_sha.is_empty() {
+                lines.push(("Commit".into(), run.head_sha.clone()));
+            }
+            if let Some(msg) = &run.head_commit_message {
+                lines.push((
+                    "Message".into(),
+                    msg.lines().next().unwrap_or("").to_string(),
+                ));
+            }
             (title, lines)
         }
+        app::ResolvedItem::Branch { name, runs } => {
+            let (active, failed) = app::branch_counts(runs);
+            let mut lines = vec![
+                ("Name".into(), (*name).to_string()),
+                ("Pipelines".into(), runs.len().to_string()),
+                ("Active".into(), active.to_string()),
+                ("Failed".into(), failed.to_string()),
+            ];
+            if let Some(latest) = runs.iter().max_by_key(|r| r.updated_at) {
+                lines.push((
+                    "Last activity".into(),
+                    app::format_relative(latest.updated_at),
+                ));
+            }
+            (format!("Branch: {name}"), lines)
+        }
+        app::ResolvedItem::Group { name, jobs } => {
+            let (status, conclusion) = app::aggregate_status(jobs);
+            let failed = jobs
+                .iter()
+                .filter(|j| j.conclusion.is_some_and(app::Conclusion::is_failure))
+                .count();
+            let lines = vec![
+                ("Name".into(), (*name).to_string()),
+                ("Jobs".into(), jobs.len().to_string()),
+                ("Failed".into(), failed.to_string()),
+                ("Status".into(), app::status_label(status)),
+                (
+                    "Conclusion".into(),
+                    conclusion.map_or("-".into(), |c| format!("{c:?}")),
+                ),
+            ];
+            (format!("Stage: {name}"), lines)
+        }
         app::ResolvedItem::Job(job) => {
             let title = format!("Job: {}", job.name);
             let conclusion_str = job.conclusion.map_or("-".into(), |c| format!("{c:?}"));
             let mut lines = vec![
                 ("Name".into(), job.name.clone()),
-                ("Status".into(), format!("{:?}", job.status)),
+                ("Status".into(), app::status_label(job.status)),
                 ("Conclusion".into(), conclusion_str),
             ];
             if let Some(started) = job.started_at {
-                lines.push((
-                    "Started".into(),
-                    started.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-                ));
+                lines.push(("Started".into(), state.config.timezone.format(started)));
             }
             if let Some(completed) = job.completed_at {
-                lines.push((
-                    "Completed".into(),
-                    completed.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-                ));
+                lines.push(("Completed".into(), state.config.timezone.format(completed)));
             }
             let dur = app::compute_duration(job.started_at, job.completed_at);
             if !dur.is_empty() {
@@ -886,6 +1587,12 @@ fn build_detail_lines(
                 };
                 lines.push(("Duration".into(), label));
             }
+            if let Some(runner) = &job.runner_name {
+                lines.push(("Runner".into(), runner.clone()));
+            }
+            if !job.labels.is_empty() {
+                lines.push(("Labels".into(), job.labels.join(", ")));
+            }
             lines.push(("URL".into(), job.url.clone()));
             // Show parent pipeline info
             if let Some(run) = state.runs.get(run_idx) {
@@ -902,20 +1609,14 @@ fn build_detail_lines(
             let mut lines = vec![
                 ("Name".into(), step.name.clone()),
                 ("Number".into(), step.number.to_string()),
-                ("Status".into(), format!("{:?}", step.status)),
+                ("Status".into(), app::status_label(step.status)),
                 ("Conclusion".into(), conclusion_str),
             ];
             if let Some(started) = step.started_at {
-                lines.push((
-                    "Started".into(),
-                    started.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-                ));
+                lines.push(("Started".into(), state.config.timezone.format(started)));
             }
             if let Some(completed) = step.completed_at {
-                lines.push((
-                    "Completed".into(),
-                    completed.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-                ));
+                lines.push(("Completed".into(), state.config.timezone.format(completed)));
             }
             let dur = app::compute_duration(step.started_at, step.completed_at);
             if !dur.is_empty() {
@@ -931,27 +1632,128 @@ fn build_detail_lines(
     }
 }
 
+/// Manual (non-poller) fetch; the result arrives as `PollResult { manual: true }`.
+/// Returns `false` (and fetches nothing) if another is still in flight.
+fn spawn_refresh(
+    state: &mut AppState,
+    executor: &Arc<dyn CiExecutor>,
+    parser: &Arc<dyn CiParser>,
+    tx: &tokio::sync::mpsc::UnboundedSender<AppEvent>,
+) -> bool {
+    if !state.try_begin_refresh() {
+        tracing::debug!("refresh: one already in flight, skipped");
+        return false;
+    }
+    let tx2 = tx.clone();
+    let executor2 = executor.clone();
+    let parser2 = parser.clone();
+    let limit = state.config.limit;
+    let wf = state.config.workflow_filter.clone();
+    let watch_run = state.config.watch_run;
+    spawn_monitored(tx.clone(), "refresh", async move {
+        let event = match poller::fetch_state(
+            &*executor2,
+            &*parser2,
+            limit,
+            wf.as_deref(),
+            None,
+            watch_run,
+        )
+        .await
+        {
+            Ok(runs) => AppEvent::PollResult { runs, manual: true },
+            Err(e) => AppEvent::RefreshFailed(format!("{}", e)),
+        };
+        if tx2.send(event).is_err() {
+            tracing::warn!("refresh: channel closed");
+        }
+    });
+    true
+}
+
+/// `fetch_failed_logs` already joins every failed job's trace under a header.
+fn copy_failed_logs_async(
+    executor: &Arc<dyn CiExecutor>,
+    run_id: u64,
+    tx: &tokio::sync::mpsc::UnboundedSender<AppEvent>,
+) {
+    let executor = executor.clone();
+    let tx2 = tx.clone();
+    spawn_monitored(tx.clone(), "clipboard", async move {
+        let result = async {
+            let logs = executor
+                .fetch_failed_logs(run_id)
+                .await
+                .map_err(|e| format!("{e}"))?;
+            if logs.trim().is_empty() {
+                return Err("No failed step logs available".to_string());
+            }
+            executor
+                .copy_to_clipboard(&logs)
+                .await
+                .map_err(|e| format!("{e}"))
+        }
+        .await;
+        if tx2.send(AppEvent::ClipboardResult(result)).is_err() {
+            tracing::warn!("clipboard: channel closed");
+        }
+    });
+}
+
+/// Off the event loop so a slow endpoint never stalls the UI; failures toast.
+fn post_webhook_async(
+    url: String,
+    payload: serde_json::Value,
+    tx: &tokio::sync::mpsc::UnboundedSender<AppEvent>,
+) {
+    let tx2 = tx.clone();
+    spawn_monitored(tx.clone(), "webhook", async move {
+        if let Err(e) = notify::send_webhook(&url, &payload).await {
+            if tx2.send(AppEvent::Error(e)).is_err() {
+                tracing::warn!("webhook: channel closed");
+            }
+        }
+    });
+}
+
+/// In-flight log fetches by `(run_id, job_id)`, aborted once nothing shows
+/// their log any more.
+type LogFetches = std::collections::HashMap<(u64, Option<u64>), tokio::task::AbortHandle>;
+
+/// Starts a log fetch, replacing (and aborting) any still running for the same
+/// log so a stale, shorter result can't land after a newer one.
 fn fetch_logs_async(
     executor: &Arc<dyn CiExecutor>,
     parser: &Arc<dyn CiParser>,
     run_id: u64,
     job_id: Option<u64>,
-    title: &str,
+    max_lines: usize,
+    fetches: &mut LogFetches,
     tx: &tokio::sync::mpsc::UnboundedSender<AppEvent>,
 ) {
     let executor = executor.clone();
     let parser = parser.clone();
-    let title = title.to_string();
     let tx2 = tx.clone();
-    spawn_monitored(tx.clone(), "fetch_logs", async move {
-        let result = if let Some(jid) = job_id {
-            executor.fetch_failed_logs_for_job(run_id, jid).await
-        } else {
-            executor.fetch_failed_logs(run_id).await
+    let handle = spawn_monitored(tx.clone(), "fetch_logs", async move {
+        let progress_tx = tx2.clone();
+        let report = move |bytes| {
+            if progress_tx
+                .send(AppEvent::LogFetchProgress {
+                    run_id,
+                    job_id,
+                    bytes,
+                })
+                .is_err()
+            {
+                tracing::warn!("fetch_logs: channel closed");
+            }
         };
+        let result = executor
+            .fetch_failed_logs_streaming(run_id, job_id, &report)
+            .await;
         match result {
             Ok(raw) => {
-                let (content, _truncated) = parser.process_log_output(&raw, app::LOG_MAX_LINES);
+                let (content, _truncated) = parser.process_log_output(&raw, max_lines);
                 let content = if content.trim().is_empty() {
                     "(no failed step logs available)".to_string()
                 } else {
@@ -961,7 +1763,6 @@ fn fetch_logs_async(
                     .send(AppEvent::FailedLogResult {
                         run_id,
                         job_id,
-                        title,
                         content,
                     })
                     .is_err()
@@ -970,10 +1771,18 @@ fn fetch_logs_async(
                 }
             }
             Err(e) => {
-                if tx2.send(AppEvent::Error(format!("{}", e))).is_err() {
+                let event = AppEvent::LogFetchFailed {
+                    run_id,
+                    job_id,
+                    error: format!("{e}"),
+                };
+                if tx2.send(event).is_err() {
                     tracing::warn!("fetch_logs: channel closed");
                 }
             }
         }
     });
+    if let Some(previous) = fetches.insert((run_id, job_id), handle) {
+        previous.abort();
+    }
 }
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use ciw_core::app::{Conclusion, Job, RunStatus, WorkflowRun};
-use ciw_core::traits::CiParser;
+use ciw_core::traits::{self, CiParser};
 use color_eyre::eyre::{eyre, Result};
 use serde::Deserialize;
 
@@ -33,6 +33,16 @@ struct GlabPipeline {
     updated_at: String,
     #[serde(default)]
     web_url: String,
+    #[serde(default)]
+    sha: String,
+    /// Who started the pipeline; the single-pipeline endpoint has it.
+    #[serde(default)]
+    user: Option<GlabUser>,
+}
+
+#[derive(Deserialize, Debug)]
+struct GlabUser {
+    username: String,
 }
 
 // -- Intermediate GitLab job struct --
@@ -48,6 +58,8 @@ struct GlabJob {
     finished_at: Option<String>,
     #[serde(default)]
     web_url: String,
+    #[serde(default)]
+    stage: String,
 }
 
 /// GitLab uses one `status` field; GitHub uses `status` + `conclusion`.
@@ -58,7 +70,10 @@ fn map_status(status: &str) -> RunStatus {
         "running" => RunStatus::InProgress,
         "pending" | "created" | "preparing" | "manual" | "scheduled" => RunStatus::Pending,
         "waiting_for_resource" => RunStatus::Waiting,
-        _ => RunStatus::Unknown,
+        other => {
+            tracing::debug!("unrecognized GitLab status {other:?}");
+            RunStatus::Unknown
+        }
     }
 }
 
@@ -98,8 +113,22 @@ impl From<GlabPipeline> for WorkflowRun {
     fn from(p: GlabPipeline) -> Self {
         let status = map_status(&p.status);
         let conclusion = map_conclusion(&p.status);
-        let created_at = parse_datetime(&p.created_at).unwrap_or_else(|_| Utc::now());
-        let updated_at = parse_datetime(&p.updated_at).unwrap_or_else(|_| Utc::now());
+        let created = parse_datetime(&p.created_at);
+        let updated = parse_datetime(&p.updated_at);
+        for e in [&created, &updated]
+            .into_iter()
+            .filter_map(|r| r.as_ref().err())
+        {
+            tracing::warn!("pipeline {}: {e}", p.id);
+        }
+        let timestamps_unknown = created.is_err() || updated.is_err();
+        // Placeholders only order the run; a surviving timestamp beats now
+        let (created_at, updated_at) = match (created, updated) {
+            (Ok(c), Ok(u)) => (c, u),
+            (Ok(c), Err(_)) => (c, c),
+            (Err(_), Ok(u)) => (u, u),
+            (Err(_), Err(_)) => (Utc::now(), Utc::now()),
+        };
 
         WorkflowRun {
             database_id: p.id,
@@ -113,6 +142,14 @@ impl From<GlabPipeline> for WorkflowRun {
             updated_at,
             event: p.source,
             url: p.web_url,
+            head_sha: p.sha,
+            head_commit_message: None,
+            workflow_path: None,
+            pr_number: None,
+            attempt: 1,
+            required: None,
+            actor: p.user.map(|u| u.username),
+            timestamps_unknown,
             jobs: None,
         }
     }
@@ -132,6 +169,9 @@ impl From<GlabJob> for Job {
             completed_at: parse_optional_datetime(&j.finished_at),
             url: j.web_url,
             steps: vec![],
+            stage: (!j.stage.is_empty()).then_some(j.stage),
+            runner_name: None,
+            labels: Vec::new(),
         }
     }
 }
@@ -147,18 +187,15 @@ impl CiParser for GlabParser {
 
     fn parse_jobs(&self, json: &str) -> Result<Vec<Job>> {
         check_response_size(json)?;
-        let jobs: Vec<GlabJob> = serde_json::from_str(json)?;
+        let mut jobs: Vec<GlabJob> = serde_json::from_str(json)?;
+        // GitLab lists jobs newest first; ids follow pipeline order, so stages
+        // (grouped by first appearance) come out build → test → deploy.
+        jobs.sort_by_key(|j| j.id);
         Ok(jobs.into_iter().map(Job::from).collect())
     }
 
     fn process_log_output(&self, raw: &str, max_lines: usize) -> (String, bool) {
-        let lines: Vec<&str> = raw.lines().collect();
-        if lines.len() > max_lines {
-            let truncated = &lines[lines.len() - max_lines..];
-            (truncated.join("\n"), true)
-        } else {
-            (raw.to_string(), false)
-        }
+        traits::tail_log(raw, max_lines)
     }
 }
 
@@ -203,6 +240,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_pipeline_user_as_actor() {
+        let runs = parser().parse_runs(SINGLE_PIPELINE_JSON).unwrap();
+        assert_eq!(runs[0].actor, None);
+
+        let json = r#"[{
+            "id": 1, "iid": 1, "ref": "main", "status": "success",
+            "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-01-01T00:00:00Z",
+            "user": {"id": 7, "username": "alice", "name": "Alice"}
+        }]"#;
+        assert_eq!(
+            parser().parse_runs(json).unwrap()[0].actor.as_deref(),
+            Some("alice")
+        );
+    }
+
+    #[test]
+    fn unparseable_timestamp_is_flagged_not_zero() {
+        let json = r#"[{
+            "id": 1, "iid": 1, "ref": "main", "status": "success",
+            "created_at": "yesterday-ish", "updated_at": "2024-01-01T00:05:00Z"
+        }]"#;
+        let run = &parser().parse_runs(json).unwrap()[0];
+        assert!(run.timestamps_unknown);
+        assert_eq!(run.duration_label(), "unknown");
+        assert_eq!(run.created_at, run.updated_at);
+
+        let runs = parser().parse_runs(SINGLE_PIPELINE_JSON).unwrap();
+        assert!(!runs[0].timestamps_unknown);
+    }
+
     #[test]
     fn parse_running_pipeline() {
         let json = r#"[{
@@ -336,6 +404,32 @@ mod tests {
         assert!(jobs[0].steps.is_empty());
         assert_eq!(jobs[1].name, "test");
         assert_eq!(jobs[1].conclusion, Some(Conclusion::Failure));
+        assert_eq!(jobs[0].stage, None);
+    }
+
+    #[test]
+    fn parse_jobs_in_pipeline_order() {
+        let json = r#"[
+            {"id": 12, "name": "deploy", "status": "created", "stage": "deploy"},
+            {"id": 11, "name": "unit", "status": "running", "stage": "test"},
+            {"id": 10, "name": "compile", "status": "success", "stage": "build"}
+        ]"#;
+        let jobs = parser().parse_jobs(json).unwrap();
+        let stages: Vec<_> = jobs.iter().filter_map(|j| j.stage.as_deref()).collect();
+        assert_eq!(stages, ["build", "test", "deploy"]);
+    }
+
+    #[test]
+    fn parse_jobs_stage() {
+        let json = r#"[
+            {"id": 1, "name": "compile", "status": "success", "stage": "build"},
+            {"id": 2, "name": "unit", "status": "running", "stage": "test"},
+            {"id": 3, "name": "legacy", "status": "success", "stage": ""}
+        ]"#;
+        let jobs = parser().parse_jobs(json).unwrap();
+        assert_eq!(jobs[0].stage.as_deref(), Some("build"));
+        assert_eq!(jobs[1].stage.as_deref(), Some("test"));
+        assert_eq!(jobs[2].stage, None);
     }
 
     #[test]
@@ -463,4 +557,19 @@ mod tests {
         assert_eq!(runs[0].name, "merge_request_event");
         assert_eq!(runs[0].event, "merge_request_event");
     }
+
+    #[test]
+    fn parse_pipeline_sha() {
+        let json = r#"[{
+            "id": 1, "iid": 1, "ref": "main", "status": "success",
+            "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-01-01T00:00:00Z",
+            "sha": "deadbeefcafe"
+        }]"#;
+        let runs = parser().parse_runs(json).unwrap();
+        assert_eq!(runs[0].head_sha, "deadbeefcafe");
+        assert_eq!(runs[0].short_sha(), "deadbee");
+
+        let runs = parser().parse_runs(SINGLE_PIPELINE_JSON).unwrap();
+        assert_eq!(runs[0].head_sha, "");
+    }
 }